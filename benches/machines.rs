@@ -0,0 +1,96 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use turing_machine::accelerate::AcceleratedTuringMachineExt;
+use turing_machine::builders::TuringMachineBuilder;
+use turing_machine::deterministic_tm::{
+    DeterministicMachineRepresentation, DeterministicTuringMachine,
+};
+use turing_machine::machine_parser;
+use turing_machine::machine_representation::MachineRepresentation;
+use turing_machine::tape::{RunLengthTape, Tape};
+use turing_machine::TuringMachine;
+
+fn machine<TapeTy: Tape>(source: &str, tape: &str) -> DeterministicTuringMachine<String, TapeTy> {
+    let repr = DeterministicMachineRepresentation::from_builder(
+        &machine_parser::parse(source.as_bytes()).unwrap(),
+    )
+    .unwrap();
+    DeterministicTuringMachine::from_builder(
+        TuringMachineBuilder::new()
+            .repr(repr)
+            .tape(tape.chars().collect()),
+    )
+    .unwrap()
+}
+
+fn plain<TapeTy: Tape>(source: &str, tape: &str, steps: usize) -> usize {
+    let mut tm = machine::<TapeTy>(source, tape);
+    let mut taken = 0;
+    while taken < steps && !tm.is_finished() {
+        tm.step();
+        taken += 1;
+    }
+    taken
+}
+
+fn accelerated<TapeTy: Tape>(source: &str, tape: &str, steps: usize) -> usize {
+    let mut tm = AcceleratedTuringMachineExt::new(machine::<TapeTy>(source, tape));
+    while tm.get_number_of_steps() < steps && !tm.is_finished() {
+        tm.macro_step(steps - tm.get_number_of_steps());
+    }
+    tm.get_number_of_steps()
+}
+
+/// Run `source` on `tape` for at most `steps` steps with every combination of engine and tape,
+/// after checking they all take the same number of steps
+fn bench_machine(c: &mut Criterion, name: &str, source: &'static str, tape: String, steps: usize) {
+    let taken = plain::<Vec<char>>(source, &tape, steps);
+    let others = [
+        plain::<RunLengthTape>(source, &tape, steps),
+        accelerated::<Vec<char>>(source, &tape, steps),
+        accelerated::<RunLengthTape>(source, &tape, steps),
+    ];
+    assert!(
+        others.iter().all(|n| *n == taken),
+        "{} took {} steps, then {:?}",
+        name,
+        taken,
+        others
+    );
+
+    let input = tape.clone();
+    c.bench_function(&format!("{}_plain", name), move |b| {
+        b.iter(|| black_box(plain::<Vec<char>>(source, &input, steps)))
+    });
+    let input = tape.clone();
+    c.bench_function(&format!("{}_run_length", name), move |b| {
+        b.iter(|| black_box(plain::<RunLengthTape>(source, &input, steps)))
+    });
+    let input = tape.clone();
+    c.bench_function(&format!("{}_accelerated", name), move |b| {
+        b.iter(|| black_box(accelerated::<Vec<char>>(source, &input, steps)))
+    });
+    c.bench_function(&format!("{}_accelerated_run_length", name), move |b| {
+        b.iter(|| black_box(accelerated::<RunLengthTape>(source, &tape, steps)))
+    });
+}
+
+fn machines_benchmark(c: &mut Criterion) {
+    let paren = include_str!("../data/paren.tm");
+    let nested = format!("{}{}", "(".repeat(500), ")".repeat(500));
+    bench_machine(c, "paren_nested", paren, nested, usize::MAX);
+
+    let binadd = include_str!("../data/binadd.tm");
+    // A carry running along the whole number, as in data/carry_binadd.csv
+    let carry = format!("{}#1#{}1", "1".repeat(250), "0".repeat(250));
+    bench_machine(c, "binadd_carry", binadd, carry, usize::MAX);
+
+    let sqrt2 = include_str!("../data/sqrt2.tm");
+    bench_machine(c, "sqrt2", sqrt2, String::new(), 100_000);
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(10);
+    targets = machines_benchmark
+}
+criterion_main!(benches);
@@ -0,0 +1,859 @@
+states 58
+check
+accept +
+apply
+apply-target
+back-check
+back-cleanup
+back-clear-compare
+back-compare
+back-copy-kind-!
+back-copy-kind-+
+back-copy-kind--
+back-copy-kind-~
+back-copy-state-put-0
+back-copy-state-put-1
+back-extend
+back-fetch
+back-fetch-put-0
+back-fetch-put-1
+cleanup
+clear-compare
+compare
+compare-bit-0
+compare-bit-1
+compare-rule-out-0
+compare-rule-out-1
+compare-sweep-0
+compare-sweep-1
+copy-kind-!
+copy-kind-+
+copy-kind--
+copy-kind-~
+copy-state
+copy-state-into-0
+copy-state-into-1
+copy-state-put-0
+copy-state-put-1
+extend
+extend-put
+fetch
+fetch-cell
+fetch-put-0
+fetch-put-1
+halt !
+move-L
+move-R
+move-S
+move-left
+move-left-edge
+move-right
+reject -
+write
+write-back
+write-into-0
+write-into-1
+write-put-0
+write-put-1
+write-symbol
+write-target
+alphabet 20 ! # $ * + - . 0 1 ; > L R S ^ i o x | ~
+check ! halt ! S
+check + accept + S
+check - reject - S
+check . check . R
+check 0 check 0 R
+check 1 check 1 R
+check ^ check ^ R
+check ~ back-fetch ~ L
+apply _ apply _ R
+apply ! apply ! R
+apply # apply # R
+apply $ reject $ S
+apply * apply * R
+apply + apply + R
+apply - apply - R
+apply . apply . R
+apply 0 apply 0 R
+apply 1 apply 1 R
+apply ; apply-target ; R
+apply > apply > R
+apply L apply L R
+apply R apply R R
+apply S apply S R
+apply ^ apply ^ R
+apply i apply i R
+apply o apply o R
+apply x apply x R
+apply | apply | R
+apply ~ apply ~ R
+apply-target _ apply-target _ R
+apply-target ! apply-target ! R
+apply-target # apply-target # R
+apply-target $ apply-target $ R
+apply-target * apply-target * R
+apply-target + apply-target + R
+apply-target - apply-target - R
+apply-target . apply-target . R
+apply-target 0 apply-target 0 R
+apply-target 1 apply-target 1 R
+apply-target ; apply-target ; R
+apply-target > copy-state > R
+apply-target L apply-target L R
+apply-target R apply-target R R
+apply-target S apply-target S R
+apply-target ^ apply-target ^ R
+apply-target i apply-target i R
+apply-target o apply-target o R
+apply-target x apply-target x R
+apply-target | apply-target | R
+apply-target ~ apply-target ~ R
+back-check _ back-check _ L
+back-check ! back-check ! L
+back-check # back-check # L
+back-check $ back-check $ L
+back-check * back-check * L
+back-check + back-check + L
+back-check - back-check - L
+back-check . back-check . L
+back-check 0 back-check 0 L
+back-check 1 back-check 1 L
+back-check ; back-check ; L
+back-check > back-check > L
+back-check L back-check L L
+back-check R back-check R L
+back-check S back-check S L
+back-check ^ check ^ R
+back-check i back-check i L
+back-check o back-check o L
+back-check x back-check x L
+back-check | back-check | L
+back-check ~ back-check ~ L
+back-cleanup _ back-cleanup _ L
+back-cleanup ! back-cleanup ! L
+back-cleanup # back-cleanup # L
+back-cleanup $ back-cleanup $ L
+back-cleanup * back-cleanup * L
+back-cleanup + back-cleanup + L
+back-cleanup - back-cleanup - L
+back-cleanup . back-cleanup . L
+back-cleanup 0 back-cleanup 0 L
+back-cleanup 1 back-cleanup 1 L
+back-cleanup ; back-cleanup ; L
+back-cleanup > back-cleanup > L
+back-cleanup L back-cleanup L L
+back-cleanup R back-cleanup R L
+back-cleanup S back-cleanup S L
+back-cleanup ^ cleanup ^ R
+back-cleanup i back-cleanup i L
+back-cleanup o back-cleanup o L
+back-cleanup x back-cleanup x L
+back-cleanup | back-cleanup | L
+back-cleanup ~ back-cleanup ~ L
+back-clear-compare _ back-clear-compare _ L
+back-clear-compare ! back-clear-compare ! L
+back-clear-compare # back-clear-compare # L
+back-clear-compare $ back-clear-compare $ L
+back-clear-compare * back-clear-compare * L
+back-clear-compare + back-clear-compare + L
+back-clear-compare - back-clear-compare - L
+back-clear-compare . back-clear-compare . L
+back-clear-compare 0 back-clear-compare 0 L
+back-clear-compare 1 back-clear-compare 1 L
+back-clear-compare ; back-clear-compare ; L
+back-clear-compare > back-clear-compare > L
+back-clear-compare L back-clear-compare L L
+back-clear-compare R back-clear-compare R L
+back-clear-compare S back-clear-compare S L
+back-clear-compare ^ clear-compare ^ R
+back-clear-compare i back-clear-compare i L
+back-clear-compare o back-clear-compare o L
+back-clear-compare x back-clear-compare x L
+back-clear-compare | back-clear-compare | L
+back-clear-compare ~ back-clear-compare ~ L
+back-compare _ back-compare _ L
+back-compare ! back-compare ! L
+back-compare # back-compare # L
+back-compare $ back-compare $ L
+back-compare * back-compare * L
+back-compare + back-compare + L
+back-compare - back-compare - L
+back-compare . back-compare . L
+back-compare 0 back-compare 0 L
+back-compare 1 back-compare 1 L
+back-compare ; back-compare ; L
+back-compare > back-compare > L
+back-compare L back-compare L L
+back-compare R back-compare R L
+back-compare S back-compare S L
+back-compare ^ compare ^ R
+back-compare i back-compare i L
+back-compare o back-compare o L
+back-compare x back-compare x L
+back-compare | back-compare | L
+back-compare ~ back-compare ~ L
+back-copy-kind-! _ back-copy-kind-! _ L
+back-copy-kind-! ! back-copy-kind-! ! L
+back-copy-kind-! # back-copy-kind-! # L
+back-copy-kind-! $ back-copy-kind-! $ L
+back-copy-kind-! * back-copy-kind-! * L
+back-copy-kind-! + back-copy-kind-! + L
+back-copy-kind-! - back-copy-kind-! - L
+back-copy-kind-! . back-copy-kind-! . L
+back-copy-kind-! 0 back-copy-kind-! 0 L
+back-copy-kind-! 1 back-copy-kind-! 1 L
+back-copy-kind-! ; back-copy-kind-! ; L
+back-copy-kind-! > back-copy-kind-! > L
+back-copy-kind-! L back-copy-kind-! L L
+back-copy-kind-! R back-copy-kind-! R L
+back-copy-kind-! S back-copy-kind-! S L
+back-copy-kind-! ^ copy-kind-! ^ R
+back-copy-kind-! i back-copy-kind-! i L
+back-copy-kind-! o back-copy-kind-! o L
+back-copy-kind-! x back-copy-kind-! x L
+back-copy-kind-! | back-copy-kind-! | L
+back-copy-kind-! ~ back-copy-kind-! ~ L
+back-copy-kind-+ _ back-copy-kind-+ _ L
+back-copy-kind-+ ! back-copy-kind-+ ! L
+back-copy-kind-+ # back-copy-kind-+ # L
+back-copy-kind-+ $ back-copy-kind-+ $ L
+back-copy-kind-+ * back-copy-kind-+ * L
+back-copy-kind-+ + back-copy-kind-+ + L
+back-copy-kind-+ - back-copy-kind-+ - L
+back-copy-kind-+ . back-copy-kind-+ . L
+back-copy-kind-+ 0 back-copy-kind-+ 0 L
+back-copy-kind-+ 1 back-copy-kind-+ 1 L
+back-copy-kind-+ ; back-copy-kind-+ ; L
+back-copy-kind-+ > back-copy-kind-+ > L
+back-copy-kind-+ L back-copy-kind-+ L L
+back-copy-kind-+ R back-copy-kind-+ R L
+back-copy-kind-+ S back-copy-kind-+ S L
+back-copy-kind-+ ^ copy-kind-+ ^ R
+back-copy-kind-+ i back-copy-kind-+ i L
+back-copy-kind-+ o back-copy-kind-+ o L
+back-copy-kind-+ x back-copy-kind-+ x L
+back-copy-kind-+ | back-copy-kind-+ | L
+back-copy-kind-+ ~ back-copy-kind-+ ~ L
+back-copy-kind-- _ back-copy-kind-- _ L
+back-copy-kind-- ! back-copy-kind-- ! L
+back-copy-kind-- # back-copy-kind-- # L
+back-copy-kind-- $ back-copy-kind-- $ L
+back-copy-kind-- * back-copy-kind-- * L
+back-copy-kind-- + back-copy-kind-- + L
+back-copy-kind-- - back-copy-kind-- - L
+back-copy-kind-- . back-copy-kind-- . L
+back-copy-kind-- 0 back-copy-kind-- 0 L
+back-copy-kind-- 1 back-copy-kind-- 1 L
+back-copy-kind-- ; back-copy-kind-- ; L
+back-copy-kind-- > back-copy-kind-- > L
+back-copy-kind-- L back-copy-kind-- L L
+back-copy-kind-- R back-copy-kind-- R L
+back-copy-kind-- S back-copy-kind-- S L
+back-copy-kind-- ^ copy-kind-- ^ R
+back-copy-kind-- i back-copy-kind-- i L
+back-copy-kind-- o back-copy-kind-- o L
+back-copy-kind-- x back-copy-kind-- x L
+back-copy-kind-- | back-copy-kind-- | L
+back-copy-kind-- ~ back-copy-kind-- ~ L
+back-copy-kind-~ _ back-copy-kind-~ _ L
+back-copy-kind-~ ! back-copy-kind-~ ! L
+back-copy-kind-~ # back-copy-kind-~ # L
+back-copy-kind-~ $ back-copy-kind-~ $ L
+back-copy-kind-~ * back-copy-kind-~ * L
+back-copy-kind-~ + back-copy-kind-~ + L
+back-copy-kind-~ - back-copy-kind-~ - L
+back-copy-kind-~ . back-copy-kind-~ . L
+back-copy-kind-~ 0 back-copy-kind-~ 0 L
+back-copy-kind-~ 1 back-copy-kind-~ 1 L
+back-copy-kind-~ ; back-copy-kind-~ ; L
+back-copy-kind-~ > back-copy-kind-~ > L
+back-copy-kind-~ L back-copy-kind-~ L L
+back-copy-kind-~ R back-copy-kind-~ R L
+back-copy-kind-~ S back-copy-kind-~ S L
+back-copy-kind-~ ^ copy-kind-~ ^ R
+back-copy-kind-~ i back-copy-kind-~ i L
+back-copy-kind-~ o back-copy-kind-~ o L
+back-copy-kind-~ x back-copy-kind-~ x L
+back-copy-kind-~ | back-copy-kind-~ | L
+back-copy-kind-~ ~ back-copy-kind-~ ~ L
+back-copy-state-put-0 _ back-copy-state-put-0 _ L
+back-copy-state-put-0 ! back-copy-state-put-0 ! L
+back-copy-state-put-0 # back-copy-state-put-0 # L
+back-copy-state-put-0 $ back-copy-state-put-0 $ L
+back-copy-state-put-0 * back-copy-state-put-0 * L
+back-copy-state-put-0 + back-copy-state-put-0 + L
+back-copy-state-put-0 - back-copy-state-put-0 - L
+back-copy-state-put-0 . back-copy-state-put-0 . L
+back-copy-state-put-0 0 back-copy-state-put-0 0 L
+back-copy-state-put-0 1 back-copy-state-put-0 1 L
+back-copy-state-put-0 ; back-copy-state-put-0 ; L
+back-copy-state-put-0 > back-copy-state-put-0 > L
+back-copy-state-put-0 L back-copy-state-put-0 L L
+back-copy-state-put-0 R back-copy-state-put-0 R L
+back-copy-state-put-0 S back-copy-state-put-0 S L
+back-copy-state-put-0 ^ copy-state-put-0 ^ R
+back-copy-state-put-0 i back-copy-state-put-0 i L
+back-copy-state-put-0 o back-copy-state-put-0 o L
+back-copy-state-put-0 x back-copy-state-put-0 x L
+back-copy-state-put-0 | back-copy-state-put-0 | L
+back-copy-state-put-0 ~ back-copy-state-put-0 ~ L
+back-copy-state-put-1 _ back-copy-state-put-1 _ L
+back-copy-state-put-1 ! back-copy-state-put-1 ! L
+back-copy-state-put-1 # back-copy-state-put-1 # L
+back-copy-state-put-1 $ back-copy-state-put-1 $ L
+back-copy-state-put-1 * back-copy-state-put-1 * L
+back-copy-state-put-1 + back-copy-state-put-1 + L
+back-copy-state-put-1 - back-copy-state-put-1 - L
+back-copy-state-put-1 . back-copy-state-put-1 . L
+back-copy-state-put-1 0 back-copy-state-put-1 0 L
+back-copy-state-put-1 1 back-copy-state-put-1 1 L
+back-copy-state-put-1 ; back-copy-state-put-1 ; L
+back-copy-state-put-1 > back-copy-state-put-1 > L
+back-copy-state-put-1 L back-copy-state-put-1 L L
+back-copy-state-put-1 R back-copy-state-put-1 R L
+back-copy-state-put-1 S back-copy-state-put-1 S L
+back-copy-state-put-1 ^ copy-state-put-1 ^ R
+back-copy-state-put-1 i back-copy-state-put-1 i L
+back-copy-state-put-1 o back-copy-state-put-1 o L
+back-copy-state-put-1 x back-copy-state-put-1 x L
+back-copy-state-put-1 | back-copy-state-put-1 | L
+back-copy-state-put-1 ~ back-copy-state-put-1 ~ L
+back-extend _ back-extend _ L
+back-extend ! back-extend ! L
+back-extend # back-extend # L
+back-extend $ back-extend $ L
+back-extend * back-extend * L
+back-extend + back-extend + L
+back-extend - back-extend - L
+back-extend . back-extend . L
+back-extend 0 back-extend 0 L
+back-extend 1 back-extend 1 L
+back-extend ; back-extend ; L
+back-extend > back-extend > L
+back-extend L back-extend L L
+back-extend R back-extend R L
+back-extend S back-extend S L
+back-extend ^ extend ^ R
+back-extend i back-extend i L
+back-extend o back-extend o L
+back-extend x back-extend x L
+back-extend | back-extend | L
+back-extend ~ back-extend ~ L
+back-fetch _ back-fetch _ L
+back-fetch ! back-fetch ! L
+back-fetch # back-fetch # L
+back-fetch $ back-fetch $ L
+back-fetch * back-fetch * L
+back-fetch + back-fetch + L
+back-fetch - back-fetch - L
+back-fetch . back-fetch . L
+back-fetch 0 back-fetch 0 L
+back-fetch 1 back-fetch 1 L
+back-fetch ; back-fetch ; L
+back-fetch > back-fetch > L
+back-fetch L back-fetch L L
+back-fetch R back-fetch R L
+back-fetch S back-fetch S L
+back-fetch ^ fetch ^ R
+back-fetch i back-fetch i L
+back-fetch o back-fetch o L
+back-fetch x back-fetch x L
+back-fetch | back-fetch | L
+back-fetch ~ back-fetch ~ L
+back-fetch-put-0 _ back-fetch-put-0 _ L
+back-fetch-put-0 ! back-fetch-put-0 ! L
+back-fetch-put-0 # back-fetch-put-0 # L
+back-fetch-put-0 $ back-fetch-put-0 $ L
+back-fetch-put-0 * back-fetch-put-0 * L
+back-fetch-put-0 + back-fetch-put-0 + L
+back-fetch-put-0 - back-fetch-put-0 - L
+back-fetch-put-0 . back-fetch-put-0 . L
+back-fetch-put-0 0 back-fetch-put-0 0 L
+back-fetch-put-0 1 back-fetch-put-0 1 L
+back-fetch-put-0 ; back-fetch-put-0 ; L
+back-fetch-put-0 > back-fetch-put-0 > L
+back-fetch-put-0 L back-fetch-put-0 L L
+back-fetch-put-0 R back-fetch-put-0 R L
+back-fetch-put-0 S back-fetch-put-0 S L
+back-fetch-put-0 ^ fetch-put-0 ^ R
+back-fetch-put-0 i back-fetch-put-0 i L
+back-fetch-put-0 o back-fetch-put-0 o L
+back-fetch-put-0 x back-fetch-put-0 x L
+back-fetch-put-0 | back-fetch-put-0 | L
+back-fetch-put-0 ~ back-fetch-put-0 ~ L
+back-fetch-put-1 _ back-fetch-put-1 _ L
+back-fetch-put-1 ! back-fetch-put-1 ! L
+back-fetch-put-1 # back-fetch-put-1 # L
+back-fetch-put-1 $ back-fetch-put-1 $ L
+back-fetch-put-1 * back-fetch-put-1 * L
+back-fetch-put-1 + back-fetch-put-1 + L
+back-fetch-put-1 - back-fetch-put-1 - L
+back-fetch-put-1 . back-fetch-put-1 . L
+back-fetch-put-1 0 back-fetch-put-1 0 L
+back-fetch-put-1 1 back-fetch-put-1 1 L
+back-fetch-put-1 ; back-fetch-put-1 ; L
+back-fetch-put-1 > back-fetch-put-1 > L
+back-fetch-put-1 L back-fetch-put-1 L L
+back-fetch-put-1 R back-fetch-put-1 R L
+back-fetch-put-1 S back-fetch-put-1 S L
+back-fetch-put-1 ^ fetch-put-1 ^ R
+back-fetch-put-1 i back-fetch-put-1 i L
+back-fetch-put-1 o back-fetch-put-1 o L
+back-fetch-put-1 x back-fetch-put-1 x L
+back-fetch-put-1 | back-fetch-put-1 | L
+back-fetch-put-1 ~ back-fetch-put-1 ~ L
+cleanup _ cleanup _ R
+cleanup ! cleanup ! R
+cleanup # cleanup # R
+cleanup $ back-check $ L
+cleanup * cleanup * R
+cleanup + cleanup + R
+cleanup - cleanup - R
+cleanup . cleanup . R
+cleanup 0 cleanup 0 R
+cleanup 1 cleanup 1 R
+cleanup ; cleanup ; R
+cleanup > cleanup > R
+cleanup L cleanup L R
+cleanup R cleanup R R
+cleanup S cleanup S R
+cleanup ^ cleanup ^ R
+cleanup i cleanup 1 R
+cleanup o cleanup 0 R
+cleanup x cleanup ; R
+cleanup | cleanup | R
+cleanup ~ cleanup ~ R
+clear-compare ! clear-compare ! R
+clear-compare # back-compare # L
+clear-compare + clear-compare + R
+clear-compare - clear-compare - R
+clear-compare . clear-compare . R
+clear-compare 0 clear-compare 0 R
+clear-compare 1 clear-compare 1 R
+clear-compare i clear-compare 1 R
+clear-compare o clear-compare 0 R
+clear-compare ~ clear-compare ~ R
+compare ! apply ! R
+compare + apply + R
+compare - apply - R
+compare . compare . R
+compare 0 compare-sweep-0 o R
+compare 1 compare-sweep-1 i R
+compare i compare i R
+compare o compare o R
+compare ~ apply ~ R
+compare-bit-0 . compare-bit-0 . R
+compare-bit-0 0 compare-sweep-0 o R
+compare-bit-0 1 compare-rule-out-0 1 L
+compare-bit-0 i compare-bit-0 i R
+compare-bit-0 o compare-bit-0 o R
+compare-bit-1 . compare-bit-1 . R
+compare-bit-1 0 compare-rule-out-1 0 L
+compare-bit-1 1 compare-sweep-1 i R
+compare-bit-1 i compare-bit-1 i R
+compare-bit-1 o compare-bit-1 o R
+compare-rule-out-0 . compare-rule-out-0 . L
+compare-rule-out-0 0 compare-rule-out-0 0 L
+compare-rule-out-0 1 compare-rule-out-0 1 L
+compare-rule-out-0 ; compare-sweep-0 x R
+compare-rule-out-0 i compare-rule-out-0 i L
+compare-rule-out-0 o compare-rule-out-0 o L
+compare-rule-out-1 . compare-rule-out-1 . L
+compare-rule-out-1 0 compare-rule-out-1 0 L
+compare-rule-out-1 1 compare-rule-out-1 1 L
+compare-rule-out-1 ; compare-sweep-1 x R
+compare-rule-out-1 i compare-rule-out-1 i L
+compare-rule-out-1 o compare-rule-out-1 o L
+compare-sweep-0 _ compare-sweep-0 _ R
+compare-sweep-0 ! compare-sweep-0 ! R
+compare-sweep-0 # compare-sweep-0 # R
+compare-sweep-0 $ back-compare $ L
+compare-sweep-0 * compare-sweep-0 * R
+compare-sweep-0 + compare-sweep-0 + R
+compare-sweep-0 - compare-sweep-0 - R
+compare-sweep-0 . compare-sweep-0 . R
+compare-sweep-0 0 compare-sweep-0 0 R
+compare-sweep-0 1 compare-sweep-0 1 R
+compare-sweep-0 ; compare-bit-0 ; R
+compare-sweep-0 > compare-sweep-0 > R
+compare-sweep-0 L compare-sweep-0 L R
+compare-sweep-0 R compare-sweep-0 R R
+compare-sweep-0 S compare-sweep-0 S R
+compare-sweep-0 ^ compare-sweep-0 ^ R
+compare-sweep-0 i compare-sweep-0 i R
+compare-sweep-0 o compare-sweep-0 o R
+compare-sweep-0 x compare-sweep-0 x R
+compare-sweep-0 | compare-sweep-0 | R
+compare-sweep-0 ~ compare-sweep-0 ~ R
+compare-sweep-1 _ compare-sweep-1 _ R
+compare-sweep-1 ! compare-sweep-1 ! R
+compare-sweep-1 # compare-sweep-1 # R
+compare-sweep-1 $ back-compare $ L
+compare-sweep-1 * compare-sweep-1 * R
+compare-sweep-1 + compare-sweep-1 + R
+compare-sweep-1 - compare-sweep-1 - R
+compare-sweep-1 . compare-sweep-1 . R
+compare-sweep-1 0 compare-sweep-1 0 R
+compare-sweep-1 1 compare-sweep-1 1 R
+compare-sweep-1 ; compare-bit-1 ; R
+compare-sweep-1 > compare-sweep-1 > R
+compare-sweep-1 L compare-sweep-1 L R
+compare-sweep-1 R compare-sweep-1 R R
+compare-sweep-1 S compare-sweep-1 S R
+compare-sweep-1 ^ compare-sweep-1 ^ R
+compare-sweep-1 i compare-sweep-1 i R
+compare-sweep-1 o compare-sweep-1 o R
+compare-sweep-1 x compare-sweep-1 x R
+compare-sweep-1 | compare-sweep-1 | R
+compare-sweep-1 ~ compare-sweep-1 ~ R
+copy-kind-! ! write ! R
+copy-kind-! + write ! R
+copy-kind-! - write ! R
+copy-kind-! . copy-kind-! . R
+copy-kind-! 0 copy-kind-! 0 R
+copy-kind-! 1 copy-kind-! 1 R
+copy-kind-! i copy-kind-! i R
+copy-kind-! o copy-kind-! o R
+copy-kind-! ~ write ! R
+copy-kind-+ ! write + R
+copy-kind-+ + write + R
+copy-kind-+ - write + R
+copy-kind-+ . copy-kind-+ . R
+copy-kind-+ 0 copy-kind-+ 0 R
+copy-kind-+ 1 copy-kind-+ 1 R
+copy-kind-+ i copy-kind-+ i R
+copy-kind-+ o copy-kind-+ o R
+copy-kind-+ ~ write + R
+copy-kind-- ! write - R
+copy-kind-- + write - R
+copy-kind-- - write - R
+copy-kind-- . copy-kind-- . R
+copy-kind-- 0 copy-kind-- 0 R
+copy-kind-- 1 copy-kind-- 1 R
+copy-kind-- i copy-kind-- i R
+copy-kind-- o copy-kind-- o R
+copy-kind-- ~ write - R
+copy-kind-~ ! write ~ R
+copy-kind-~ + write ~ R
+copy-kind-~ - write ~ R
+copy-kind-~ . copy-kind-~ . R
+copy-kind-~ 0 copy-kind-~ 0 R
+copy-kind-~ 1 copy-kind-~ 1 R
+copy-kind-~ i copy-kind-~ i R
+copy-kind-~ o copy-kind-~ o R
+copy-kind-~ ~ write ~ R
+copy-state ! back-copy-kind-! ! L
+copy-state + back-copy-kind-+ + L
+copy-state - back-copy-kind-- - L
+copy-state 0 back-copy-state-put-0 o L
+copy-state 1 back-copy-state-put-1 i L
+copy-state i copy-state i R
+copy-state o copy-state o R
+copy-state ~ back-copy-kind-~ ~ L
+copy-state-into-0 0 copy-state-into-0 0 R
+copy-state-into-0 1 copy-state-into-0 1 R
+copy-state-into-0 i apply 0 R
+copy-state-into-0 o apply 0 R
+copy-state-into-1 0 copy-state-into-1 0 R
+copy-state-into-1 1 copy-state-into-1 1 R
+copy-state-into-1 i apply 1 R
+copy-state-into-1 o apply 1 R
+copy-state-put-0 _ copy-state-put-0 _ R
+copy-state-put-0 ! copy-state-put-0 ! R
+copy-state-put-0 # copy-state-put-0 # R
+copy-state-put-0 $ copy-state-put-0 $ R
+copy-state-put-0 * copy-state-put-0 * R
+copy-state-put-0 + copy-state-put-0 + R
+copy-state-put-0 - copy-state-put-0 - R
+copy-state-put-0 . copy-state-into-0 . R
+copy-state-put-0 0 copy-state-put-0 0 R
+copy-state-put-0 1 copy-state-put-0 1 R
+copy-state-put-0 ; copy-state-put-0 ; R
+copy-state-put-0 > copy-state-put-0 > R
+copy-state-put-0 L copy-state-put-0 L R
+copy-state-put-0 R copy-state-put-0 R R
+copy-state-put-0 S copy-state-put-0 S R
+copy-state-put-0 ^ copy-state-put-0 ^ R
+copy-state-put-0 i copy-state-put-0 i R
+copy-state-put-0 o copy-state-put-0 o R
+copy-state-put-0 x copy-state-put-0 x R
+copy-state-put-0 | copy-state-put-0 | R
+copy-state-put-0 ~ copy-state-put-0 ~ R
+copy-state-put-1 _ copy-state-put-1 _ R
+copy-state-put-1 ! copy-state-put-1 ! R
+copy-state-put-1 # copy-state-put-1 # R
+copy-state-put-1 $ copy-state-put-1 $ R
+copy-state-put-1 * copy-state-put-1 * R
+copy-state-put-1 + copy-state-put-1 + R
+copy-state-put-1 - copy-state-put-1 - R
+copy-state-put-1 . copy-state-into-1 . R
+copy-state-put-1 0 copy-state-put-1 0 R
+copy-state-put-1 1 copy-state-put-1 1 R
+copy-state-put-1 ; copy-state-put-1 ; R
+copy-state-put-1 > copy-state-put-1 > R
+copy-state-put-1 L copy-state-put-1 L R
+copy-state-put-1 R copy-state-put-1 R R
+copy-state-put-1 S copy-state-put-1 S R
+copy-state-put-1 ^ copy-state-put-1 ^ R
+copy-state-put-1 i copy-state-put-1 i R
+copy-state-put-1 o copy-state-put-1 o R
+copy-state-put-1 x copy-state-put-1 x R
+copy-state-put-1 | copy-state-put-1 | R
+copy-state-put-1 ~ copy-state-put-1 ~ R
+extend . back-cleanup . L
+extend 0 extend 0 R
+extend 1 extend 1 R
+extend i extend-put 1 R
+extend o extend-put 0 R
+extend-put _ back-extend 0 L
+extend-put ! extend-put ! R
+extend-put # extend-put # R
+extend-put $ extend-put $ R
+extend-put * extend-put * R
+extend-put + extend-put + R
+extend-put - extend-put - R
+extend-put . extend-put . R
+extend-put 0 extend-put 0 R
+extend-put 1 extend-put 1 R
+extend-put ; extend-put ; R
+extend-put > extend-put > R
+extend-put L extend-put L R
+extend-put R extend-put R R
+extend-put S extend-put S R
+extend-put ^ extend-put ^ R
+extend-put i extend-put i R
+extend-put o extend-put o R
+extend-put x extend-put x R
+extend-put | extend-put | R
+extend-put ~ extend-put ~ R
+fetch _ fetch _ R
+fetch ! fetch ! R
+fetch # fetch # R
+fetch $ fetch $ R
+fetch * fetch-cell * R
+fetch + fetch + R
+fetch - fetch - R
+fetch . fetch . R
+fetch 0 fetch 0 R
+fetch 1 fetch 1 R
+fetch ; fetch ; R
+fetch > fetch > R
+fetch L fetch L R
+fetch R fetch R R
+fetch S fetch S R
+fetch ^ fetch ^ R
+fetch i fetch i R
+fetch o fetch o R
+fetch x fetch x R
+fetch | fetch | R
+fetch ~ fetch ~ R
+fetch-cell _ back-clear-compare _ L
+fetch-cell 0 back-fetch-put-0 o L
+fetch-cell 1 back-fetch-put-1 i L
+fetch-cell i fetch-cell i R
+fetch-cell o fetch-cell o R
+fetch-cell | back-clear-compare | L
+fetch-put-0 0 fetch o R
+fetch-put-0 1 fetch o R
+fetch-put-0 i fetch-put-0 i R
+fetch-put-0 o fetch-put-0 o R
+fetch-put-1 0 fetch i R
+fetch-put-1 1 fetch i R
+fetch-put-1 i fetch-put-1 i R
+fetch-put-1 o fetch-put-1 o R
+move-L _ move-L _ R
+move-L ! move-L ! R
+move-L # move-L # R
+move-L $ move-L $ R
+move-L * move-left | L
+move-L + move-L + R
+move-L - move-L - R
+move-L . move-L . R
+move-L 0 move-L 0 R
+move-L 1 move-L 1 R
+move-L ; move-L ; R
+move-L > move-L > R
+move-L L move-L L R
+move-L R move-L R R
+move-L S move-L S R
+move-L ^ move-L ^ R
+move-L i move-L i R
+move-L o move-L o R
+move-L x move-L x R
+move-L | move-L | R
+move-L ~ move-L ~ R
+move-R _ move-R _ R
+move-R ! move-R ! R
+move-R # move-R # R
+move-R $ move-R $ R
+move-R * move-right | R
+move-R + move-R + R
+move-R - move-R - R
+move-R . move-R . R
+move-R 0 move-R 0 R
+move-R 1 move-R 1 R
+move-R ; move-R ; R
+move-R > move-R > R
+move-R L move-R L R
+move-R R move-R R R
+move-R S move-R S R
+move-R ^ move-R ^ R
+move-R i move-R i R
+move-R o move-R o R
+move-R x move-R x R
+move-R | move-R | R
+move-R ~ move-R ~ R
+move-S _ move-S _ R
+move-S ! move-S ! R
+move-S # move-S # R
+move-S $ move-S $ R
+move-S * back-cleanup * L
+move-S + move-S + R
+move-S - move-S - R
+move-S . move-S . R
+move-S 0 move-S 0 R
+move-S 1 move-S 1 R
+move-S ; move-S ; R
+move-S > move-S > R
+move-S L move-S L R
+move-S R move-S R R
+move-S S move-S S R
+move-S ^ move-S ^ R
+move-S i move-S i R
+move-S o move-S o R
+move-S x move-S x R
+move-S | move-S | R
+move-S ~ move-S ~ R
+move-left $ move-left-edge $ R
+move-left 0 move-left 0 L
+move-left 1 move-left 1 L
+move-left | back-cleanup * L
+move-left-edge | back-cleanup * L
+move-right _ back-extend * L
+move-right 0 move-right 0 R
+move-right 1 move-right 1 R
+move-right | back-cleanup * L
+write _ write _ R
+write ! write ! R
+write # write # R
+write $ write $ R
+write * write * R
+write + write + R
+write - write - R
+write . write . R
+write 0 write 0 R
+write 1 write 1 R
+write ; write-target ; R
+write > write > R
+write L write L R
+write R write R R
+write S write S R
+write ^ write ^ R
+write i write i R
+write o write o R
+write x write x R
+write | write | R
+write ~ write ~ R
+write-back _ write-back _ L
+write-back ! write-back ! L
+write-back # write-back # L
+write-back $ write-back $ L
+write-back * write-back * L
+write-back + write-back + L
+write-back - write-back - L
+write-back . write-back . L
+write-back 0 write-back 0 L
+write-back 1 write-back 1 L
+write-back ; write-target ; R
+write-back > write-back > L
+write-back L write-back L L
+write-back R write-back R L
+write-back S write-back S L
+write-back ^ write-back ^ L
+write-back i write-back i L
+write-back o write-back o L
+write-back x write-back x L
+write-back | write-back | L
+write-back ~ write-back ~ L
+write-into-0 0 write-into-0 0 R
+write-into-0 1 write-into-0 1 R
+write-into-0 i write-back 0 L
+write-into-0 o write-back 0 L
+write-into-1 0 write-into-1 0 R
+write-into-1 1 write-into-1 1 R
+write-into-1 i write-back 1 L
+write-into-1 o write-back 1 L
+write-put-0 _ write-put-0 _ R
+write-put-0 ! write-put-0 ! R
+write-put-0 # write-put-0 # R
+write-put-0 $ write-put-0 $ R
+write-put-0 * write-into-0 * R
+write-put-0 + write-put-0 + R
+write-put-0 - write-put-0 - R
+write-put-0 . write-put-0 . R
+write-put-0 0 write-put-0 0 R
+write-put-0 1 write-put-0 1 R
+write-put-0 ; write-put-0 ; R
+write-put-0 > write-put-0 > R
+write-put-0 L write-put-0 L R
+write-put-0 R write-put-0 R R
+write-put-0 S write-put-0 S R
+write-put-0 ^ write-put-0 ^ R
+write-put-0 i write-put-0 i R
+write-put-0 o write-put-0 o R
+write-put-0 x write-put-0 x R
+write-put-0 | write-put-0 | R
+write-put-0 ~ write-put-0 ~ R
+write-put-1 _ write-put-1 _ R
+write-put-1 ! write-put-1 ! R
+write-put-1 # write-put-1 # R
+write-put-1 $ write-put-1 $ R
+write-put-1 * write-into-1 * R
+write-put-1 + write-put-1 + R
+write-put-1 - write-put-1 - R
+write-put-1 . write-put-1 . R
+write-put-1 0 write-put-1 0 R
+write-put-1 1 write-put-1 1 R
+write-put-1 ; write-put-1 ; R
+write-put-1 > write-put-1 > R
+write-put-1 L write-put-1 L R
+write-put-1 R write-put-1 R R
+write-put-1 S write-put-1 S R
+write-put-1 ^ write-put-1 ^ R
+write-put-1 i write-put-1 i R
+write-put-1 o write-put-1 o R
+write-put-1 x write-put-1 x R
+write-put-1 | write-put-1 | R
+write-put-1 ~ write-put-1 ~ R
+write-symbol ! write-symbol ! R
+write-symbol + write-symbol + R
+write-symbol - write-symbol - R
+write-symbol 0 write-put-0 o R
+write-symbol 1 write-put-1 i R
+write-symbol L move-L L R
+write-symbol R move-R R R
+write-symbol S move-S S R
+write-symbol i write-symbol i R
+write-symbol o write-symbol o R
+write-symbol ~ write-symbol ~ R
+write-target _ write-target _ R
+write-target ! write-target ! R
+write-target # write-target # R
+write-target $ write-target $ R
+write-target * write-target * R
+write-target + write-target + R
+write-target - write-target - R
+write-target . write-target . R
+write-target 0 write-target 0 R
+write-target 1 write-target 1 R
+write-target ; write-target ; R
+write-target > write-symbol > R
+write-target L write-target L R
+write-target R write-target R R
+write-target S write-target S R
+write-target ^ write-target ^ R
+write-target i write-target i R
+write-target o write-target o R
+write-target x write-target x R
+write-target | write-target | R
+write-target ~ write-target ~ R
@@ -0,0 +1,276 @@
+use crate::{
+    builders::TuringMachineBuilder,
+    common::{Motion, StateTrait},
+    deterministic_tm::DeterministicTuringMachine,
+    machine_representation::MachineRepresentation,
+    tape::Tape,
+    transition_table::TransitionTable,
+    TuringMachine,
+};
+
+/// A wrapper struct, which takes a [`DeterministicTuringMachine`](../deterministic_tm/struct.DeterministicTuringMachine.html) and jumps across sweeps in a single operation.
+///
+/// A sweep is a transition that stays in its state and moves the head, as `(q, a) -> (q, b, R)`: the machine repeats it over the whole run of `a` under the head.
+/// The wrapper writes `b` over the run and moves past it at once, while counting every step it stands for.
+/// A [`RunLengthTape`](../tape/struct.RunLengthTape.html) finds and fills runs without visiting their cells, which is where the speedup comes from.
+///
+/// A sweep right over the blanks past the tape never ends, so the wrapper stops there and counts the machine as rejecting.
+#[derive(Debug)]
+pub struct AcceleratedTuringMachineExt<StateTy, TapeTy = Vec<char>>
+where
+    StateTy: StateTrait,
+    TapeTy: Tape,
+{
+    tm: DeterministicTuringMachine<StateTy, TapeTy>,
+    steps: usize,
+    macro_steps: usize,
+    looping: bool,
+}
+
+impl<StateTy, TapeTy> AcceleratedTuringMachineExt<StateTy, TapeTy>
+where
+    StateTy: StateTrait,
+    TapeTy: Tape,
+{
+    /// Decorate an existing machine
+    pub fn new(tm: DeterministicTuringMachine<StateTy, TapeTy>) -> Self {
+        Self {
+            tm,
+            steps: 0,
+            macro_steps: 0,
+            looping: false,
+        }
+    }
+
+    /// Return how many steps of the machine have been taken so far, counting each one a sweep stands for
+    pub fn get_number_of_steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Return how many operations the steps so far took, each a sweep or a single step
+    pub fn get_number_of_macro_steps(&self) -> usize {
+        self.macro_steps
+    }
+
+    /// Is the machine sweeping right over the blanks past the tape, which it does forever?
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Get the machine in its current configuration
+    pub fn machine(&self) -> &DeterministicTuringMachine<StateTy, TapeTy> {
+        &self.tm
+    }
+
+    pub fn inner(self) -> DeterministicTuringMachine<StateTy, TapeTy> {
+        self.tm
+    }
+
+    /// Take at most `max` steps in one operation, returning how many were taken.
+    /// This is the whole sweep the machine is in, cut short at `max`, or a single step otherwise.
+    /// No steps are taken once the machine is found sweeping over the blanks forever.
+    pub fn macro_step(&mut self, max: usize) -> usize {
+        if max == 0 || self.is_finished() {
+            return 0;
+        }
+
+        let head = self.tm.head();
+        let sweep = self
+            .tm
+            .representation()
+            .transition_table()
+            .apply_transition_table(self.tm.current_state(), self.tm.tape().read(head))
+            .filter(|act| act.next_state() == self.tm.current_state())
+            .map(|act| (*act.tape_output(), *act.motion()));
+
+        let taken = match sweep {
+            Some((output, Motion::Right)) => {
+                let run = self.tm.tape().run_right(head);
+                let end = match head.checked_add(run) {
+                    Some(_) if run != usize::MAX => head + run.min(max),
+                    _ => {
+                        self.looping = true;
+                        return 0;
+                    }
+                };
+                let (tape, head, _) = self.tm.configuration_mut();
+                let len = end - *head;
+                tape.fill(*head..end, output);
+                *head = end;
+                len
+            }
+            Some((output, Motion::Left)) => {
+                // Moving left from the first cell stays there, so a run reaching it ends the sweep on it
+                let len = self.tm.tape().run_left(head).min(max);
+                let (tape, head, _) = self.tm.configuration_mut();
+                tape.fill(*head + 1 - len..*head + 1, output);
+                *head = (*head + 1 - len).saturating_sub(1);
+                len
+            }
+            _ => {
+                self.tm.step();
+                1
+            }
+        };
+        self.steps += taken;
+        self.macro_steps += 1;
+        taken
+    }
+}
+
+/// Each step of the wrapper is a [`macro_step`](struct.AcceleratedTuringMachineExt.html#method.macro_step), which may stand for many steps of the machine
+impl<StateTy, TapeTy> TuringMachine for AcceleratedTuringMachineExt<StateTy, TapeTy>
+where
+    StateTy: StateTrait,
+    TapeTy: Tape,
+{
+    type Tape = TapeTy;
+    type StateTy = StateTy;
+    type ReprTy = <DeterministicTuringMachine<StateTy, TapeTy> as TuringMachine>::ReprTy;
+    type ErrorTy = <DeterministicTuringMachine<StateTy, TapeTy> as TuringMachine>::ErrorTy;
+
+    fn from_builder(
+        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
+    ) -> Result<Self, Self::ErrorTy> {
+        Ok(Self::new(DeterministicTuringMachine::from_builder(
+            builder,
+        )?))
+    }
+
+    /// Take the whole sweep, as long as the steps can still be counted
+    fn step(&mut self) {
+        self.macro_step(usize::MAX - self.steps);
+    }
+
+    fn tape(&self) -> &Self::Tape {
+        self.tm.tape()
+    }
+
+    fn is_accepting(&self) -> bool {
+        self.tm.is_accepting()
+    }
+
+    fn is_rejecting(&self) -> bool {
+        self.looping || self.tm.is_rejecting()
+    }
+
+    fn is_halting(&self) -> bool {
+        self.tm.is_halting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deterministic_tm::DeterministicMachineRepresentation, machine_parser, tape::RunLengthTape,
+    };
+
+    fn machine<TapeTy: Tape>(
+        source: &str,
+        tape: &str,
+    ) -> DeterministicTuringMachine<String, TapeTy> {
+        let repr = DeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(source.as_bytes()).unwrap(),
+        )
+        .unwrap();
+        DeterministicTuringMachine::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape(tape.chars().collect()),
+        )
+        .unwrap()
+    }
+
+    fn configuration<TapeTy: Tape>(
+        tm: &DeterministicTuringMachine<String, TapeTy>,
+    ) -> (String, usize, TapeTy) {
+        (tm.current_state().clone(), tm.head(), tm.tape().clone())
+    }
+
+    /// Check the accelerated machine is in the same configuration as the plain one after every step in `checkpoints`
+    fn follows<TapeTy: Tape>(source: &str, tape: &str, checkpoints: &[usize]) {
+        let mut plain = machine::<TapeTy>(source, tape);
+        let mut fast = AcceleratedTuringMachineExt::new(machine::<TapeTy>(source, tape));
+        let mut steps = 0;
+        for checkpoint in checkpoints {
+            while steps < *checkpoint && !plain.is_finished() {
+                plain.step();
+                steps += 1;
+            }
+            while fast.get_number_of_steps() < steps {
+                let left = steps - fast.get_number_of_steps();
+                assert!(fast.macro_step(left) > 0);
+            }
+            assert_eq!(fast.get_number_of_steps(), steps);
+            assert_eq!(configuration(fast.machine()), configuration(&plain));
+            assert_eq!(fast.is_finished(), plain.is_finished());
+        }
+    }
+
+    #[test]
+    fn follows_the_plain_machine() {
+        let checkpoints: Vec<usize> = (0..60).map(|i| i * i * 37).collect();
+        let sqrt2 = include_str!("../data/sqrt2.tm");
+        follows::<Vec<char>>(sqrt2, "", &checkpoints);
+        follows::<RunLengthTape>(sqrt2, "", &checkpoints);
+
+        let binadd = include_str!("../data/binadd.tm");
+        let sum = format!("{}#{}", "10".repeat(20), "1".repeat(30));
+        follows::<Vec<char>>(binadd, &sum, &checkpoints);
+        follows::<RunLengthTape>(binadd, &sum, &checkpoints);
+
+        let paren = include_str!("../data/paren.tm");
+        let nested = format!("{}{}", "(".repeat(50), ")".repeat(50));
+        follows::<RunLengthTape>(paren, &nested, &[1, 2, 3, 100, 1000, 10_000]);
+    }
+
+    #[test]
+    fn stops_exactly() {
+        let paren = include_str!("../data/paren.tm");
+        let nested = format!("{}{}", "(".repeat(300), ")".repeat(300));
+        let mut plain = machine::<Vec<char>>(paren, &nested);
+        let mut steps = 0;
+        while !plain.is_finished() {
+            plain.step();
+            steps += 1;
+        }
+
+        let mut fast = AcceleratedTuringMachineExt::new(machine::<RunLengthTape>(paren, &nested));
+        assert!(fast.run());
+        assert_eq!(fast.get_number_of_steps(), steps);
+        assert!(fast.get_number_of_macro_steps() * 10 < steps);
+        assert_eq!(fast.macro_step(10), 0);
+    }
+
+    #[test]
+    fn sweeps_to_the_first_cell() {
+        let sweep = "states 3\nback\nhalt !\nreject -\nalphabet 1 1\nback 1 back 1 L\n";
+        let mut tm = machine::<RunLengthTape>(sweep, "111");
+        *tm.configuration_mut().1 = 2;
+
+        // The head stays on the first cell, reading 1 forever
+        let mut fast = AcceleratedTuringMachineExt::new(tm);
+        assert_eq!(fast.macro_step(1000), 3);
+        assert_eq!(fast.machine().head(), 0);
+        assert_eq!(fast.macro_step(1000), 1);
+        assert_eq!(fast.machine().head(), 0);
+        assert!(!fast.is_finished());
+    }
+
+    #[test]
+    fn stops_sweeping_the_blanks() {
+        let sweep = "states 3\nq0\nhalt !\nreject -\nalphabet 1 1\nq0 _ q0 _ R\nq0 1 q0 1 R\n";
+        let mut fast = AcceleratedTuringMachineExt::new(machine::<Vec<char>>(sweep, "11"));
+        fast.step();
+        fast.step();
+        assert!(fast.is_looping());
+        assert!(fast.is_rejecting());
+        assert_eq!(fast.get_number_of_steps(), 2);
+        assert_eq!(fast.macro_step(10), 0);
+
+        let mut fast = AcceleratedTuringMachineExt::new(machine::<RunLengthTape>(sweep, ""));
+        assert!(!fast.run());
+        assert_eq!(fast.get_number_of_steps(), 0);
+    }
+}
@@ -0,0 +1,208 @@
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign},
+};
+
+/// An unsigned integer of any size, for counts which could overflow a `usize`.
+/// Only what counting steps needs is supported: adding, comparing and printing.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct BigUint {
+    /// Base 2^32 digits, least significant first, without trailing zeros
+    digits: Vec<u32>,
+}
+
+impl BigUint {
+    pub fn zero() -> Self {
+        Self::default()
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.digits.is_empty()
+    }
+
+    /// Get the value back as a `u128`, if it fits
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.digits.len() > 4 {
+            return None;
+        }
+        Some(
+            self.digits
+                .iter()
+                .rev()
+                .fold(0, |acc, d| (acc << 32) | u128::from(*d)),
+        )
+    }
+
+    /// Subtract `other`, if it is not greater
+    pub fn checked_sub(&self, other: &BigUint) -> Option<BigUint> {
+        if *self < *other {
+            return None;
+        }
+        let mut digits = Vec::with_capacity(self.digits.len());
+        let mut borrow = 0;
+        for (i, d) in self.digits.iter().enumerate() {
+            let o = u64::from(other.digits.get(i).cloned().unwrap_or(0)) + borrow;
+            let d = u64::from(*d);
+            if d >= o {
+                digits.push((d - o) as u32);
+                borrow = 0;
+            } else {
+                digits.push((d + (1 << 32) - o) as u32);
+                borrow = 1;
+            }
+        }
+        let mut res = BigUint { digits };
+        res.trim();
+        Some(res)
+    }
+
+    fn trim(&mut self) {
+        while self.digits.last() == Some(&0) {
+            self.digits.pop();
+        }
+    }
+}
+
+impl From<u128> for BigUint {
+    fn from(mut n: u128) -> Self {
+        let mut digits = Vec::new();
+        while n > 0 {
+            digits.push(n as u32);
+            n >>= 32;
+        }
+        BigUint { digits }
+    }
+}
+
+impl From<u64> for BigUint {
+    fn from(n: u64) -> Self {
+        BigUint::from(u128::from(n))
+    }
+}
+
+impl AddAssign<&BigUint> for BigUint {
+    fn add_assign(&mut self, other: &BigUint) {
+        if self.digits.len() < other.digits.len() {
+            self.digits.resize(other.digits.len(), 0);
+        }
+        let mut carry = 0;
+        for (i, d) in self.digits.iter_mut().enumerate() {
+            let sum = u64::from(*d) + u64::from(other.digits.get(i).cloned().unwrap_or(0)) + carry;
+            *d = sum as u32;
+            carry = sum >> 32;
+            if carry == 0 && i >= other.digits.len() {
+                break;
+            }
+        }
+        if carry > 0 {
+            self.digits.push(carry as u32);
+        }
+    }
+}
+
+impl AddAssign<u128> for BigUint {
+    fn add_assign(&mut self, other: u128) {
+        *self += &BigUint::from(other);
+    }
+}
+
+impl Add<u128> for BigUint {
+    type Output = BigUint;
+
+    fn add(mut self, other: u128) -> BigUint {
+        self += other;
+        self
+    }
+}
+
+impl Ord for BigUint {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.digits
+            .len()
+            .cmp(&other.digits.len())
+            .then_with(|| self.digits.iter().rev().cmp(other.digits.iter().rev()))
+    }
+}
+
+impl PartialOrd for BigUint {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for BigUint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Split into base 10^9 chunks, most significant last, by long division
+        const CHUNK: u64 = 1_000_000_000;
+        let mut digits = self.digits.clone();
+        let mut chunks = Vec::new();
+        while !digits.is_empty() {
+            let mut rem = 0;
+            for d in digits.iter_mut().rev() {
+                let cur = (rem << 32) | u64::from(*d);
+                *d = (cur / CHUNK) as u32;
+                rem = cur % CHUNK;
+            }
+            chunks.push(rem);
+            while digits.last() == Some(&0) {
+                digits.pop();
+            }
+        }
+
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((first, rest)) => {
+                write!(f, "{}", first)?;
+                for chunk in rest.iter().rev() {
+                    write!(f, "{:09}", chunk)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn adds_past_u128() {
+        let mut n = BigUint::from(u128::MAX);
+        n += 1;
+        assert_eq!(n.to_u128(), None);
+        assert_eq!(n.to_string(), "340282366920938463463374607431768211456");
+
+        n += &n.clone();
+        assert_eq!(n.to_string(), "680564733841876926926749214863536422912");
+        assert!(n > BigUint::from(u128::MAX));
+    }
+
+    #[test]
+    fn agrees_with_u128() {
+        let mut seed = 0x9e37_79b9_7f4a_7c15_u64;
+        let mut big = BigUint::zero();
+        let mut small = 0u128;
+        for _ in 0..1000 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let n = u128::from(seed) * u128::from(seed >> 20);
+            big += n;
+            small += n;
+            assert_eq!(big.to_u128(), Some(small));
+            assert_eq!(big.to_string(), small.to_string());
+            assert_eq!(big.cmp(&BigUint::from(n)), small.cmp(&n));
+        }
+
+        let n = BigUint::from(12_345_678_901_234_567_890_u128);
+        assert_eq!(
+            big.checked_sub(&n).unwrap().to_u128(),
+            Some(small - 12_345_678_901_234_567_890)
+        );
+        assert_eq!(n.checked_sub(&big), None);
+        assert!(big.checked_sub(&big).unwrap().is_zero());
+        assert_eq!(BigUint::zero().to_string(), "0");
+    }
+}
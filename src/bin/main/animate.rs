@@ -0,0 +1,215 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+
+use turing_machine::{
+    builders::TuringMachineBuilder,
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    hooked::{HookedTuringMachineExt, StepHook},
+    TuringMachine,
+};
+
+use crate::{handle_error, load_repr, parse_optional_arg, read_tape_file, ErrorType};
+
+#[cfg(unix)]
+mod terminal;
+
+type T = DeterministicTuringMachine<String>;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("animate")
+        .about("Animate a deterministic machine in the terminal, printing a line per step if not a TTY or not on Unix")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("tapefile")
+                .index(2)
+                .value_name("TAPE_FILE")
+                .help("A file containing the tape the machine should start on"),
+        )
+        .arg(
+            Arg::with_name("tapevalue")
+                .short("T")
+                .long("tape")
+                .conflicts_with("tapefile")
+                .takes_value(true)
+                .value_name("TAPE")
+                .help("Inline tape for testing"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .short("l")
+                .long("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .help("Stop after this many steps"),
+        )
+        .arg(
+            Arg::with_name("paused")
+                .long("paused")
+                .short("p")
+                .help("Start paused, waiting for keys to step"),
+        )
+}
+
+/// The cells of the tape starting at `start`, `width` of them, padded with blanks
+fn cells(tape: &[char], start: usize, width: usize) -> impl Iterator<Item = char> + '_ {
+    (start..start + width).map(move |i| *tape.get(i).unwrap_or(&'_'))
+}
+
+/// The line printed for a configuration reached after `steps` steps, with up to 10 cells each side of the head
+fn trace_line(tm: &T, steps: usize) -> String {
+    let start = tm.head().saturating_sub(10);
+    let tape: String = cells(tm.tape(), start, tm.head() - start)
+        .chain(Some('['))
+        .chain(cells(tm.tape(), tm.head(), 1))
+        .chain(Some(']'))
+        .chain(cells(tm.tape(), tm.head() + 1, 10))
+        .collect();
+    format!("{} {} @{} {}", steps, tm.current_state(), tm.head(), tape)
+}
+
+fn verdict(tm: &T) -> &'static str {
+    if tm.is_accepting() {
+        "accepted"
+    } else if tm.is_halting() {
+        "halted"
+    } else if tm.is_rejecting() {
+        "not accepted"
+    } else {
+        "stopped"
+    }
+}
+
+/// Prints a line per configuration, for when stdout is not a terminal
+#[derive(Default)]
+struct Trace {
+    steps: Option<usize>,
+}
+
+impl StepHook<T> for Trace {
+    fn on_step(&mut self, tm: &T) {
+        let steps = self.steps.map_or(0, |s| s + 1);
+        self.steps = Some(steps);
+        println!("{}", trace_line(tm, steps));
+    }
+}
+
+fn animate<H>(tm: T, hook: H, limit: Option<usize>, stop: fn(&H) -> bool) -> (T, H)
+where
+    H: StepHook<T> + Default,
+{
+    let mut tm = HookedTuringMachineExt::new(tm, hook);
+    let mut steps = 0;
+    while !tm.is_finished() && !stop(tm.hook()) && limit.is_none_or(|limit| steps < limit) {
+        tm.step();
+        steps += 1;
+    }
+    tm.into_parts()
+}
+
+/// Print a line per configuration
+fn trace(tm: T, limit: Option<usize>) -> T {
+    animate(tm, Trace::default(), limit, |_| false).0
+}
+
+/// Runs the `animate` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+    let limit = match parse_optional_arg(matches, "limit") {
+        Ok(limit) => limit,
+        Err(code) => return code,
+    };
+    let tape = match (matches.value_of("tapefile"), matches.value_of("tapevalue")) {
+        (Some(path), _) => match read_tape_file(path) {
+            Ok(tape) => tape,
+            Err(e) => return handle_error::<T>(ErrorType::IO(e)),
+        },
+        (None, Some(tape)) => tape.chars().collect(),
+        (None, None) => Vec::new(),
+    };
+
+    let repr = match load_repr::<T, DeterministicMachineRepresentation<String>>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+    let tm = match T::from_builder(TuringMachineBuilder::new().repr(repr).tape(tape)) {
+        Ok(tm) => tm,
+        Err(e) => return handle_error::<T>(ErrorType::MachineCreation(e)),
+    };
+
+    #[cfg(unix)]
+    let tm = match terminal::play(tm, limit, matches.is_present("paused")) {
+        Ok(tm) => tm,
+        Err(e) => return handle_error::<T>(ErrorType::IO(e)),
+    };
+    // termion only supports Unix, so elsewhere there is no animation
+    #[cfg(not(unix))]
+    let tm = trace(tm, limit);
+
+    println!("{}", verdict(&tm));
+    if tm.is_accepting() || tm.is_halting() {
+        0
+    } else {
+        1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use turing_machine::{machine_parser, machine_representation::MachineRepresentation};
+
+    pub(super) fn paren(tape: &str) -> T {
+        let repr = DeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(include_str!("../../../data/paren.tm").as_bytes()).unwrap(),
+        )
+        .unwrap();
+        T::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape(tape.chars().collect()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn pads_cells_with_blanks() {
+        let tape = ['a', 'b'];
+        assert_eq!(cells(&tape, 0, 4).collect::<String>(), "ab__");
+        assert_eq!(cells(&tape, 1, 2).collect::<String>(), "b_");
+        assert_eq!(cells(&tape, 5, 0).collect::<String>(), "");
+    }
+
+    #[test]
+    fn gives_verdicts() {
+        let mut tm = paren("()");
+        assert_eq!(verdict(&tm), "stopped");
+        while !tm.is_finished() {
+            tm.step();
+        }
+        assert_eq!(verdict(&tm), "accepted");
+
+        let mut tm = paren(")");
+        tm.step();
+        assert_eq!(verdict(&tm), "not accepted");
+    }
+
+    #[test]
+    fn traces_configurations() {
+        let mut tm = paren("(())");
+        assert_eq!(trace_line(&tm, 0), "0 start @0 [(]())_______");
+        tm.step();
+        assert_eq!(trace_line(&tm, 1), "1 close @1 ^[(]))________");
+
+        // At most 10 cells are shown before the head
+        let mut tm = paren("(((((((((((((())");
+        for _ in 0..12 {
+            tm.step();
+        }
+        assert_eq!(trace_line(&tm, 12), "12 close @12 (((((((((([(]())_______");
+    }
+}
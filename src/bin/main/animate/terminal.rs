@@ -0,0 +1,268 @@
+//! The full screen animation, which needs termion and so is only built on Unix
+
+use std::{
+    io::{self, Stdout, Write},
+    thread,
+    time::{Duration, Instant},
+};
+use termion::{
+    async_stdin, clear, cursor,
+    event::Key,
+    input::{Keys, TermRead},
+    raw::{IntoRawMode, RawTerminal},
+    screen::AlternateScreen,
+    style, AsyncReader,
+};
+
+use turing_machine::{
+    hooked::StepHook, machine_representation::MachineRepresentation,
+    transition_table::TransitionTable, TuringMachine,
+};
+
+use super::{animate, cells, trace, verdict, T};
+
+/// How long a frame stays up when playing, in milliseconds
+const DEFAULT_DELAY: u64 = 100;
+const MAX_DELAY: u64 = 2000;
+
+/// How often keys are polled while waiting, in milliseconds
+const POLL: u64 = 10;
+
+const KEYS: &str = "space play/pause  s step  + faster  - slower  q quit";
+
+/// The transition that takes the machine out of its current configuration
+fn transition(tm: &T) -> String {
+    let state = tm.current_state();
+    let read = *tm.tape().get(tm.head()).unwrap_or(&'_');
+    match tm
+        .representation()
+        .transition_table()
+        .apply_transition_table(state, read)
+    {
+        Some(act) => format!(
+            "{}, {} -> {}, {}, {}",
+            state,
+            read,
+            act.next_state(),
+            act.tape_output(),
+            act.motion()
+        ),
+        None => format!("{}, {} -> implicit reject", state, read),
+    }
+}
+
+/// The tape around the head, `width` cells wide: those before the head, the one under it, and those after it.
+/// The head is kept in the middle, unless the tape starts before that
+fn window(tm: &T, width: usize) -> (String, char, String) {
+    let head = tm.head();
+    let start = head.saturating_sub(width / 2);
+    let before = cells(tm.tape(), start, head - start).collect();
+    let under = cells(tm.tape(), head, 1).next().unwrap();
+    let after = cells(tm.tape(), head + 1, width - (head - start) - 1).collect();
+    (before, under, after)
+}
+
+/// Draws every configuration full screen, and waits between frames for keys or for the delay to pass.
+/// The terminal is only taken over on the first frame.
+struct Animation {
+    screen: Option<AlternateScreen<RawTerminal<Stdout>>>,
+    keys: Option<Keys<AsyncReader>>,
+    error: Option<io::Error>,
+    steps: Option<usize>,
+    last: Option<String>,
+    next: String,
+    delay: u64,
+    paused: bool,
+    quit: bool,
+}
+
+impl Default for Animation {
+    fn default() -> Self {
+        Self {
+            screen: None,
+            keys: None,
+            error: None,
+            steps: None,
+            last: None,
+            next: String::new(),
+            delay: DEFAULT_DELAY,
+            paused: false,
+            quit: false,
+        }
+    }
+}
+
+impl Animation {
+    fn draw(&mut self, tm: &T, status: &str) -> io::Result<()> {
+        if self.screen.is_none() {
+            let mut screen = AlternateScreen::from(io::stdout().into_raw_mode()?);
+            write!(screen, "{}", cursor::Hide)?;
+            self.screen = Some(screen);
+            self.keys = Some(async_stdin().keys());
+        }
+        let (width, _) = termion::terminal_size()?;
+        let screen = self.screen.as_mut().unwrap();
+
+        let head = tm.head();
+        let (before, under, after) = window(tm, usize::from(width).max(3));
+
+        write!(
+            screen,
+            "{}{}step {}  state {}  head {}  {}ms/step  {}",
+            clear::All,
+            cursor::Goto(1, 1),
+            self.steps.unwrap_or(0),
+            tm.current_state(),
+            head,
+            self.delay,
+            status
+        )?;
+        write!(
+            screen,
+            "{}last  {}",
+            cursor::Goto(1, 2),
+            self.last.as_ref().map_or("-", String::as_str)
+        )?;
+        write!(screen, "{}next  {}", cursor::Goto(1, 3), self.next)?;
+        write!(
+            screen,
+            "{}{}{}{}{}{}",
+            cursor::Goto(1, 5),
+            before,
+            style::Invert,
+            under,
+            style::Reset,
+            after
+        )?;
+        write!(
+            screen,
+            "{}^{}{}",
+            cursor::Goto((before.chars().count() + 1) as u16, 6),
+            cursor::Goto(1, 8),
+            KEYS
+        )?;
+        screen.flush()
+    }
+
+    /// Handle the keys pressed so far, returning whether a single step was asked for
+    fn handle_keys(&mut self) -> bool {
+        let mut step = false;
+        while let Some(Ok(key)) = self.keys.as_mut().and_then(Iterator::next) {
+            match key {
+                Key::Char(' ') => self.paused = !self.paused,
+                Key::Char('s') | Key::Char('n') | Key::Right => step = true,
+                Key::Char('+') => self.delay /= 2,
+                Key::Char('-') => self.delay = (self.delay * 2).clamp(1, MAX_DELAY),
+                Key::Char('q') | Key::Esc | Key::Ctrl('c') => self.quit = true,
+                _ => {}
+            }
+        }
+        step
+    }
+
+    /// Wait until the next step should be taken
+    fn wait(&mut self, tm: &T) {
+        let shown = Instant::now();
+        loop {
+            let paused = self.paused;
+            let step = self.handle_keys();
+            if self.quit
+                || step
+                || (!self.paused && shown.elapsed().as_millis() >= u128::from(self.delay))
+            {
+                return;
+            }
+            if paused != self.paused {
+                self.redraw(tm);
+            }
+            thread::sleep(Duration::from_millis(POLL));
+        }
+    }
+
+    fn redraw(&mut self, tm: &T) {
+        let status = if self.paused { "paused" } else { "playing" };
+        if let Err(e) = self.draw(tm, status) {
+            self.error = Some(e);
+            self.quit = true;
+        }
+    }
+
+    /// Show the final configuration until the user quits
+    fn finish(&mut self, tm: &T) -> io::Result<()> {
+        if let Some(e) = self.error.take() {
+            return Err(e);
+        }
+        if !self.quit {
+            self.draw(tm, verdict(tm))?;
+            while !self.quit {
+                self.handle_keys();
+                thread::sleep(Duration::from_millis(POLL));
+            }
+        }
+        if let Some(screen) = self.screen.as_mut() {
+            write!(screen, "{}", cursor::Show)?;
+            screen.flush()?;
+        }
+        Ok(())
+    }
+}
+
+impl StepHook<T> for Animation {
+    fn on_step(&mut self, tm: &T) {
+        self.steps = Some(self.steps.map_or(0, |s| s + 1));
+        self.last = self.steps.filter(|s| *s > 0).map(|_| self.next.clone());
+        self.next = if tm.is_finished() {
+            "-".to_string()
+        } else {
+            transition(tm)
+        };
+
+        self.redraw(tm);
+        if !tm.is_finished() {
+            self.wait(tm);
+        }
+    }
+}
+
+/// Animate the machine if stdout is a terminal, or print a line per step otherwise
+pub fn play(tm: T, limit: Option<usize>, paused: bool) -> io::Result<T> {
+    if !termion::is_tty(&io::stdout()) {
+        return Ok(trace(tm, limit));
+    }
+    let hook = Animation {
+        paused,
+        ..Animation::default()
+    };
+    let (tm, mut hook) = animate(tm, hook, limit, |h| h.quit);
+    hook.finish(&tm)?;
+    Ok(tm)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::animate::tests::paren;
+
+    #[test]
+    fn describes_transitions() {
+        let tm = paren("()");
+        assert_eq!(transition(&tm), "start, ( -> close, ^, R");
+
+        let tm = paren(")");
+        assert_eq!(transition(&tm), "start, ) -> implicit reject");
+    }
+
+    #[test]
+    fn centres_the_head() {
+        let mut tm = paren("(())");
+        assert_eq!(window(&tm, 5), (String::new(), '(', "())_".to_string()));
+
+        // Once the head is far enough from the start, it is in the middle
+        for _ in 0..5 {
+            tm.step();
+        }
+        assert_eq!(tm.head(), 3);
+        assert_eq!(window(&tm, 5), ("$$".to_string(), ')', "__".to_string()));
+        assert_eq!(window(&tm, 4), ("$$".to_string(), ')', "_".to_string()));
+    }
+}
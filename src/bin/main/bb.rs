@@ -0,0 +1,122 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+};
+
+use turing_machine::{
+    busy_beaver,
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    machine_parser,
+};
+
+use crate::{handle_error, load_repr, parse_arg, ErrorType};
+
+type T = DeterministicTuringMachine<String>;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("bb")
+        .about("Convert between busy beaver notation like 1RB1LB_1LA1RZ and .tm files, and score machines")
+        .arg(
+            Arg::with_name("machine")
+                .required(true)
+                .index(1)
+                .value_name("MACHINE")
+                .help("The machine in busy beaver notation, or a .tm file with --from-file"),
+        )
+        .arg(
+            Arg::with_name("from_file")
+                .long("from-file")
+                .short("f")
+                .help("Read the machine from a .tm file and print it in busy beaver notation"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .conflicts_with("from_file")
+                .takes_value(true)
+                .value_name("TM_FILE")
+                .help("Write the .tm file there instead of stdout"),
+        )
+        .arg(
+            Arg::with_name("run")
+                .long("run")
+                .short("r")
+                .help("Instead, run the machine on a blank tape infinite both ways, and print its steps and ones"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .short("l")
+                .long("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .default_value("100000000")
+                .help("Give up running after this many steps"),
+        )
+}
+
+/// Runs the `bb` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let machine = matches.value_of("machine").unwrap();
+    let repr = if matches.is_present("from_file") {
+        match load_repr::<T, DeterministicMachineRepresentation<String>>(machine) {
+            Ok(repr) => repr,
+            Err(e) => return handle_error(e),
+        }
+    } else {
+        match busy_beaver::parse(machine) {
+            Ok(repr) => repr,
+            Err(e) => {
+                error!("Notation({})", e);
+                return 2;
+            }
+        }
+    };
+
+    if matches.is_present("run") {
+        let limit = match parse_arg(matches, "limit") {
+            Ok(limit) => limit,
+            Err(code) => return code,
+        };
+        return match busy_beaver::run(&repr, limit) {
+            Some(score) if score.accepting => {
+                println!("halted\nsteps {}\nones {}", score.steps, score.ones);
+                0
+            }
+            Some(score) => {
+                println!("not accepted\nsteps {}\nones {}", score.steps, score.ones);
+                1
+            }
+            None => {
+                println!("still running after {} steps", limit);
+                1
+            }
+        };
+    }
+
+    if matches.is_present("from_file") {
+        return match busy_beaver::write(&repr) {
+            Ok(notation) => {
+                println!("{}", notation);
+                0
+            }
+            Err(e) => {
+                error!("Notation({})", e);
+                2
+            }
+        };
+    }
+
+    let written = match matches.value_of("output") {
+        Some(path) => {
+            File::create(path).and_then(|f| machine_parser::write(&repr, BufWriter::new(f)))
+        }
+        None => machine_parser::write(&repr, io::stdout().lock()),
+    };
+    match written {
+        Ok(()) => 0,
+        Err(e) => handle_error::<T>(ErrorType::IO(e)),
+    }
+}
@@ -0,0 +1,111 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+};
+
+use turing_machine::{
+    binary::{self, BlockEncoding},
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    machine_parser,
+    machine_representation::MachineRepresentation,
+};
+
+use crate::{handle_error, load_repr, ErrorType};
+
+type T = DeterministicTuringMachine<String>;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("binary")
+        .about("Reduce a deterministic machine to the alphabet {_, 1}, writing it back as .tm")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .value_name("TM_FILE")
+                .help("Write to a file instead of stdout"),
+        )
+        .arg(
+            Arg::with_name("encode")
+                .long("encode")
+                .takes_value(true)
+                .value_name("TAPE")
+                .conflicts_with_all(&["decode", "output"])
+                .help("Print the encoding of a tape for the reduced machine instead"),
+        )
+        .arg(
+            Arg::with_name("decode")
+                .long("decode")
+                .takes_value(true)
+                .value_name("TAPE")
+                .conflicts_with("output")
+                .help("Print the decoding of a tape of the reduced machine instead"),
+        )
+}
+
+fn translate(encoding: &BlockEncoding, matches: &ArgMatches) -> i32 {
+    let translated = match (matches.value_of("encode"), matches.value_of("decode")) {
+        (Some(tape), _) => encoding.encode_tape(&tape.chars().collect::<Vec<_>>()),
+        (_, Some(tape)) => encoding.decode_tape(&tape.chars().collect::<Vec<_>>()),
+        _ => unreachable!(),
+    };
+    match translated {
+        Ok(tape) => {
+            println!("{}", tape.into_iter().collect::<String>());
+            0
+        }
+        Err(e) => {
+            error!("Encoding({:?})", e);
+            2
+        }
+    }
+}
+
+/// Runs the `binary` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+    let output = matches.value_of("output");
+
+    let repr = match load_repr::<T, DeterministicMachineRepresentation<String>>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+    let (reduced, encoding) = binary::to_binary(&repr);
+    if matches.is_present("encode") || matches.is_present("decode") {
+        return translate(&encoding, matches);
+    }
+
+    let written = match output {
+        Some(path) => {
+            File::create(path).and_then(|f| machine_parser::write(&reduced, BufWriter::new(f)))
+        }
+        None => machine_parser::write(&reduced, io::stdout().lock()),
+    };
+    if let Err(e) = written {
+        return handle_error::<T>(ErrorType::IO(e));
+    }
+
+    if let Some(path) = output {
+        println!(
+            "{} states over {} symbols, using blocks of {} cells, written to {}",
+            reduced.states().len(),
+            reduced.alphabet().len(),
+            encoding.width(),
+            path
+        );
+        for c in encoding.symbols() {
+            let code: String = encoding.code(*c).unwrap().into_iter().collect();
+            println!("{} {}", c, code);
+        }
+    }
+    0
+}
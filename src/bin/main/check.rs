@@ -0,0 +1,78 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::fs::File;
+
+use turing_machine::{
+    lint::{self, Lint, Severity},
+    machine_parser,
+};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("check")
+        .about("Analyse a machine without running it")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("ndtm")
+                .long("nondeterministic")
+                .short("n")
+                .help("The machine is meant to be non deterministic"),
+        )
+        .arg(
+            Arg::with_name("verbose")
+                .long("verbose")
+                .short("v")
+                .help("Also report the transitions falling through to the implicit reject"),
+        )
+}
+
+/// Runs the `check` subcommand, returning the exit code.
+/// This is 1 if anything more serious than an info is found
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+
+    let parser = match File::open(repr_path) {
+        Ok(f) => match machine_parser::parse(f) {
+            Ok(parser) => parser,
+            Err(e) => {
+                error!("Parsing({:?})", e);
+                return 2;
+            }
+        },
+        Err(e) => {
+            error!("IO({:?})", e);
+            return 3;
+        }
+    };
+
+    let lints: Vec<Lint<String>> = lint::check(&parser)
+        .into_iter()
+        .filter(|l| !(matches.is_present("ndtm") && matches!(l, Lint::NonDeterministic(..))))
+        .collect();
+
+    let mut infos = 0;
+    for lint in &lints {
+        if lint.severity() == Severity::Info && !matches.is_present("verbose") {
+            infos += 1;
+            continue;
+        }
+        println!("{}: {}", lint.severity(), lint);
+    }
+    if infos > 0 {
+        println!(
+            "{} transitions fall through to the implicit reject, use --verbose to list them",
+            infos
+        );
+    }
+
+    if lints.iter().any(|l| l.severity() < Severity::Info) {
+        1
+    } else {
+        0
+    }
+}
@@ -0,0 +1,63 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+};
+
+use turing_machine::{complement, deterministic_tm::DeterministicTuringMachine, machine_parser};
+
+use crate::{handle_error, load_repr, ErrorType};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("complement")
+        .about("Swap acceptance of a deterministic decider, writing the machine back as .tm")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .value_name("TM_FILE")
+                .help("Write to a file instead of stdout"),
+        )
+}
+
+/// Runs the `complement` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    type T = DeterministicTuringMachine<String>;
+    let repr_path = matches.value_of("repr").unwrap();
+    let output = matches.value_of("output");
+
+    let repr = match load_repr::<T, _>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+    let (complemented, total) = complement::complement(&repr);
+
+    let written = match output {
+        Some(path) => {
+            File::create(path).and_then(|f| machine_parser::write(&complemented, BufWriter::new(f)))
+        }
+        None => machine_parser::write(&complemented, io::stdout().lock()),
+    };
+    if let Err(e) = written {
+        return handle_error::<T>(ErrorType::IO(e));
+    }
+
+    // On stderr, so that it does not end up in the machine written to stdout
+    if !total {
+        eprintln!(
+            "warning: the machine is not known to stop on every input, its complement loops wherever it does"
+        );
+    }
+    if let Some(path) = output {
+        println!("complement written to {}", path);
+    }
+    0
+}
@@ -0,0 +1,215 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{fmt::Display, fs::File, io::BufWriter, ops::Range};
+
+use turing_machine::{
+    complexity::{self, Generator},
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    machine_representation::MachineRepresentation,
+    non_deterministic_tm::{NonDeterministicMachineRepresentation, NonDeterministicTuringMachine},
+    TuringMachine,
+};
+
+use crate::{handle_error, load_repr, parse_arg, parse_optional_arg, parse_range, ErrorType};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("complexity")
+        .about(
+            "Measure the number of steps on generated inputs, and fit them against growth classes",
+        )
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("generator")
+                .required(true)
+                .index(2)
+                .value_name("GENERATOR")
+                .help("The input generator, e.g. '\"(\" * n + \")\" * n'"),
+        )
+        .arg(
+            Arg::with_name("range")
+                .long("range")
+                .short("r")
+                .takes_value(true)
+                .value_name("START..END")
+                .default_value("0..100")
+                .help("The values of n to generate inputs for"),
+        )
+        .arg(
+            Arg::with_name("ndtm")
+                .long("nondeterministic")
+                .short("n")
+                .help("Use a non deterministic TM"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .short("l")
+                .long("limit")
+                .help("Limit the number of steps on each input, runs exceeding it are not fitted"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .takes_value(true)
+                .value_name("CSV_FILE")
+                .short("o")
+                .long("output")
+                .help("Write the measurements to a CSV file"),
+        )
+        .arg(
+            Arg::with_name("digits")
+                .long("digits")
+                .short("d")
+                .conflicts_with("ndtm")
+                .takes_value(true)
+                .value_name("SYMBOLS")
+                .help("Instead, run once on the input for n = 0, and count the cells holding one of SYMBOLS after STEPS * n steps for each n of the range"),
+        )
+        .arg(
+            Arg::with_name("every")
+                .long("every")
+                .short("e")
+                .takes_value(true)
+                .value_name("STEPS")
+                .default_value("100")
+                .help("How many steps apart the cells are counted, with --digits"),
+        )
+}
+
+/// Runs the `complexity` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+    let generator = match matches.value_of("generator").unwrap().parse::<Generator>() {
+        Ok(generator) => generator,
+        Err(e) => {
+            error!("Generator({:?})", e);
+            return 2;
+        }
+    };
+    let (range, limit, every) =
+        match parse_range(matches.value_of("range").unwrap()).and_then(|range| {
+            Ok((
+                range,
+                parse_optional_arg(matches, "limit")?,
+                parse_arg::<usize>(matches, "every")?,
+            ))
+        }) {
+            Ok(parsed) => parsed,
+            Err(code) => return code,
+        };
+    let output = matches.value_of("output");
+
+    if let Some(digits) = matches.value_of("digits") {
+        let symbols: Vec<char> = digits.chars().collect();
+        let checkpoints = range.map(|n| n.saturating_mul(every));
+        let tape = match generator.generate(0) {
+            Ok(tape) => tape,
+            Err(e) => {
+                error!("Generator({:?})", e);
+                return 2;
+            }
+        };
+        measure_output(repr_path, tape, &symbols, checkpoints, output)
+    } else if matches.is_present("ndtm") {
+        measure::<
+            NonDeterministicTuringMachine<String>,
+            NonDeterministicMachineRepresentation<String>,
+        >(repr_path, &generator, range, limit, output)
+    } else {
+        measure::<DeterministicTuringMachine<String>, DeterministicMachineRepresentation<String>>(
+            repr_path, &generator, range, limit, output,
+        )
+    }
+}
+
+fn measure<T, Repr>(
+    repr_path: &str,
+    generator: &Generator,
+    range: Range<usize>,
+    limit: Option<usize>,
+    output: Option<&str>,
+) -> i32
+where
+    T: TuringMachine<StateTy = String, ReprTy = Repr>,
+    T::ErrorTy: Display,
+    Repr: MachineRepresentation<String, InputTy = char> + Clone,
+{
+    let samples = match load_repr::<T, Repr>(repr_path).and_then(|repr| {
+        complexity::measure::<T>(&repr, generator, range, limit).map_err(ErrorType::MachineCreation)
+    }) {
+        Ok(samples) => samples,
+        Err(e) => return handle_error(e),
+    };
+
+    if let Some(path) = output {
+        if let Err(e) =
+            File::create(path).and_then(|f| complexity::write_csv(&samples, BufWriter::new(f)))
+        {
+            return handle_error::<T>(ErrorType::IO(e));
+        }
+    }
+
+    let unfinished = samples.iter().filter(|s| !s.finished).count();
+    if unfinished > 0 {
+        println!("{} runs exceeded the step limit", unfinished);
+    }
+    print_fits(&samples)
+}
+
+/// Run a deterministic machine once, measuring the steps it takes to write its output
+fn measure_output(
+    repr_path: &str,
+    tape: Vec<char>,
+    symbols: &[char],
+    checkpoints: impl Iterator<Item = usize>,
+    output: Option<&str>,
+) -> i32 {
+    type T = DeterministicTuringMachine<String>;
+    let samples = match load_repr::<T, DeterministicMachineRepresentation<String>>(repr_path)
+        .and_then(|repr| {
+            complexity::measure_output::<T>(&repr, tape, symbols, checkpoints)
+                .map_err(ErrorType::MachineCreation)
+        }) {
+        Ok(samples) => samples,
+        Err(e) => return handle_error(e),
+    };
+
+    if let Some(path) = output {
+        if let Err(e) = File::create(path)
+            .and_then(|f| complexity::write_output_csv(&samples, BufWriter::new(f)))
+        {
+            return handle_error::<T>(ErrorType::IO(e));
+        }
+    }
+    print_fits(&samples)
+}
+
+/// Print how well the samples fit each growth class, returning the exit code
+fn print_fits(samples: &[complexity::Sample]) -> i32 {
+    let fits = complexity::fit(samples);
+    for fit in &fits {
+        println!(
+            "{:<8} a = {:<12.6e} b = {:<12.4} R^2 = {:.6}",
+            fit.class.to_string(),
+            fit.a,
+            fit.b,
+            fit.r_squared
+        );
+    }
+    match fits.first() {
+        Some(best) => {
+            println!("best fit: {}", best.class);
+            0
+        }
+        None => {
+            println!("not enough samples to fit");
+            1
+        }
+    }
+}
@@ -0,0 +1,361 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::{
+    fmt::{Debug, Display},
+    io::{self, BufRead, Write},
+    str::FromStr,
+};
+
+use turing_machine::{
+    builders::{TuringMachineBuilder, ValidationError},
+    common::representation::GeneralMachineRepresentation,
+    debugger::{Breakpoint, Debugger, Stop},
+    deterministic_tm::DeterministicTuringMachine,
+    non_deterministic_tm::NonDeterministicTuringMachine,
+    transition_table::{ActionSet, TransitionTable},
+    TuringMachine,
+};
+
+use crate::{handle_error, load_repr, parse_arg, read_tape_file, ErrorType};
+
+const HELP: &str = "\
+step [N]            take N steps (default 1), stopping early like continue
+continue            run until the machine stops, a breakpoint or watchpoint triggers, or a choice is needed
+choose K            take the K-th transition listed by choices
+choices             list the transitions that apply
+break STATE [SYM]   stop in STATE, or in STATE reading SYM
+delete N            remove the N-th breakpoint
+watch CELL          stop when the symbol in CELL changes
+unwatch CELL        stop watching CELL
+info                list breakpoints and watchpoints
+print [RADIUS]      show the configuration around the head
+set CELL SYM        write SYM in CELL
+help                show this message
+quit                leave the debugger";
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("debug")
+        .about("Step through a run interactively, with breakpoints and watchpoints")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("tapefile")
+                .index(2)
+                .value_name("TAPE_FILE")
+                .help("A file containing the tape the machine should start on"),
+        )
+        .arg(
+            Arg::with_name("tapevalue")
+                .short("T")
+                .long("tape")
+                .conflicts_with("tapefile")
+                .takes_value(true)
+                .value_name("TAPE")
+                .help("Inline tape for testing"),
+        )
+        .arg(
+            Arg::with_name("ndtm")
+                .long("nondeterministic")
+                .short("n")
+                .help("Use a non deterministic TM, choosing which branch to follow"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .short("l")
+                .long("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .default_value("1000000")
+                .help("The most steps continue takes before giving control back"),
+        )
+}
+
+fn report<TableTy>(debugger: &Debugger<TableTy>, stop: Option<Stop>)
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    match stop {
+        Some(Stop::Finished(kind)) => println!("finished ({:?})", kind),
+        Some(Stop::Breakpoint(i)) => println!("breakpoint {}: {}", i, debugger.breakpoints()[i]),
+        Some(Stop::Watchpoint { cell, old, new }) => {
+            println!("watchpoint on cell {}: {} -> {}", cell, old, new)
+        }
+        Some(Stop::Choice) => {
+            println!("several transitions apply, pick one with choose");
+            print_choices(debugger);
+        }
+        None => {}
+    }
+    println!("{}", debugger.configuration(10));
+}
+
+fn print_choices<TableTy>(debugger: &Debugger<TableTy>)
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    let choices = debugger.choices();
+    if choices.is_empty() && !debugger.is_finished() {
+        println!("0: implicit reject");
+    }
+    for (i, act) in choices.iter().enumerate() {
+        println!(
+            "{}: {} {} {}",
+            i,
+            act.next_state(),
+            act.tape_output(),
+            act.motion()
+        );
+    }
+}
+
+/// Parse the argument at `index`, or use `default` when it is missing
+fn arg<T: FromStr>(args: &[&str], index: usize, default: Option<T>) -> Result<T, String> {
+    match args.get(index) {
+        Some(s) => s.parse().map_err(|_| format!("invalid argument {}", s)),
+        None => default.ok_or_else(|| "missing argument".to_string()),
+    }
+}
+
+/// Run a single command, returning false once the debugger should exit
+fn command<TableTy>(
+    debugger: &mut Debugger<TableTy>,
+    line: &str,
+    limit: usize,
+) -> Result<bool, String>
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    let args: Vec<&str> = line.split_whitespace().collect();
+    match args.first().cloned().unwrap_or("") {
+        "" => {}
+        "s" | "step" => {
+            let stop = debugger.run(arg(&args, 1, Some(1))?);
+            report(debugger, stop);
+        }
+        "c" | "continue" => {
+            let stop = debugger.run(limit);
+            if stop.is_none() {
+                println!("still running after {} steps", limit);
+            }
+            report(debugger, stop);
+        }
+        "choose" => {
+            let stop = debugger
+                .step_with(arg(&args, 1, None)?)
+                .map_err(|e| format!("{:?}", e))?;
+            report(debugger, stop);
+        }
+        "choices" => print_choices(debugger),
+        "b" | "break" => {
+            let state = arg::<String>(&args, 1, None)?;
+            let breakpoint = match args.get(2) {
+                Some(_) => Breakpoint::Transition(state, arg(&args, 2, None)?),
+                None => Breakpoint::State(state),
+            };
+            println!(
+                "breakpoint {}: {}",
+                debugger.breakpoints().len(),
+                breakpoint
+            );
+            debugger.add_breakpoint(breakpoint);
+        }
+        "d" | "delete" => {
+            let removed = debugger
+                .remove_breakpoint(arg(&args, 1, None)?)
+                .map_err(|e| format!("{:?}", e))?;
+            println!("deleted {}", removed);
+        }
+        "w" | "watch" => debugger.add_watchpoint(arg(&args, 1, None)?),
+        "unwatch" => {
+            if !debugger.remove_watchpoint(arg(&args, 1, None)?) {
+                return Err("the cell is not watched".to_string());
+            }
+        }
+        "i" | "info" => {
+            for (i, b) in debugger.breakpoints().iter().enumerate() {
+                println!("breakpoint {}: {}", i, b);
+            }
+            for cell in debugger.watchpoints() {
+                println!("watchpoint on cell {}", cell);
+            }
+        }
+        "p" | "print" => println!("{}", debugger.configuration(arg(&args, 1, Some(10))?)),
+        "set" => {
+            debugger
+                .set_cell(arg(&args, 1, None)?, arg(&args, 2, None)?)
+                .map_err(|e| format!("{:?}", e))?;
+            println!("{}", debugger.configuration(10));
+        }
+        "h" | "help" => println!("{}", HELP),
+        "q" | "quit" => return Ok(false),
+        other => return Err(format!("unknown command {}, try help", other)),
+    }
+    Ok(true)
+}
+
+fn debug_machine<T, TableTy>(repr_path: &str, tape: Vec<char>, limit: usize) -> i32
+where
+    T: TuringMachine<StateTy = String, ReprTy = GeneralMachineRepresentation<String, TableTy>>,
+    T::ErrorTy: Display + From<ValidationError<char>>,
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    let repr = match load_repr::<T, T::ReprTy>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+    let mut debugger = match Debugger::new(TuringMachineBuilder::new().repr(repr).tape(tape)) {
+        Ok(debugger) => debugger,
+        Err(e) => return handle_error::<T>(ErrorType::MachineCreation(e.into())),
+    };
+    println!("{}", debugger.configuration(10));
+
+    let stdin = io::stdin();
+    loop {
+        print!("(debug) ");
+        if let Err(e) = io::stdout().flush() {
+            return handle_error::<T>(ErrorType::IO(e));
+        }
+
+        let mut line = String::new();
+        match stdin.lock().read_line(&mut line) {
+            Ok(0) => return 0,
+            Ok(_) => {}
+            Err(e) => return handle_error::<T>(ErrorType::IO(e)),
+        }
+        match command(&mut debugger, &line, limit) {
+            Ok(true) => {}
+            Ok(false) => return 0,
+            Err(e) => println!("error: {}", e),
+        }
+    }
+}
+
+/// Runs the `debug` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+    let limit = match parse_arg(matches, "limit") {
+        Ok(limit) => limit,
+        Err(code) => return code,
+    };
+    let tape = match (matches.value_of("tapefile"), matches.value_of("tapevalue")) {
+        (Some(path), _) => match read_tape_file(path) {
+            Ok(tape) => tape,
+            Err(e) => return handle_error::<DeterministicTuringMachine<String>>(ErrorType::IO(e)),
+        },
+        (None, Some(tape)) => tape.chars().collect(),
+        (None, None) => Vec::new(),
+    };
+
+    if matches.is_present("ndtm") {
+        debug_machine::<NonDeterministicTuringMachine<String>, _>(repr_path, tape, limit)
+    } else {
+        debug_machine::<DeterministicTuringMachine<String>, _>(repr_path, tape, limit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use turing_machine::{
+        deterministic_tm::{
+            transition_table::DeterministicTransitionTable, DeterministicMachineRepresentation,
+        },
+        machine_parser,
+        machine_representation::MachineRepresentation,
+    };
+
+    fn paren(tape: &str) -> Debugger<DeterministicTransitionTable<String>> {
+        let repr = DeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(include_str!("../../../data/paren.tm").as_bytes()).unwrap(),
+        )
+        .unwrap();
+        Debugger::new(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape(tape.chars().collect()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn steps_and_continues() {
+        let mut debugger = paren("(())");
+        assert_eq!(command(&mut debugger, "", 1000), Ok(true));
+        assert_eq!(debugger.steps(), 0);
+        assert_eq!(command(&mut debugger, "step", 1000), Ok(true));
+        assert_eq!(debugger.steps(), 1);
+        assert_eq!(command(&mut debugger, "s 2", 1000), Ok(true));
+        assert_eq!(debugger.steps(), 3);
+
+        // Continuing stops at the limit, then at the end of the run
+        assert_eq!(command(&mut debugger, "continue", 2), Ok(true));
+        assert_eq!(debugger.steps(), 5);
+        assert_eq!(command(&mut debugger, "c", 1000), Ok(true));
+        assert!(debugger.is_finished());
+    }
+
+    #[test]
+    fn manages_breakpoints_and_watchpoints() {
+        let mut debugger = paren("(())");
+        assert_eq!(command(&mut debugger, "break close )", 1000), Ok(true));
+        assert_eq!(command(&mut debugger, "b open", 1000), Ok(true));
+        assert_eq!(
+            debugger.breakpoints(),
+            &[
+                Breakpoint::Transition("close".to_string(), ')'),
+                Breakpoint::State("open".to_string())
+            ]
+        );
+        assert_eq!(command(&mut debugger, "delete 1", 1000), Ok(true));
+        assert_eq!(debugger.breakpoints().len(), 1);
+        assert!(command(&mut debugger, "delete 1", 1000).is_err());
+
+        assert_eq!(command(&mut debugger, "watch 2", 1000), Ok(true));
+        assert_eq!(debugger.watchpoints(), &[2]);
+        assert_eq!(command(&mut debugger, "unwatch 2", 1000), Ok(true));
+        assert_eq!(
+            command(&mut debugger, "unwatch 2", 1000),
+            Err("the cell is not watched".to_string())
+        );
+
+        assert_eq!(command(&mut debugger, "c", 1000), Ok(true));
+        assert_eq!(debugger.state(), "close");
+        assert_eq!(debugger.read(), ')');
+    }
+
+    #[test]
+    fn edits_tape() {
+        let mut debugger = paren("(()");
+        assert_eq!(command(&mut debugger, "set 3 )", 1000), Ok(true));
+        assert_eq!(debugger.tape()[3], ')');
+        assert!(command(&mut debugger, "set 0 x", 1000).is_err());
+        assert_eq!(debugger.tape()[0], '(');
+    }
+
+    #[test]
+    fn reports_bad_commands() {
+        let mut debugger = paren("()");
+        assert_eq!(
+            command(&mut debugger, "step x", 1000),
+            Err("invalid argument x".to_string())
+        );
+        assert_eq!(
+            command(&mut debugger, "choose", 1000),
+            Err("missing argument".to_string())
+        );
+        assert_eq!(
+            command(&mut debugger, "jump", 1000),
+            Err("unknown command jump, try help".to_string())
+        );
+        assert_eq!(debugger.steps(), 0);
+        assert_eq!(command(&mut debugger, "quit", 1000), Ok(false));
+    }
+}
@@ -0,0 +1,78 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{
+    fs::{self, File},
+    io::{self, BufWriter, Write},
+};
+
+use turing_machine::{
+    busy_beaver,
+    deciders::{Pipeline, Verdict},
+};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("decide")
+        .about("Try to tell whether busy beaver candidates halt, as those left undecided by enumerate")
+        .arg(
+            Arg::with_name("machines")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The machines, one per line in busy beaver notation"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .value_name("REPORT")
+                .help("Write the report there and print how many machines got each verdict, instead of writing it to stdout"),
+        )
+}
+
+/// Runs the `decide` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let path = matches.value_of("machines").unwrap();
+    let machines = match fs::read_to_string(path) {
+        Ok(machines) => machines,
+        Err(e) => {
+            error!("IO({:?})", e);
+            return 3;
+        }
+    };
+
+    let mut parsed = Vec::new();
+    for (i, line) in machines.lines().map(str::trim).enumerate() {
+        if line.is_empty() {
+            continue;
+        }
+        match busy_beaver::parse(line) {
+            Ok(repr) => parsed.push((line.to_string(), repr)),
+            Err(e) => {
+                error!("Notation({}) on line {}", e, i + 1);
+                return 2;
+            }
+        }
+    }
+    let report = Pipeline::default().report(parsed);
+
+    let written = match matches.value_of("output") {
+        Some(output) => File::create(output).and_then(|f| {
+            let mut w = BufWriter::new(f);
+            report.write(&mut w)?;
+            w.flush()
+        }),
+        None => report.write(io::stdout().lock()),
+    };
+    if let Err(e) = written {
+        error!("IO({:?})", e);
+        return 3;
+    }
+
+    if matches.is_present("output") {
+        for verdict in &[Verdict::Halts, Verdict::NonHalting, Verdict::Unknown] {
+            println!("{} {}", verdict, report.count(*verdict));
+        }
+    }
+    0
+}
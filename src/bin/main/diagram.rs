@@ -0,0 +1,175 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{fs::File, io::BufWriter, path::Path};
+
+use turing_machine::{
+    builders::TuringMachineBuilder,
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    diagram::SpaceTimeRecorder,
+    hooked::HookedTuringMachineExt,
+    limited::LimitedTuringMachineExt,
+    TuringMachine,
+};
+
+use crate::{handle_error, load_repr, parse_optional_arg, parse_range, read_tape_file, ErrorType};
+
+type Machine = DeterministicTuringMachine<String>;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("diagram")
+        .about("Draw the space-time diagram of a deterministic run")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("tapefile")
+                .index(2)
+                .value_name("TAPE_FILE")
+                .help("A file containing the tape the machine should start on"),
+        )
+        .arg(
+            Arg::with_name("tapevalue")
+                .short("T")
+                .long("tape")
+                .conflicts_with("tapefile")
+                .takes_value(true)
+                .value_name("TAPE")
+                .help("Inline tape for testing"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .required(true)
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .value_name("IMAGE")
+                .help("The image to write, the format is taken from the extension unless --format is given"),
+        )
+        .arg(
+            Arg::with_name("format")
+                .long("format")
+                .takes_value(true)
+                .possible_values(&["png", "ppm", "svg"])
+                .help("The image format"),
+        )
+        .arg(
+            Arg::with_name("steps")
+                .long("steps")
+                .takes_value(true)
+                .value_name("START..END")
+                .help("Only draw these steps, 0 being the starting configuration"),
+        )
+        .arg(
+            Arg::with_name("cells")
+                .long("cells")
+                .takes_value(true)
+                .value_name("START..END")
+                .help("Only draw these tape cells"),
+        )
+        .arg(
+            Arg::with_name("scale")
+                .long("scale")
+                .takes_value(true)
+                .value_name("PIXELS")
+                .help("The size of each cell [default: 4, 16 for svg]"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .short("l")
+                .long("limit")
+                .help("Limit the number of steps the machine is allowed to take [default: the end of --steps, or 10000]"),
+        )
+}
+
+/// Runs the `diagram` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+    let output = matches.value_of("output").unwrap();
+    let format = matches
+        .value_of("format")
+        .or_else(|| Path::new(output).extension().and_then(|e| e.to_str()))
+        .unwrap_or("png")
+        .to_lowercase();
+
+    let (steps, cells) = match (
+        matches.value_of("steps").map(parse_range).transpose(),
+        matches.value_of("cells").map(parse_range).transpose(),
+    ) {
+        (Ok(steps), Ok(cells)) => (steps, cells),
+        (Err(code), _) | (_, Err(code)) => return code,
+    };
+    let (scale, limit) = match (
+        parse_optional_arg(matches, "scale"),
+        parse_optional_arg(matches, "limit"),
+    ) {
+        (Ok(scale), Ok(limit)) => (scale, limit),
+        (Err(code), _) | (_, Err(code)) => return code,
+    };
+    let scale = scale.unwrap_or(if format == "svg" { 16 } else { 4 });
+    let limit = limit
+        .or_else(|| steps.as_ref().map(|s| s.end))
+        .unwrap_or(10000);
+
+    let tape = match matches.value_of("tapefile") {
+        Some(p) => match read_tape_file(p) {
+            Ok(tape) => tape,
+            Err(e) => return handle_error::<Machine>(ErrorType::IO(e)),
+        },
+        None => matches
+            .value_of("tapevalue")
+            .map_or_else(Vec::new, |s| s.chars().collect()),
+    };
+
+    let repr = match load_repr::<Machine, DeterministicMachineRepresentation<String>>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+    let machine = match Machine::from_builder(TuringMachineBuilder::new().repr(repr).tape(tape)) {
+        Ok(machine) => machine,
+        Err(e) => return handle_error::<Machine>(ErrorType::MachineCreation(e)),
+    };
+
+    let mut recorder = SpaceTimeRecorder::new();
+    if let Some(steps) = steps {
+        recorder = recorder.steps(steps);
+    }
+    if let Some(cells) = cells {
+        recorder = recorder.cells(cells);
+    }
+
+    let machine = LimitedTuringMachineExt::new_with_limit(
+        HookedTuringMachineExt::new(machine, recorder),
+        limit,
+    );
+    let exe = machine.execute_and_get_result();
+    let diagram = exe.tm.hook().diagram();
+
+    let written = File::create(output).and_then(|f| {
+        let f = BufWriter::new(f);
+        match format.as_str() {
+            "svg" => diagram.write_svg(scale, f),
+            "ppm" => diagram.write_ppm(scale, f),
+            _ => diagram.write_png(scale, f),
+        }
+    });
+    if let Err(e) = written {
+        return handle_error::<Machine>(ErrorType::IO(e));
+    }
+
+    if !exe.tm.is_finished() {
+        error!("The machine was stopped after {} steps", limit);
+    }
+    println!(
+        "{}x{} cells written to {}",
+        diagram.width(),
+        diagram.height(),
+        output
+    );
+    0
+}
@@ -0,0 +1,62 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{fs::File, io::Write};
+
+use turing_machine::{graph::StateGraph, machine_parser};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("draw")
+        .about("Export the state diagram of a machine as Graphviz DOT")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .value_name("DOT_FILE")
+                .help("Write to a file instead of stdout"),
+        )
+}
+
+/// Runs the `draw` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+
+    let parser = match File::open(repr_path) {
+        Ok(f) => match machine_parser::parse(f) {
+            Ok(parser) => parser,
+            Err(e) => {
+                error!("Parsing({:?})", e);
+                return 2;
+            }
+        },
+        Err(e) => {
+            error!("IO({:?})", e);
+            return 3;
+        }
+    };
+
+    // The parser always sets the starting state
+    let dot = StateGraph::from_builder(&parser).unwrap().to_dot();
+
+    let written = match matches.value_of("output") {
+        Some(path) => File::create(path).and_then(|mut f| f.write_all(dot.as_bytes())),
+        None => {
+            print!("{}", dot);
+            Ok(())
+        }
+    };
+    match written {
+        Ok(()) => 0,
+        Err(e) => {
+            error!("IO({:?})", e);
+            3
+        }
+    }
+}
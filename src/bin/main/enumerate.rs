@@ -0,0 +1,92 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+};
+
+use turing_machine::enumeration;
+
+use crate::parse_arg;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("enumerate")
+        .about("Run every busy beaver candidate of a size in tree normal form, and report the champions")
+        .arg(
+            Arg::with_name("states")
+                .required(true)
+                .index(1)
+                .value_name("STATES")
+                .help("The number of states, not counting the halting one"),
+        )
+        .arg(
+            Arg::with_name("symbols")
+                .index(2)
+                .value_name("SYMBOLS")
+                .default_value("2")
+                .help("The number of symbols, the blank included"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .short("l")
+                .long("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .default_value("1000")
+                .help("Leave machines undecided after this many steps"),
+        )
+        .arg(
+            Arg::with_name("undecided")
+                .short("u")
+                .long("undecided")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Write the undecided machines there, one per line in busy beaver notation"),
+        )
+}
+
+/// Runs the `enumerate` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let parsed = parse_arg(matches, "states").and_then(|states| {
+        Ok((
+            states,
+            parse_arg(matches, "symbols")?,
+            parse_arg(matches, "limit")?,
+        ))
+    });
+    let (states, symbols, limit): (usize, usize, usize) = match parsed {
+        Ok(parsed) => parsed,
+        Err(code) => return code,
+    };
+    if !(1..=25).contains(&states) || !(2..=10).contains(&symbols) {
+        error!("There must be between 1 and 25 states and between 2 and 10 symbols");
+        return 2;
+    }
+
+    let enumeration = enumeration::enumerate(states, symbols, limit);
+    println!("machines {}", enumeration.machines);
+    println!("halting {}", enumeration.halting);
+    println!("cycling {}", enumeration.cycling);
+    println!("undecided {}", enumeration.undecided.len());
+    if let Some((score, machine)) = &enumeration.most_steps {
+        println!("most steps {} by {}", score.steps, machine);
+    }
+    if let Some((score, machine)) = &enumeration.most_ones {
+        println!("most ones {} by {}", score.ones, machine);
+    }
+
+    if let Some(path) = matches.value_of("undecided") {
+        let written = File::create(path).and_then(|f| {
+            let mut w = BufWriter::new(f);
+            for machine in &enumeration.undecided {
+                writeln!(w, "{}", machine)?;
+            }
+            w.flush()
+        });
+        if let Err(e) = written {
+            error!("IO({:?})", e);
+            return 3;
+        }
+    }
+    0
+}
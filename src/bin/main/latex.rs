@@ -0,0 +1,168 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{fs::File, io::Write};
+
+use turing_machine::{
+    builders::TuringMachineBuilder,
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    graph::StateGraph,
+    hooked::HookedTuringMachineExt,
+    latex::{self, ConfigurationRecorder},
+    limited::LimitedTuringMachineExt,
+    machine_parser, TuringMachine,
+};
+
+use crate::{handle_error, load_repr, parse_arg, read_tape_file, ErrorType};
+
+type Machine = DeterministicTuringMachine<String>;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("latex")
+        .about(
+            "Typeset the configurations of a deterministic run, or the state diagram with --tikz",
+        )
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("tapefile")
+                .index(2)
+                .value_name("TAPE_FILE")
+                .help("A file containing the tape the machine should start on"),
+        )
+        .arg(
+            Arg::with_name("tapevalue")
+                .short("T")
+                .long("tape")
+                .conflicts_with("tapefile")
+                .takes_value(true)
+                .value_name("TAPE")
+                .help("Inline tape for testing"),
+        )
+        .arg(
+            Arg::with_name("tikz")
+                .long("tikz")
+                .conflicts_with_all(&["tapefile", "tapevalue"])
+                .help("Output the state diagram as a TikZ picture"),
+        )
+        .arg(
+            Arg::with_name("columns")
+                .long("columns")
+                .takes_value(true)
+                .default_value("4")
+                .help("The number of columns the states of the diagram are laid out in"),
+        )
+        .arg(
+            Arg::with_name("standalone")
+                .short("s")
+                .long("standalone")
+                .help("Output a complete document instead of a fragment"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .short("l")
+                .long("limit")
+                .default_value("100")
+                .help("Limit the number of steps the machine is allowed to take"),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .value_name("TEX_FILE")
+                .help("Write to a file instead of stdout"),
+        )
+}
+
+fn tikz(repr_path: &str, columns: usize) -> Result<String, i32> {
+    let parser = match File::open(repr_path) {
+        Ok(f) => match machine_parser::parse(f) {
+            Ok(parser) => parser,
+            Err(e) => {
+                error!("Parsing({:?})", e);
+                return Err(2);
+            }
+        },
+        Err(e) => {
+            error!("IO({:?})", e);
+            return Err(3);
+        }
+    };
+
+    // The parser always sets the starting state
+    Ok(latex::tikz(
+        &StateGraph::from_builder(&parser).unwrap(),
+        columns,
+    ))
+}
+
+fn configurations(repr_path: &str, tape: Vec<char>, limit: usize) -> Result<String, i32> {
+    let repr = load_repr::<Machine, DeterministicMachineRepresentation<String>>(repr_path)
+        .map_err(handle_error)?;
+    let machine = Machine::from_builder(TuringMachineBuilder::new().repr(repr).tape(tape))
+        .map_err(|e| handle_error::<Machine>(ErrorType::MachineCreation(e)))?;
+
+    let machine = LimitedTuringMachineExt::new_with_limit(
+        HookedTuringMachineExt::new(machine, ConfigurationRecorder::new()),
+        limit,
+    );
+    let exe = machine.execute_and_get_result();
+    if !exe.tm.is_finished() {
+        error!("The machine was stopped after {} steps", limit);
+    }
+    Ok(exe.tm.hook().to_latex())
+}
+
+/// Runs the `latex` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+
+    let body = if matches.is_present("tikz") {
+        match parse_arg(matches, "columns") {
+            Ok(columns) => tikz(repr_path, columns),
+            Err(code) => return code,
+        }
+    } else {
+        let tape = match matches.value_of("tapefile") {
+            Some(p) => match read_tape_file(p) {
+                Ok(tape) => tape,
+                Err(e) => return handle_error::<Machine>(ErrorType::IO(e)),
+            },
+            None => matches
+                .value_of("tapevalue")
+                .map_or_else(Vec::new, |s| s.chars().collect()),
+        };
+        match parse_arg(matches, "limit") {
+            Ok(limit) => configurations(repr_path, tape, limit),
+            Err(code) => return code,
+        }
+    };
+    let body = match body {
+        Ok(body) => body,
+        Err(code) => return code,
+    };
+    let out = if matches.is_present("standalone") {
+        latex::standalone(&body)
+    } else {
+        body
+    };
+
+    let written = match matches.value_of("output") {
+        Some(path) => File::create(path).and_then(|mut f| f.write_all(out.as_bytes())),
+        None => {
+            print!("{}", out);
+            Ok(())
+        }
+    };
+    match written {
+        Ok(()) => 0,
+        Err(e) => handle_error::<Machine>(ErrorType::IO(e)),
+    }
+}
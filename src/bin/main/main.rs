@@ -0,0 +1,824 @@
+mod animate;
+mod bb;
+mod binary;
+mod check;
+mod complement;
+mod complexity;
+mod debug;
+mod decide;
+mod diagram;
+mod draw;
+mod enumerate;
+mod latex;
+mod minimize;
+mod suite;
+mod universal;
+
+use clap::{App, AppSettings, Arg, ArgMatches};
+use log::{debug, error, info};
+use std::{
+    fmt::{Debug, Display},
+    fs::File,
+    io::{self, Read},
+    ops::Range,
+    process,
+    str::FromStr,
+};
+
+use turing_machine::{
+    accelerate::AcceleratedTuringMachineExt,
+    big::BigUint,
+    builders::TuringMachineBuilder,
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    function::{FunctionSpec, Value},
+    limited::LimitedTuringMachineExt,
+    machine_parser::{self, ParsingError},
+    machine_representation::MachineRepresentation,
+    macro_machine::MacroTuringMachine,
+    non_deterministic_tm::{NonDeterministicMachineRepresentation, NonDeterministicTuringMachine},
+    snapshot::{Resumable, Snapshot, SnapshotError, SnapshotTuringMachineExt},
+    stats::ExecutionResult,
+    tape::{RunLengthTape, Tape},
+    TuringMachine,
+};
+
+#[derive(Debug)]
+enum ErrorType<T>
+where
+    T: TuringMachine,
+{
+    IO(io::Error),
+    Parsing(ParsingError),
+    ReprCreation(<T::ReprTy as MachineRepresentation<T::StateTy>>::ErrorTy),
+    MachineCreation(T::ErrorTy),
+    Snapshot(SnapshotError),
+}
+
+/// The outcome of running a machine from the command line
+type Execution<T> = Result<ExecutionResult<T>, ErrorType<T>>;
+
+impl<T> From<io::Error> for ErrorType<T>
+where
+    T: TuringMachine,
+{
+    fn from(err: io::Error) -> Self {
+        ErrorType::IO(err)
+    }
+}
+
+impl<T> From<ParsingError> for ErrorType<T>
+where
+    T: TuringMachine,
+{
+    fn from(err: ParsingError) -> Self {
+        ErrorType::Parsing(err)
+    }
+}
+
+impl<T> From<SnapshotError> for ErrorType<T>
+where
+    T: TuringMachine,
+{
+    fn from(err: SnapshotError) -> Self {
+        ErrorType::Snapshot(err)
+    }
+}
+
+/// Where to resume a run from, and where to save it to
+struct SnapshotArgs<'a> {
+    resume: Option<&'a str>,
+    save: Option<(usize, &'a str)>,
+}
+
+fn load_repr<T, Repr>(repr_path: &str) -> Result<Repr, ErrorType<T>>
+where
+    T: TuringMachine<StateTy = String, ReprTy = Repr>,
+    Repr: MachineRepresentation<String, InputTy = char>,
+{
+    // Open the repr file
+    let repr_file = File::open(repr_path)?;
+
+    debug!("Parsing {} ...", repr_path);
+    // Parse to TM bc
+    let repr_builder = machine_parser::parse(repr_file)?;
+
+    debug!("Building Representation ...");
+    // Build the representation
+    Repr::from_builder(&repr_builder).map_err(ErrorType::ReprCreation)
+}
+
+fn run<T, Repr>(
+    repr_path: &str,
+    tape: Vec<char>,
+    limit: Option<usize>,
+    snapshots: &SnapshotArgs,
+) -> Execution<T>
+where
+    T: Resumable + TuringMachine<StateTy = String, ReprTy = Repr>,
+    Repr: MachineRepresentation<String, InputTy = char>,
+{
+    info!("Tape: {:?}", tape);
+
+    let repr = load_repr::<T, Repr>(repr_path)?;
+
+    let (machine, steps) = match snapshots.resume {
+        Some(path) => {
+            debug!("Resuming from {} ...", path);
+            let snapshot = Snapshot::read(File::open(path)?)?;
+            (T::resume(repr, &snapshot)?, snapshot.steps)
+        }
+        None => {
+            debug!("Creating Machine Builder ...");
+            // Adjoin with the tape
+            let builder = TuringMachineBuilder::new().repr(repr).tape(tape);
+
+            debug!("Creating Machine ...");
+            // Build the machine
+            let machine = T::from_builder(builder).map_err(ErrorType::MachineCreation)?;
+            (machine, 0)
+        }
+    };
+
+    // Save snapshots along the way if asked to
+    let machine = match snapshots.save {
+        Some((every, path)) => SnapshotTuringMachineExt::with_saving(machine, steps, every, path),
+        None => SnapshotTuringMachineExt::new(machine, steps),
+    };
+
+    // Decorate with stats extension, the steps taken before resuming counting towards the limit
+    let machine = match limit {
+        Some(limit) => {
+            LimitedTuringMachineExt::new_with_limit(machine, limit.saturating_sub(steps))
+        }
+        None => LimitedTuringMachineExt::new(machine),
+    };
+
+    debug!("Execution Start ...");
+    // Run to completion, counting the steps taken before resuming too
+    let mut exe = machine.execute_and_get_result();
+    if let Some(e) = exe.tm.take_failure() {
+        error!("Saving a snapshot failed");
+        return Err(ErrorType::IO(e));
+    }
+    Ok(ExecutionResult {
+        accepting: exe.accepting,
+        halting: exe.halting,
+        tape: exe.tape,
+        num_steps: exe.num_steps + steps,
+        tm: exe.tm.inner(),
+    })
+}
+
+/// Run a deterministic machine taking sweeps in one go, with the same verdict and steps as [`run`](fn.run.html).
+/// A machine sweeping right over the blanks forever is stopped where the sweep starts, so its tape lacks what the sweep
+/// would have written before reaching the limit.
+fn run_accelerated<TapeTy: Tape>(
+    repr_path: &str,
+    tape: Vec<char>,
+    limit: Option<usize>,
+) -> Execution<DeterministicTuringMachine<String, TapeTy>> {
+    info!("Tape: {:?}", tape);
+
+    let repr = load_repr::<_, DeterministicMachineRepresentation<String>>(repr_path)?;
+    let builder = TuringMachineBuilder::new().repr(repr).tape(tape);
+    let machine = DeterministicTuringMachine::<String, TapeTy>::from_builder(builder)
+        .map_err(ErrorType::MachineCreation)?;
+    let mut machine = AcceleratedTuringMachineExt::new(machine);
+
+    debug!("Execution Start ...");
+    // As with the limited extension, a machine over the limit took one step more than it
+    let max_steps = limit.map_or(usize::MAX, |l| l.saturating_add(1));
+    while !machine.is_finished() && machine.get_number_of_steps() < max_steps {
+        machine.macro_step(max_steps - machine.get_number_of_steps());
+    }
+    info!(
+        " Took {} steps in {} sweeps",
+        machine.get_number_of_steps(),
+        machine.get_number_of_macro_steps()
+    );
+    if machine.is_looping() {
+        info!(" The machine sweeps over the blanks forever");
+    }
+
+    let num_steps = match limit {
+        // The sweep would have gone on past the limit
+        Some(l) if machine.is_looping() => l.saturating_add(1),
+        _ => machine.get_number_of_steps(),
+    };
+    let limited = limit.is_some_and(|l| num_steps > l);
+    Ok(ExecutionResult {
+        accepting: !limited && machine.is_accepting(),
+        halting: !limited && machine.is_halting(),
+        tape: machine.tape().clone(),
+        num_steps,
+        tm: machine.inner(),
+    })
+}
+
+/// Run a deterministic machine as a macro machine over blocks of `block_size` cells.
+/// Returns whether the run went over the limit, which is only noticed at the end of a macro step.
+fn run_macro(
+    repr_path: &str,
+    tape: Vec<char>,
+    limit: Option<usize>,
+    block_size: usize,
+) -> Result<(MacroTuringMachine<String>, bool), ErrorType<MacroTuringMachine<String>>> {
+    info!("Tape: {:?}", tape);
+
+    let repr = load_repr::<_, DeterministicMachineRepresentation<String>>(repr_path)?;
+    let builder = TuringMachineBuilder::new().repr(repr).tape(tape);
+    let mut machine = MacroTuringMachine::with_block_size(builder, block_size)
+        .map_err(ErrorType::MachineCreation)?;
+
+    debug!("Execution Start ...");
+    let limit = limit.map(|l| BigUint::from(l as u64));
+    let over = |machine: &MacroTuringMachine<String>| {
+        limit
+            .as_ref()
+            .is_some_and(|l| machine.get_number_of_steps() > l)
+    };
+    while !machine.is_finished() && !over(&machine) {
+        machine.step();
+    }
+    info!(
+        " Took {} steps in {} macro steps, using {} macro transitions",
+        machine.get_number_of_steps(),
+        machine.get_number_of_macro_steps(),
+        machine.get_number_of_macro_transitions()
+    );
+    if machine.is_looping() {
+        info!(" The machine never leaves a block");
+    }
+    info!(" Final tape: {}", machine.tape().compressed());
+
+    let limited = over(&machine);
+    Ok((machine, limited))
+}
+
+fn handle_macro_and_get_exit_code(
+    res: Result<(MacroTuringMachine<String>, bool), ErrorType<MacroTuringMachine<String>>>,
+    limit: Option<usize>,
+) -> i32 {
+    match res {
+        Ok((tm, limited)) => {
+            // As with the limited extension, a machine over the limit took one step more than it
+            let steps = match limit {
+                Some(l) if limited => BigUint::from(l as u64 + 1),
+                _ => tm.get_number_of_steps().clone(),
+            };
+            let res = if limited {
+                println!("not accepted");
+                1
+            } else if tm.is_accepting() {
+                println!("accepted");
+                0
+            } else if tm.is_halting() {
+                println!("halted");
+                0
+            } else {
+                println!("not accepted");
+                1
+            };
+            println!(
+                "{}",
+                steps.checked_sub(&BigUint::from(1u64)).unwrap_or_default()
+            );
+            print!("{}", tm);
+
+            res
+        }
+        Err(ty) => handle_error(ty),
+    }
+}
+
+fn handle_and_get_exit_code<T>(res: Execution<T>) -> i32
+where
+    T: TuringMachine + Display,
+    T::ErrorTy: Display,
+{
+    match res {
+        Ok(exe) => {
+            info!(" Machine ran for {} steps", exe.num_steps);
+            info!(" Final configuration: {:?}", exe.tape);
+
+            let res = print_verdict(&exe);
+            println!("{}", exe.num_steps - 1);
+            print!("{}", exe.tm);
+
+            res
+        }
+        Err(ty) => handle_error(ty),
+    }
+}
+
+fn handle_function_and_get_exit_code(
+    res: Result<
+        ExecutionResult<DeterministicTuringMachine<String>>,
+        ErrorType<DeterministicTuringMachine<String>>,
+    >,
+    spec: &FunctionSpec,
+    expected: Option<Value>,
+) -> i32 {
+    match res {
+        Ok(exe) => {
+            info!(" Machine ran for {} steps", exe.num_steps);
+            info!(" Final configuration: {:?}", exe.tape);
+
+            let res = print_verdict(&exe);
+            println!("{}", exe.num_steps - 1);
+
+            let output = match spec.decode_output(&exe.tape, exe.tm.head()) {
+                Ok(output) => output,
+                Err(e) => {
+                    error!("Output({:?})", e);
+                    println!("invalid output");
+                    return 1;
+                }
+            };
+            println!("{}", output);
+
+            match expected {
+                Some(expected) if expected == output => {
+                    println!("match");
+                    res
+                }
+                Some(expected) => {
+                    println!("mismatch, expected {}", expected);
+                    1
+                }
+                None => res,
+            }
+        }
+        Err(ty) => handle_error(ty),
+    }
+}
+
+fn print_verdict<T: TuringMachine>(exe: &ExecutionResult<T>) -> i32 {
+    if exe.accepting {
+        println!("accepted");
+        0
+    } else if exe.halting {
+        println!("halted");
+        0
+    } else {
+        println!("not accepted");
+        1
+    }
+}
+
+fn handle_error<T>(ty: ErrorType<T>) -> i32
+where
+    T: TuringMachine,
+    T::ErrorTy: Display,
+{
+    match ty {
+        ErrorType::Parsing(e) => {
+            error!("Parsing({:?})", e);
+            2
+        }
+        ErrorType::ReprCreation(e) => {
+            error!("Repr({:?})", e);
+            2
+        }
+        ErrorType::MachineCreation(e) => {
+            error!("Machine({})", e);
+            2
+        }
+        ErrorType::Snapshot(e) => {
+            error!("Snapshot({:?})", e);
+            2
+        }
+        ErrorType::IO(e) => {
+            error!("IO({:?})", e);
+            3
+        }
+    }
+}
+
+/// Read the tape in a file, skipping whitespace, so that cells count symbols rather than offsets in the file
+fn read_tape_file(path: &str) -> io::Result<Vec<char>> {
+    let mut input_file = File::open(path)?;
+    let mut buf = String::new();
+    input_file.read_to_string(&mut buf)?;
+    Ok(buf
+        .chars()
+        .filter(|c| c.is_ascii() && !c.is_whitespace())
+        .collect())
+}
+
+/// Parse a range written `START..END`, logging what is wrong with it and returning the exit code for bad input otherwise
+fn parse_range(s: &str) -> Result<Range<usize>, i32> {
+    let mut bounds = s.splitn(2, "..");
+    let start = parse_value("range start", bounds.next().unwrap().trim())?;
+    match bounds.next() {
+        Some(end) => Ok(start..parse_value("range end", end.trim())?),
+        None => {
+            error!("The range must be START..END, not {}", s);
+            Err(2)
+        }
+    }
+}
+
+/// Parse the value of an argument, logging what is wrong with it and returning the exit code for bad input otherwise
+fn parse_value<T>(name: &str, value: &str) -> Result<T, i32>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    value.parse::<T>().map_err(|e| {
+        error!("Argument({}: {:?})", name, e);
+        2
+    })
+}
+
+fn parse_arg<T>(matches: &ArgMatches, name: &str) -> Result<T, i32>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    parse_value(name, matches.value_of(name).unwrap())
+}
+
+/// Parse an argument which may not be given
+fn parse_optional_arg<T>(matches: &ArgMatches, name: &str) -> Result<Option<T>, i32>
+where
+    T: FromStr,
+    T::Err: Debug,
+{
+    matches
+        .value_of(name)
+        .map(|value| parse_value(name, value))
+        .transpose()
+}
+
+fn main() {
+    let matches = App::new("Turing Machine")
+        .version("0.1")
+        .author("Giacomo Fenzi <giacomofenzi@outlook.com>")
+        .about("Simulate a Turing Machine")
+        .setting(AppSettings::SubcommandsNegateReqs)
+        .subcommand(suite::subcommand())
+        .subcommand(animate::subcommand())
+        .subcommand(bb::subcommand())
+        .subcommand(binary::subcommand())
+        .subcommand(check::subcommand())
+        .subcommand(complement::subcommand())
+        .subcommand(complexity::subcommand())
+        .subcommand(debug::subcommand())
+        .subcommand(decide::subcommand())
+        .subcommand(diagram::subcommand())
+        .subcommand(draw::subcommand())
+        .subcommand(enumerate::subcommand())
+        .subcommand(latex::subcommand())
+        .subcommand(minimize::subcommand())
+        .subcommand(universal::subcommand())
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .takes_value(true)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("tapefile")
+                .short("t")
+                .index(2)
+                .takes_value(true)
+                .value_name("TAPE_FILE")
+                .help("A file containing the tape the machine should start on"),
+        )
+        .arg(
+            Arg::with_name("ndtm")
+                .long("nondeterministic")
+                .short("n")
+                .help("Use a non deterministic TM"),
+        )
+        .arg(
+            Arg::with_name("limit")
+                .takes_value(true)
+                .value_name("STEP_LIMIT")
+                .short("l")
+                .long("limit")
+                .help("Limit the number of steps the machine is allowed to take"),
+        )
+        .arg(
+            Arg::with_name("tapevalue")
+                .short("T")
+                .long("tape")
+                .conflicts_with("tapefile")
+                .takes_value(true)
+                .value_name("TAPE")
+                .help("Inline tape for testing"),
+        )
+        .arg(
+            Arg::with_name("function")
+                .long("function")
+                .short("f")
+                .conflicts_with("ndtm")
+                .help("Treat the machine as computing a function, and print the decoded output"),
+        )
+        .arg(
+            Arg::with_name("args")
+                .long("args")
+                .short("a")
+                .requires("function")
+                .conflicts_with_all(&["tapefile", "tapevalue"])
+                .takes_value(true)
+                .value_name("ARGS")
+                .help("Comma separated arguments, encoded on the tape with the input codec"),
+        )
+        .arg(
+            Arg::with_name("input_codec")
+                .long("input-codec")
+                .takes_value(true)
+                .value_name("CODEC")
+                .default_value("raw")
+                .help(
+                    "How arguments are written on the tape: raw, unary, unary:<symbol> or binary",
+                ),
+        )
+        .arg(
+            Arg::with_name("output_codec")
+                .long("output-codec")
+                .takes_value(true)
+                .value_name("CODEC")
+                .default_value("raw")
+                .help("How the output is read from the tape: raw, unary, unary:<symbol> or binary"),
+        )
+        .arg(
+            Arg::with_name("output_region")
+                .long("output-region")
+                .takes_value(true)
+                .value_name("REGION")
+                .default_value("tape")
+                .help("Where the output lies on the tape: tape, head or between:<open><close>"),
+        )
+        .arg(
+            Arg::with_name("separator")
+                .long("separator")
+                .requires("args")
+                .takes_value(true)
+                .value_name("SYMBOL")
+                .help("The symbol written between consecutive arguments"),
+        )
+        .arg(
+            Arg::with_name("expected")
+                .long("expected")
+                .short("e")
+                .requires("function")
+                .takes_value(true)
+                .value_name("VALUE")
+                .help("The expected output, compared with the decoded one"),
+        )
+        .arg(
+            Arg::with_name("run_length")
+                .long("run-length")
+                .short("r")
+                .conflicts_with("function")
+                .help("Store the tape as runs of repeated symbols, for machines using huge uniform regions"),
+        )
+        .arg(
+            Arg::with_name("accelerate")
+                .long("accelerate")
+                .short("x")
+                .conflicts_with_all(&["ndtm", "function", "resume", "save_every"])
+                .help("Jump across sweeps over runs of a symbol in one go, best with --run-length"),
+        )
+        .arg(
+            Arg::with_name("block_size")
+                .long("block-size")
+                .short("k")
+                .conflicts_with_all(&["ndtm", "function", "accelerate", "run_length", "resume", "save_every"])
+                .takes_value(true)
+                .value_name("CELLS")
+                .help("Run as a macro machine over blocks of CELLS cells, counting steps past what fits in a usize"),
+        )
+        .arg(
+            Arg::with_name("resume")
+                .long("resume")
+                .conflicts_with_all(&["tapefile", "tapevalue", "args"])
+                .takes_value(true)
+                .value_name("SNAPSHOT_FILE")
+                .help("Resume a run from a snapshot of the same machine, instead of starting on a tape"),
+        )
+        .arg(
+            Arg::with_name("save_every")
+                .long("save-every")
+                .requires("snapshot")
+                .takes_value(true)
+                .value_name("STEPS")
+                .help("Save a snapshot of the run every STEPS steps"),
+        )
+        .arg(
+            Arg::with_name("snapshot")
+                .long("snapshot")
+                .requires("save_every")
+                .takes_value(true)
+                .value_name("SNAPSHOT_FILE")
+                .help("The file snapshots are saved to, replacing the previous one"),
+        )
+        .get_matches();
+
+    // Initialize the logger
+    env_logger::init();
+
+    match matches.subcommand() {
+        ("test", Some(matches)) => process::exit(suite::run(matches)),
+        ("complement", Some(matches)) => process::exit(complement::run(matches)),
+        ("complexity", Some(matches)) => process::exit(complexity::run(matches)),
+        ("debug", Some(matches)) => process::exit(debug::run(matches)),
+        ("decide", Some(matches)) => process::exit(decide::run(matches)),
+        ("diagram", Some(matches)) => process::exit(diagram::run(matches)),
+        ("draw", Some(matches)) => process::exit(draw::run(matches)),
+        ("enumerate", Some(matches)) => process::exit(enumerate::run(matches)),
+        ("animate", Some(matches)) => process::exit(animate::run(matches)),
+        ("bb", Some(matches)) => process::exit(bb::run(matches)),
+        ("binary", Some(matches)) => process::exit(binary::run(matches)),
+        ("check", Some(matches)) => process::exit(check::run(matches)),
+        ("latex", Some(matches)) => process::exit(latex::run(matches)),
+        ("minimize", Some(matches)) => process::exit(minimize::run(matches)),
+        ("utm", Some(matches)) => process::exit(universal::run(matches)),
+        _ => {}
+    }
+
+    let exit_code = run_machine(&matches).unwrap_or_else(|code| code);
+
+    // Handle remaining case
+    match exit_code {
+        2 => println!("input error"),
+        3 => println!("IO Error"),
+        _ => {}
+    }
+
+    process::exit(exit_code);
+}
+
+/// Runs the machine given on the command line, returning the exit code, or the exit code of bad input as an error
+fn run_machine(matches: &ArgMatches) -> Result<i32, i32> {
+    // Path is required, so it must be this
+    let repr_path = matches.value_of("repr").unwrap();
+    let tape_file = matches.value_of("tapefile");
+
+    let spec = FunctionSpec {
+        input: parse_arg(matches, "input_codec")?,
+        output: parse_arg(matches, "output_codec")?,
+        region: parse_arg(matches, "output_region")?,
+        separator: match matches.value_of("separator").map(|s| s.chars()) {
+            Some(mut chars) => match (chars.next(), chars.next()) {
+                (Some(c), None) => Some(c),
+                _ => {
+                    error!("The separator must be a single symbol");
+                    return Err(2);
+                }
+            },
+            None => None,
+        },
+    };
+
+    // One of the three branches must necessarily be true
+    let tape: Vec<char> = match tape_file {
+        Some(p) => read_tape_file(p).map_err(|e| {
+            error!("IO({:?})", e);
+            3
+        })?,
+        None => match (matches.value_of("tapevalue"), matches.value_of("args")) {
+            (Some(s), _) => s.chars().collect(),
+            (None, Some(args)) => {
+                let args: Vec<Value> = args
+                    .split(',')
+                    .map(|arg| spec.input.parse_value(arg))
+                    .collect::<Result<_, _>>()
+                    .map_err(|e| {
+                        error!("Arguments({:?})", e);
+                        2
+                    })?;
+                spec.encode_input(&args).map_err(|e| {
+                    error!("Encoding({:?})", e);
+                    2
+                })?
+            }
+            (None, None) => Vec::new(),
+        },
+    };
+
+    let limit = parse_optional_arg(matches, "limit")?;
+
+    let snapshots = SnapshotArgs {
+        resume: matches.value_of("resume"),
+        save: match matches.value_of("snapshot") {
+            Some(path) => Some((parse_arg(matches, "save_every")?, path)),
+            None => None,
+        },
+    };
+
+    let exit_code = if matches.is_present("function") {
+        let expected = match matches.value_of("expected") {
+            Some(s) => Some(spec.output.parse_value(s).map_err(|e| {
+                error!("Expected({:?})", e);
+                2
+            })?),
+            None => None,
+        };
+        let result = run::<
+            DeterministicTuringMachine<String>,
+            DeterministicMachineRepresentation<String>,
+        >(repr_path, tape, limit, &snapshots);
+        handle_function_and_get_exit_code(result, &spec, expected)
+    } else if matches.is_present("block_size") {
+        let block_size: usize = parse_arg(matches, "block_size")?;
+        if block_size == 0 {
+            error!("The block size must be positive");
+            2
+        } else {
+            handle_macro_and_get_exit_code(run_macro(repr_path, tape, limit, block_size), limit)
+        }
+    } else if matches.is_present("accelerate") {
+        if matches.is_present("run_length") {
+            handle_and_get_exit_code(run_accelerated::<RunLengthTape>(repr_path, tape, limit))
+        } else {
+            handle_and_get_exit_code(run_accelerated::<Vec<char>>(repr_path, tape, limit))
+        }
+    } else if !matches.is_present("ndtm") {
+        if matches.is_present("run_length") {
+            let result = run::<
+                DeterministicTuringMachine<String, RunLengthTape>,
+                DeterministicMachineRepresentation<String>,
+            >(repr_path, tape, limit, &snapshots);
+            handle_and_get_exit_code(result)
+        } else {
+            let result = run::<
+                DeterministicTuringMachine<String>,
+                DeterministicMachineRepresentation<String>,
+            >(repr_path, tape, limit, &snapshots);
+            handle_and_get_exit_code(result)
+        }
+    } else if matches.is_present("run_length") {
+        let result = run::<
+            NonDeterministicTuringMachine<String, RunLengthTape>,
+            NonDeterministicMachineRepresentation<String>,
+        >(repr_path, tape, limit, &snapshots);
+        handle_and_get_exit_code(result)
+    } else {
+        let result = run::<
+            NonDeterministicTuringMachine<String>,
+            NonDeterministicMachineRepresentation<String>,
+        >(repr_path, tape, limit, &snapshots);
+        handle_and_get_exit_code(result)
+    };
+
+    Ok(exit_code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{env, fs};
+
+    const NO_SNAPSHOTS: SnapshotArgs = SnapshotArgs {
+        resume: None,
+        save: None,
+    };
+
+    #[test]
+    fn accelerated_runs_take_the_same_steps() {
+        // Sweeping right over the blanks forever
+        let sweep = env::temp_dir().join(format!("sweep-{}.tm", process::id()));
+        fs::write(
+            &sweep,
+            "states 3\nq0\nqa +\nqr -\nalphabet 1 a\nq0 a q0 a R\nq0 _ q0 a R\n",
+        )
+        .unwrap();
+        let sweep = sweep.to_str().unwrap();
+        let paren = concat!(env!("CARGO_MANIFEST_DIR"), "/data/paren.tm");
+
+        let cases = [
+            (sweep, "aa", Some(100)),
+            (sweep, "", Some(0)),
+            (paren, "(())", None),
+            (paren, "(())", Some(5)),
+            (paren, "(()", Some(100)),
+        ];
+        for (path, tape, limit) in &cases {
+            let tape: Vec<char> = tape.chars().collect();
+            let plain = run::<
+                DeterministicTuringMachine<String>,
+                DeterministicMachineRepresentation<String>,
+            >(path, tape.clone(), *limit, &NO_SNAPSHOTS)
+            .unwrap();
+            let accelerated = run_accelerated::<Vec<char>>(path, tape, *limit).unwrap();
+            assert_eq!(
+                (
+                    accelerated.accepting,
+                    accelerated.halting,
+                    accelerated.num_steps
+                ),
+                (plain.accepting, plain.halting, plain.num_steps),
+                "On {:?} with limit {:?}",
+                path,
+                limit
+            );
+        }
+        fs::remove_file(sweep).unwrap();
+    }
+}
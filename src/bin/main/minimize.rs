@@ -0,0 +1,99 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use std::{
+    fmt::{Debug, Display},
+    fs::File,
+    io::{self, BufWriter},
+};
+
+use turing_machine::{
+    common::representation::GeneralMachineRepresentation,
+    deterministic_tm::DeterministicTuringMachine,
+    machine_parser,
+    machine_representation::MachineRepresentation,
+    minimize,
+    non_deterministic_tm::NonDeterministicTuringMachine,
+    transition_table::{ActionSet, TransitionTable},
+    TuringMachine,
+};
+
+use crate::{handle_error, load_repr, ErrorType};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("minimize")
+        .about("Remove useless states and merge equivalent ones, writing the machine back as .tm")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("ndtm")
+                .long("nondeterministic")
+                .short("n")
+                .help("Use a non deterministic TM"),
+        )
+        .arg(Arg::with_name("keep_dead").long("keep-dead").help(
+            "Keep the states that can never accept or halt, for machines meant to run forever",
+        ))
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .takes_value(true)
+                .value_name("TM_FILE")
+                .help("Write to a file instead of stdout"),
+        )
+}
+
+fn minimize_machine<T, TableTy>(repr_path: &str, output: Option<&str>, keep_dead: bool) -> i32
+where
+    T: TuringMachine<StateTy = String, ReprTy = GeneralMachineRepresentation<String, TableTy>>,
+    T::ErrorTy: Display,
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    let repr = match load_repr::<T, T::ReprTy>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+    let minimized = if keep_dead {
+        minimize::merge_equivalent_states(&repr)
+    } else {
+        minimize::minimize(&repr)
+    };
+
+    let written = match output {
+        Some(path) => {
+            File::create(path).and_then(|f| machine_parser::write(&minimized, BufWriter::new(f)))
+        }
+        None => machine_parser::write(&minimized, io::stdout().lock()),
+    };
+    if let Err(e) = written {
+        return handle_error::<T>(ErrorType::IO(e));
+    }
+
+    if let Some(path) = output {
+        println!(
+            "{} states reduced to {}, written to {}",
+            repr.states().len(),
+            minimized.states().len(),
+            path
+        );
+    }
+    0
+}
+
+/// Runs the `minimize` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+    let output = matches.value_of("output");
+    let keep_dead = matches.is_present("keep_dead");
+
+    if matches.is_present("ndtm") {
+        minimize_machine::<NonDeterministicTuringMachine<String>, _>(repr_path, output, keep_dead)
+    } else {
+        minimize_machine::<DeterministicTuringMachine<String>, _>(repr_path, output, keep_dead)
+    }
+}
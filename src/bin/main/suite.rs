@@ -0,0 +1,147 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{fmt::Display, fs::File, thread};
+
+use turing_machine::{
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    machine_representation::MachineRepresentation,
+    non_deterministic_tm::{NonDeterministicMachineRepresentation, NonDeterministicTuringMachine},
+    test_suite::{self, CaseResult},
+    TuringMachine,
+};
+
+use crate::{handle_error, load_repr, parse_optional_arg};
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("test")
+        .about("Run a test file against a machine")
+        .arg(
+            Arg::with_name("repr")
+                .required(true)
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file to use"),
+        )
+        .arg(
+            Arg::with_name("suite")
+                .required(true)
+                .index(2)
+                .value_name("TEST_FILE")
+                .help("The test file, one case per line"),
+        )
+        .arg(
+            Arg::with_name("ndtm")
+                .long("nondeterministic")
+                .short("n")
+                .help("Use a non deterministic TM"),
+        )
+        .arg(
+            Arg::with_name("jobs")
+                .long("jobs")
+                .short("j")
+                .takes_value(true)
+                .value_name("THREADS")
+                .help("Number of threads used to run the cases, defaults to the available parallelism"),
+        )
+}
+
+/// Runs the `test` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    let repr_path = matches.value_of("repr").unwrap();
+    let suite_path = matches.value_of("suite").unwrap();
+    let jobs = match parse_optional_arg(matches, "jobs") {
+        Ok(jobs) => jobs.unwrap_or_else(|| thread::available_parallelism().map_or(1, |n| n.get())),
+        Err(code) => return code,
+    };
+
+    let cases = match File::open(suite_path)
+        .map_err(test_suite::TestSuiteError::IO)
+        .and_then(test_suite::parse)
+    {
+        Ok(cases) => cases,
+        Err(e) => {
+            error!("TestSuite({:?})", e);
+            return 2;
+        }
+    };
+
+    if matches.is_present("ndtm") {
+        run_cases::<
+            NonDeterministicTuringMachine<String>,
+            NonDeterministicMachineRepresentation<String>,
+        >(repr_path, &cases, jobs)
+    } else {
+        run_cases::<DeterministicTuringMachine<String>, DeterministicMachineRepresentation<String>>(
+            repr_path, &cases, jobs,
+        )
+    }
+}
+
+fn run_cases<T, Repr>(repr_path: &str, cases: &[test_suite::TestCase], jobs: usize) -> i32
+where
+    T: TuringMachine<StateTy = String, ReprTy = Repr> + Display,
+    T::ErrorTy: Display,
+    Repr: MachineRepresentation<String, InputTy = char> + Clone + Sync,
+{
+    let repr = match load_repr::<T, Repr>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+
+    let results = test_suite::run_suite::<T>(&repr, cases, jobs);
+    let failed: Vec<_> = results.iter().filter(|r| !r.passed()).collect();
+    for result in &failed {
+        print_failure(result);
+    }
+
+    println!(
+        "{} passed, {} failed",
+        results.len() - failed.len(),
+        failed.len()
+    );
+
+    if failed.is_empty() {
+        0
+    } else {
+        1
+    }
+}
+
+fn print_failure(result: &CaseResult) {
+    let case = &result.case;
+    println!(
+        "FAIL line {}: {}",
+        case.line,
+        case.tape.iter().collect::<String>()
+    );
+
+    if let Some(e) = &result.error {
+        println!("  error:   {}", e);
+        return;
+    }
+
+    if result.timed_out {
+        println!(
+            "  verdict: expected {}, still running after {} steps",
+            case.verdict,
+            result.num_steps - 1
+        );
+    }
+
+    if let Some(verdict) = result.verdict.filter(|v| *v != case.verdict) {
+        println!("  verdict: expected {}, got {}", case.verdict, verdict);
+    }
+
+    if let Some(output) = case.output.as_ref().filter(|o| **o != result.output) {
+        println!("  tape:    expected {}", output);
+        println!("           got      {}", result.output);
+
+        // Point at the first cell that differs
+        let first_diff = output
+            .chars()
+            .zip(result.output.chars())
+            .take_while(|(a, b)| a == b)
+            .count();
+        println!("                    {}^", " ".repeat(first_diff));
+    }
+}
@@ -0,0 +1,104 @@
+use clap::{App, Arg, ArgMatches, SubCommand};
+use log::error;
+use std::{
+    fs::File,
+    io::{self, BufWriter},
+};
+
+use turing_machine::{
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    machine_parser,
+    universal::{self, Description},
+};
+
+use crate::{handle_error, load_repr, ErrorType};
+
+type T = DeterministicTuringMachine<String>;
+
+pub fn subcommand<'a, 'b>() -> App<'a, 'b> {
+    SubCommand::with_name("utm")
+        .about("Write the universal machine as .tm, or encode a machine and its tape for it")
+        .arg(
+            Arg::with_name("repr")
+                .index(1)
+                .value_name("FILE")
+                .help("The representation file of the machine to encode"),
+        )
+        .arg(
+            Arg::with_name("tapevalue")
+                .short("T")
+                .long("tape")
+                .requires("repr")
+                .takes_value(true)
+                .value_name("TAPE")
+                .help("The tape to encode along with the machine"),
+        )
+        .arg(
+            Arg::with_name("decode")
+                .long("decode")
+                .requires("tapevalue")
+                .help(
+                    "Decode TAPE, a tape of the universal machine, back into a tape of the machine",
+                ),
+        )
+        .arg(
+            Arg::with_name("output")
+                .short("o")
+                .long("output")
+                .conflicts_with("repr")
+                .takes_value(true)
+                .value_name("TM_FILE")
+                .help("Write the universal machine to a file instead of stdout"),
+        )
+}
+
+fn translate(repr_path: &str, matches: &ArgMatches) -> i32 {
+    let repr = match load_repr::<T, DeterministicMachineRepresentation<String>>(repr_path) {
+        Ok(repr) => repr,
+        Err(e) => return handle_error(e),
+    };
+    let description = Description::new(&repr);
+    let tape: Vec<char> = matches
+        .value_of("tapevalue")
+        .unwrap_or("")
+        .chars()
+        .collect();
+
+    let translated = if matches.is_present("decode") {
+        description.decode(&tape).map(|(tape, head)| {
+            println!("{}", head);
+            tape
+        })
+    } else {
+        description.encode(&tape)
+    };
+    match translated {
+        Ok(tape) => {
+            println!("{}", tape.into_iter().collect::<String>());
+            0
+        }
+        Err(e) => {
+            error!("Encoding({:?})", e);
+            2
+        }
+    }
+}
+
+/// Runs the `utm` subcommand, returning the exit code
+pub fn run(matches: &ArgMatches) -> i32 {
+    if let Some(repr_path) = matches.value_of("repr") {
+        return translate(repr_path, matches);
+    }
+
+    let utm = universal::universal();
+    let written = match matches.value_of("output") {
+        Some(path) => {
+            File::create(path).and_then(|f| machine_parser::write(&utm, BufWriter::new(f)))
+        }
+        None => machine_parser::write(&utm, io::stdout().lock()),
+    };
+    match written {
+        Ok(()) => 0,
+        Err(e) => handle_error::<T>(ErrorType::IO(e)),
+    }
+}
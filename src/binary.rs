@@ -0,0 +1,401 @@
+//! Reduction of deterministic machines to the binary alphabet `{_, 1}`.
+//!
+//! Each symbol is written as a block of [`width`](struct.BlockEncoding.html#method.width) cells, with `_` standing for
+//! a zero bit, so that the blank is a block of blanks and the blank part of the tape needs no encoding. The reduced
+//! machine reads a whole block moving right, writes the new block back moving left, then moves a whole block in the
+//! direction of the original motion.
+//!
+//! Missing transitions are left missing, as the implicit reject of the reduced machine leaves the tape unchanged.
+//!
+//! # Usage:
+//! ```
+//! use turing_machine::{
+//!     binary, deterministic_tm::DeterministicMachineRepresentation, machine_parser,
+//!     machine_representation::MachineRepresentation,
+//! };
+//!
+//! let repr = DeterministicMachineRepresentation::from_builder(
+//!     &machine_parser::parse("states 3\nq0\nqa +\nqr -\nalphabet 2 a b\nq0 a q0 b R\nq0 _ qa _ S".as_bytes()).unwrap(),
+//! )
+//! .unwrap();
+//!
+//! let (reduced, encoding) = binary::to_binary(&repr);
+//! assert_eq!(encoding.width(), 2);
+//! assert_eq!(reduced.alphabet().len(), 2);
+//! assert_eq!(encoding.encode_tape(&['a', 'b']).unwrap(), vec!['_', '1', '1', '_']);
+//! ```
+
+use hashbrown::HashSet;
+
+use crate::{
+    builders::MachineRepresentationBuilder,
+    common::{Action, Motion, State},
+    deterministic_tm::DeterministicMachineRepresentation,
+    machine_parser::MachineParser,
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+};
+
+type Repr = DeterministicMachineRepresentation<String>;
+
+const ZERO: char = '_';
+const ONE: char = '1';
+
+#[derive(Debug, PartialEq)]
+pub enum EncodingError {
+    /// A symbol outside the alphabet of the machine
+    UnknownSymbol(char),
+
+    /// A block that is not the code of any symbol
+    UnknownBlock(Vec<char>),
+}
+
+/// The fixed width block code of an alphabet, the blank being coded as a block of blanks
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockEncoding {
+    symbols: Vec<char>,
+    width: usize,
+}
+
+impl BlockEncoding {
+    /// The code of an alphabet, which must contain the blank
+    pub fn new(alphabet: &HashSet<char>) -> Self {
+        let mut symbols: Vec<char> = alphabet.iter().cloned().filter(|c| *c != '_').collect();
+        symbols.sort();
+        symbols.insert(0, '_');
+
+        let mut width = 1;
+        while 1 << width < symbols.len() {
+            width += 1;
+        }
+        BlockEncoding { symbols, width }
+    }
+
+    /// The number of cells each symbol takes
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The symbols in the order of their codes
+    pub fn symbols(&self) -> &[char] {
+        &self.symbols
+    }
+
+    fn bits(&self, index: usize) -> Vec<char> {
+        (0..self.width)
+            .rev()
+            .map(|i| if (index >> i) & 1 == 1 { ONE } else { ZERO })
+            .collect()
+    }
+
+    /// The block coding a symbol, most significant bit first
+    pub fn code(&self, symbol: char) -> Result<Vec<char>, EncodingError> {
+        self.symbols
+            .iter()
+            .position(|c| *c == symbol)
+            .map(|index| self.bits(index))
+            .ok_or(EncodingError::UnknownSymbol(symbol))
+    }
+
+    /// Encode a tape for the reduced machine
+    pub fn encode_tape(&self, tape: &[char]) -> Result<Vec<char>, EncodingError> {
+        let mut encoded = Vec::with_capacity(tape.len() * self.width);
+        for c in tape {
+            encoded.extend(self.code(*c)?);
+        }
+        Ok(encoded)
+    }
+
+    /// Decode a tape of the reduced machine, a trailing partial block being padded with blanks
+    pub fn decode_tape(&self, tape: &[char]) -> Result<Vec<char>, EncodingError> {
+        tape.chunks(self.width)
+            .map(|block| {
+                let index = (0..self.width)
+                    .fold(0, |acc, i| acc * 2 + (block.get(i) == Some(&ONE)) as usize);
+                match (
+                    block.iter().all(|c| *c == ZERO || *c == ONE),
+                    self.symbols.get(index),
+                ) {
+                    (true, Some(c)) => Ok(*c),
+                    _ => Err(EncodingError::UnknownBlock(block.to_vec())),
+                }
+            })
+            .collect()
+    }
+
+    /// The head position on the reduced tape for a head position on the original tape
+    pub fn encode_head(&self, head: usize) -> usize {
+        head * self.width
+    }
+
+    /// The head position on the original tape for a head position on the reduced tape
+    pub fn decode_head(&self, head: usize) -> usize {
+        head / self.width
+    }
+}
+
+struct Reducer<'a> {
+    repr: &'a Repr,
+    encoding: BlockEncoding,
+    parser: MachineParser,
+    separator: String,
+    states: HashSet<String>,
+}
+
+impl<'a> Reducer<'a> {
+    fn add_state(&mut self, name: &str) {
+        if self.states.insert(name.to_string()) {
+            self.parser
+                .add_state(name.to_string(), State::Neutral)
+                .expect("Generated state names are unique");
+        }
+    }
+
+    fn add_transition(&mut self, from: &str, read: char, to: &str, write: char, motion: Motion) {
+        self.parser.get_transition_builder().add_transition(
+            from.to_string(),
+            read,
+            Action::new(to.to_string(), write, motion),
+        );
+    }
+
+    /// The state moving `remaining` more cells in the direction of `motion` before entering `target`
+    fn goto(&mut self, target: &str, motion: Motion, remaining: usize) -> String {
+        let direction = match motion {
+            Motion::Left => 'L',
+            Motion::Right => 'R',
+            Motion::Stay => return target.to_string(),
+        };
+        if remaining == 0 {
+            return target.to_string();
+        }
+
+        let name = format!("{}{}{}{}", target, self.separator, direction, remaining);
+        if !self.states.contains(&name) {
+            self.add_state(&name);
+            let next = self.goto(target, motion, remaining - 1);
+            for bit in &[ZERO, ONE] {
+                self.add_transition(&name, *bit, &next, *bit, motion);
+            }
+        }
+        name
+    }
+
+    /// Write the bits of `code` from `from` down to the first, then move a whole block
+    fn write_back(
+        &mut self,
+        state: &str,
+        code: &[char],
+        from: usize,
+        act: &Action<String>,
+    ) -> (String, char, Motion) {
+        if from == 0 {
+            let next = self.goto(act.next_state(), *act.motion(), self.encoding.width - 1);
+            return (next, code[0], *act.motion());
+        }
+
+        let name = format!("{}{}w{}", state, self.separator, from - 1);
+        if !self.states.contains(&name) {
+            self.add_state(&name);
+            let (next, write, motion) = self.write_back(state, code, from - 1, act);
+            for bit in &[ZERO, ONE] {
+                self.add_transition(&name, *bit, &next, write, motion);
+            }
+        }
+        (name, code[from], Motion::Left)
+    }
+
+    /// Add the states reading a block in `state`, given the bits read so far
+    fn read(&mut self, state: &String, read: &str) {
+        let width = self.encoding.width;
+        let name = if read.is_empty() {
+            state.clone()
+        } else {
+            format!("{}{}r{}", state, self.separator, read)
+        };
+
+        for (digit, bit) in &[('0', ZERO), ('1', ONE)] {
+            let bits = format!("{}{}", read, digit);
+            if bits.len() < width {
+                let next = format!("{}{}r{}", state, self.separator, bits);
+                self.add_state(&next);
+                self.add_transition(&name, *bit, &next, *bit, Motion::Right);
+                self.read(state, &bits);
+                continue;
+            }
+
+            let symbol = match self
+                .encoding
+                .symbols
+                .get(usize::from_str_radix(&bits, 2).unwrap())
+            {
+                Some(symbol) => *symbol,
+                None => continue,
+            };
+            if let Some(act) = self
+                .repr
+                .transition_table()
+                .apply_transition_table(state, symbol)
+            {
+                let code = self.encoding.code(*act.tape_output()).unwrap();
+                // The write states are specific to the symbol read
+                let writer = format!("{}{}{}", state, self.separator, bits);
+                let (next, write, motion) = self.write_back(&writer, &code, width - 1, &act);
+                self.add_transition(&name, *bit, &next, write, motion);
+            }
+        }
+    }
+}
+
+/// Reduce the machine to the alphabet `{_, 1}`, returning the encoding its tapes use.
+/// The original states keep their names, and each step of the original machine takes about three blocks worth of steps
+pub fn to_binary(repr: &Repr) -> (Repr, BlockEncoding) {
+    let mut states: Vec<&String> = repr.states().iter().collect();
+    states.sort();
+
+    // Generated names join an original name and a suffix, with a separator no original name contains
+    let mut separator = "#".to_string();
+    while states.iter().any(|s| s.contains(&separator)) {
+        separator.push('#');
+    }
+
+    let mut reducer = Reducer {
+        repr,
+        encoding: BlockEncoding::new(repr.alphabet()),
+        parser: MachineParser::default(),
+        separator,
+        states: HashSet::new(),
+    };
+    for state in &states {
+        match repr.state_kind(state) {
+            State::Neutral => reducer.add_state(state),
+            kind => {
+                reducer.states.insert(state.to_string());
+                reducer
+                    .parser
+                    .add_state(state.to_string(), kind)
+                    .expect("State names are unique");
+            }
+        }
+    }
+    for state in states {
+        if repr.state_kind(state) == State::Neutral {
+            reducer.read(state, "");
+        }
+    }
+
+    let mut parser = reducer.parser;
+    parser
+        .add_starting_state(repr.starting_state().clone())
+        .expect("The starting state is only set once");
+    for c in &[ZERO, ONE] {
+        parser
+            .add_alphabet_symbol(*c)
+            .expect("The alphabet has no duplicates");
+    }
+
+    let reduced = Repr::from_builder(&parser).expect("A reduced machine is a valid machine");
+    (reduced, reducer.encoding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder, deterministic_tm::DeterministicTuringMachine,
+        limited::LimitedTuringMachineExt, machine_parser, TuringMachine,
+    };
+
+    fn load(machine: &str) -> Repr {
+        Repr::from_builder(&machine_parser::parse(machine.as_bytes()).unwrap()).unwrap()
+    }
+
+    /// Run the machine, returning whether it accepted and the tape without trailing blanks
+    fn run(repr: &Repr, tape: Vec<char>) -> (bool, Vec<char>) {
+        let machine = DeterministicTuringMachine::<String>::from_builder(
+            TuringMachineBuilder::new().repr(repr.clone()).tape(tape),
+        )
+        .unwrap();
+        let exe =
+            LimitedTuringMachineExt::new_with_limit(machine, 1_000_000).execute_and_get_result();
+        let mut tape = exe.tape;
+        while tape.last() == Some(&'_') {
+            tape.pop();
+        }
+        (exe.accepting, tape)
+    }
+
+    /// Compare the reduced machine with the original on pseudo random inputs, from a fixed xorshift seed
+    fn assert_equivalent(original: &Repr, inputs: &[char]) {
+        let (reduced, encoding) = to_binary(original);
+        assert_eq!(reduced.alphabet().len(), 2);
+
+        let mut seed: u64 = 0xda94_2042_e4dd_58b5;
+        for _ in 0..100 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let len = (seed % 10) as usize;
+            let tape: Vec<char> = (0..len)
+                .map(|i| inputs[((seed >> (i * 3)) % inputs.len() as u64) as usize])
+                .collect();
+
+            let (accepting, output) = run(original, tape.clone());
+            let (reduced_accepting, reduced_output) =
+                run(&reduced, encoding.encode_tape(&tape).unwrap());
+            let mut decoded = encoding.decode_tape(&reduced_output).unwrap();
+            while decoded.last() == Some(&'_') {
+                decoded.pop();
+            }
+            assert_eq!(
+                reduced_accepting, accepting,
+                "Verdicts differ on {:?}",
+                tape
+            );
+            assert_eq!(decoded, output, "Tapes differ on {:?}", tape);
+        }
+    }
+
+    #[test]
+    fn encodes_blocks() {
+        let alphabet: HashSet<char> = "_abc".chars().collect();
+        let encoding = BlockEncoding::new(&alphabet);
+        assert_eq!(encoding.width(), 2);
+        assert_eq!(encoding.code('_'), Ok(vec!['_', '_']));
+        assert_eq!(encoding.code('c'), Ok(vec!['1', '1']));
+        assert_eq!(encoding.code('d'), Err(EncodingError::UnknownSymbol('d')));
+
+        let tape = encoding.encode_tape(&['c', 'a', '_', 'b']).unwrap();
+        assert_eq!(
+            encoding.decode_tape(&tape).unwrap(),
+            vec!['c', 'a', '_', 'b']
+        );
+        assert_eq!(encoding.decode_tape(&['1']).unwrap(), vec!['b']);
+        assert_eq!(
+            encoding.decode_tape(&['x', '1']),
+            Err(EncodingError::UnknownBlock(vec!['x', '1']))
+        );
+
+        let alphabet: HashSet<char> = "_a".chars().collect();
+        assert_eq!(BlockEncoding::new(&alphabet).width(), 1);
+    }
+
+    #[test]
+    fn reduces_paren() {
+        assert_equivalent(&load(include_str!("../data/paren.tm")), &['(', ')']);
+    }
+
+    #[test]
+    fn reduces_binadd() {
+        assert_equivalent(&load(include_str!("../data/binadd.tm")), &['0', '1', '#']);
+    }
+
+    #[test]
+    fn keeps_separators_unique() {
+        let original =
+            load("states 3\nq#0\nqa +\nqr -\nalphabet 3 a b c\nq#0 a q#0 c R\nq#0 _ qa _ L");
+        let (reduced, _) = to_binary(&original);
+        assert!(reduced.states().iter().any(|s| s.starts_with("q#0##")));
+        assert_equivalent(&original, &['a', 'b', 'c']);
+    }
+}
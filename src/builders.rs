@@ -1,186 +1,247 @@
-use hashbrown::HashSet;
-use std::io::{self, BufRead, BufReader, Read};
-
-use crate::{
-    common::{Action, State, StateTrait},
-    machine_representation::MachineRepresentation,
-};
-
-// TODO, would it generally be worth to replace at least some of these with structs? Or do we need the full generality of the problem?
-
-/// A trait to generally parse a transition table
-pub trait TransitionTableBuilder<StateTy>
-where
-    StateTy: StateTrait,
-{
-    type InputTy;
-    type ErrorTy: From<io::Error>;
-
-    fn parse_line(&mut self, line: &str) -> Result<(), Self::ErrorTy>;
-    fn build_from_lines(
-        &mut self,
-        lines: impl Iterator<Item = String>,
-    ) -> Result<(), Self::ErrorTy> {
-        for line in lines {
-            self.parse_line(&line)?;
-        }
-        Ok(())
-    }
-
-    fn build_from_reader(&mut self, reader: impl Read) -> Result<(), Self::ErrorTy> {
-        let r = BufReader::new(reader);
-        self.build_from_lines(r.lines().filter_map(|r| r.ok()))
-    }
-
-    fn states(&self) -> HashSet<StateTy>;
-    fn alphabet(&self) -> HashSet<Self::InputTy>;
-
-    /// Given a current state, what is the transitions that we can take?
-    fn get_state_transitions(&self, state: &StateTy) -> Vec<(Self::InputTy, Action<StateTy>)>;
-}
-
-pub trait MachineRepresentationBuilder<StateTy>
-where
-    StateTy: StateTrait,
-{
-    type TableBuilder: TransitionTableBuilder<StateTy>;
-    type ErrorTy;
-
-    fn add_state(&mut self, state: StateTy, value: State) -> Result<(), Self::ErrorTy>;
-    fn add_starting_state(&mut self, state: StateTy) -> Result<(), Self::ErrorTy>;
-    fn add_alphabet_symbol(&mut self, symbol: char) -> Result<(), Self::ErrorTy>;
-    fn get_transition_builder(&mut self) -> &mut Self::TableBuilder;
-
-    /// Get the list of states in the representation
-    fn states(&self) -> &HashSet<StateTy>;
-
-    /// Get the starting state
-    fn starting_state(&self) -> &Option<StateTy>;
-
-    fn accepting_state(&self) -> &Option<StateTy>;
-    fn rejecting_state(&self) -> &Option<StateTy>;
-
-    /// Get the alphabet
-    fn alphabet(
-        &self,
-    ) -> &HashSet<<Self::TableBuilder as TransitionTableBuilder<StateTy>>::InputTy>;
-
-    /// Get the transition table
-    fn transition_table_builder(&self) -> &Self::TableBuilder;
-}
-
-/// A builder struct for a [`TuringMachine`](trait.TuringMachine.html)  
-#[derive(Debug, Default)]
-pub struct TuringMachineBuilder<StateTy, ReprTy>
-where
-    StateTy: StateTrait,
-    ReprTy: MachineRepresentation<StateTy>,
-{
-    tape: Vec<ReprTy::InputTy>,
-    repr: Option<ReprTy>,
-}
-
-impl<StateTy, ReprTy> TuringMachineBuilder<StateTy, ReprTy>
-where
-    StateTy: StateTrait,
-    ReprTy: MachineRepresentation<StateTy>,
-{
-    /// Create a empty `TuringMachineBuilder`.  
-    /// This uses a empty starting tape, a machine with only two states, the accepting and rejecting state.  
-    /// Furthermore the transition table will be empty, so reading every input (including the empty word) will reject.
-    pub fn new() -> Self {
-        TuringMachineBuilder {
-            tape: Vec::new(),
-            repr: None,
-        }
-    }
-
-    /// Sets the tape of the builder.  
-    pub fn tape(mut self, tape: Vec<ReprTy::InputTy>) -> Self {
-        self.tape = tape;
-        self
-    }
-
-    /// Sets the representation of the machine
-    pub fn repr(mut self, repr: ReprTy) -> Self {
-        self.repr = Some(repr);
-        self
-    }
-
-    /// Convert the builder in a format easy to consume
-    pub fn decompose(self) -> (Vec<ReprTy::InputTy>, ReprTy) {
-        (self.tape, self.repr.unwrap())
-    }
-
-    /// Validates that the representation and the tape are consistent.  
-    /// This should be called on any conversion to avoid inconsistencies  
-    /// Return `Some` if the builder is valid, `None` otherwise
-    pub fn validate(self) -> Option<Self> {
-        // If we haven't set this, it should return none
-
-        if self.repr.is_none()
-            || self
-                .tape
-                .iter()
-                .any(|tape_elem| !self.repr.as_ref().unwrap().alphabet().contains(tape_elem))
-        {
-            None
-        } else {
-            Some(self)
-        }
-    }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::mocking::MockRepr;
-    use std::iter::FromIterator;
-
-    fn make_mock_repr<T>(v: Vec<char>) -> MockRepr<T>
-    where
-        T: StateTrait,
-    {
-        MockRepr {
-            p: Default::default(),
-            alphabet: HashSet::from_iter(v),
-        }
-    }
-
-    #[test]
-    fn check_initialization() {
-        let builder = TuringMachineBuilder::<String, ()>::new();
-        assert!(builder.validate().is_none());
-    }
-
-    #[test]
-    #[should_panic]
-    fn check_panic_on_repr_non_set() {
-        let builder = TuringMachineBuilder::<usize, ()>::new();
-        builder.decompose();
-    }
-
-    #[test]
-    fn check_matching_alphabet_works() {
-        let t_vec = vec!['_', 'a', 'b', 'b', '_', 'a'];
-        let alpha_vec = vec!['_', 'a', 'b'];
-        let builder = TuringMachineBuilder::new()
-            .tape(t_vec.clone())
-            .repr(make_mock_repr::<String>(alpha_vec.clone()))
-            .validate()
-            .unwrap();
-
-        let (tape, repr) = builder.decompose();
-        assert_eq!(t_vec, tape);
-        assert_eq!(*repr.alphabet(), HashSet::from_iter(alpha_vec));
-    }
-
-    #[test]
-    fn check_non_matching_alphabet_does_not_work() {
-        let builder = TuringMachineBuilder::new()
-            .tape(vec!['_', 'a', '1', 'b', '_', 'a'])
-            .repr(make_mock_repr::<usize>(vec!['_', 'a', 'b']))
-            .validate();
-        assert!(builder.is_none());
-    }
-}
+use hashbrown::HashSet;
+use std::{
+    fmt::{self, Display},
+    io::{self, BufRead, BufReader, Read},
+};
+
+use crate::{
+    common::{Action, State, StateTrait},
+    machine_representation::MachineRepresentation,
+};
+
+// TODO, would it generally be worth to replace at least some of these with structs? Or do we need the full generality of the problem?
+
+/// A trait to generally parse a transition table
+pub trait TransitionTableBuilder<StateTy>
+where
+    StateTy: StateTrait,
+{
+    type InputTy;
+    type ErrorTy: From<io::Error>;
+
+    fn parse_line(&mut self, line: &str) -> Result<(), Self::ErrorTy>;
+    fn build_from_lines(
+        &mut self,
+        lines: impl Iterator<Item = String>,
+    ) -> Result<(), Self::ErrorTy> {
+        for line in lines {
+            self.parse_line(&line)?;
+        }
+        Ok(())
+    }
+
+    #[allow(clippy::lines_filter_map_ok)]
+    fn build_from_reader(&mut self, reader: impl Read) -> Result<(), Self::ErrorTy> {
+        let r = BufReader::new(reader);
+        self.build_from_lines(r.lines().filter_map(|r| r.ok()))
+    }
+
+    fn states(&self) -> HashSet<StateTy>;
+    fn alphabet(&self) -> HashSet<Self::InputTy>;
+
+    /// Given a current state, what is the transitions that we can take?
+    fn get_state_transitions(&self, state: &StateTy) -> Vec<(Self::InputTy, Action<StateTy>)>;
+}
+
+pub trait MachineRepresentationBuilder<StateTy>
+where
+    StateTy: StateTrait,
+{
+    type TableBuilder: TransitionTableBuilder<StateTy>;
+    type ErrorTy;
+
+    fn add_state(&mut self, state: StateTy, value: State) -> Result<(), Self::ErrorTy>;
+    fn add_starting_state(&mut self, state: StateTy) -> Result<(), Self::ErrorTy>;
+    fn add_alphabet_symbol(&mut self, symbol: char) -> Result<(), Self::ErrorTy>;
+    fn get_transition_builder(&mut self) -> &mut Self::TableBuilder;
+
+    /// Get the list of states in the representation
+    fn states(&self) -> &HashSet<StateTy>;
+
+    /// Get the starting state
+    fn starting_state(&self) -> &Option<StateTy>;
+
+    /// Get the accepting states
+    fn accepting_states(&self) -> &HashSet<StateTy>;
+
+    /// Get the rejecting states
+    fn rejecting_states(&self) -> &HashSet<StateTy>;
+
+    /// Get the rejecting state missing transitions move to, the first one added
+    fn implicit_reject(&self) -> Option<&StateTy>;
+
+    /// Get the plain halting states
+    fn halting_states(&self) -> &HashSet<StateTy>;
+
+    /// Get the alphabet
+    fn alphabet(
+        &self,
+    ) -> &HashSet<<Self::TableBuilder as TransitionTableBuilder<StateTy>>::InputTy>;
+
+    /// Get the transition table
+    fn transition_table_builder(&self) -> &Self::TableBuilder;
+}
+
+/// A builder struct for a [`TuringMachine`](trait.TuringMachine.html)  
+#[derive(Debug, Default)]
+pub struct TuringMachineBuilder<StateTy, ReprTy>
+where
+    StateTy: StateTrait,
+    ReprTy: MachineRepresentation<StateTy>,
+{
+    tape: Vec<ReprTy::InputTy>,
+    repr: Option<ReprTy>,
+}
+
+/// The reasons a [`TuringMachineBuilder`](struct.TuringMachineBuilder.html) can be invalid
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValidationError<InputTy> {
+    /// No representation has been set
+    MissingRepr,
+
+    /// Some tape symbols are not in the alphabet, stored along with the index of their cell on the tape.
+    /// These are indices among the symbols, so they skip anything dropped before building, as the whitespace of tape files
+    TapeAlphabetMismatch(Vec<(usize, InputTy)>),
+}
+
+impl<InputTy> Display for ValidationError<InputTy>
+where
+    InputTy: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        const SHOWN: usize = 10;
+        match self {
+            ValidationError::MissingRepr => write!(f, "no machine representation was given"),
+            ValidationError::TapeAlphabetMismatch(symbols) => {
+                let shown: Vec<String> = symbols
+                    .iter()
+                    .take(SHOWN)
+                    .map(|(cell, symbol)| format!("'{}' in cell {}", symbol, cell))
+                    .collect();
+                write!(
+                    f,
+                    "the tape contains symbols not in the alphabet: {}",
+                    shown.join(", ")
+                )?;
+                if symbols.len() > SHOWN {
+                    write!(f, " and {} more", symbols.len() - SHOWN)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl<StateTy, ReprTy> TuringMachineBuilder<StateTy, ReprTy>
+where
+    StateTy: StateTrait,
+    ReprTy: MachineRepresentation<StateTy>,
+{
+    /// Create a empty `TuringMachineBuilder`.  
+    /// This uses a empty starting tape, a machine with only two states, the accepting and rejecting state.  
+    /// Furthermore the transition table will be empty, so reading every input (including the empty word) will reject.
+    pub fn new() -> Self {
+        TuringMachineBuilder {
+            tape: Vec::new(),
+            repr: None,
+        }
+    }
+
+    /// Sets the tape of the builder.  
+    pub fn tape(mut self, tape: Vec<ReprTy::InputTy>) -> Self {
+        self.tape = tape;
+        self
+    }
+
+    /// Sets the representation of the machine
+    pub fn repr(mut self, repr: ReprTy) -> Self {
+        self.repr = Some(repr);
+        self
+    }
+
+    /// Convert the builder in a format easy to consume
+    pub fn decompose(self) -> (Vec<ReprTy::InputTy>, ReprTy) {
+        (self.tape, self.repr.unwrap())
+    }
+
+    /// Validates that the representation and the tape are consistent.  
+    /// This should be called on any conversion to avoid inconsistencies  
+    /// Return `Ok` if the builder is valid, otherwise the [`ValidationError`](enum.ValidationError.html) describing why not
+    pub fn validate(self) -> Result<Self, ValidationError<ReprTy::InputTy>>
+    where
+        ReprTy::InputTy: Clone,
+    {
+        let repr = self.repr.as_ref().ok_or(ValidationError::MissingRepr)?;
+
+        let mismatches: Vec<_> = self
+            .tape
+            .iter()
+            .enumerate()
+            .filter(|(_, tape_elem)| !repr.alphabet().contains(tape_elem))
+            .map(|(i, tape_elem)| (i, tape_elem.clone()))
+            .collect();
+        if !mismatches.is_empty() {
+            return Err(ValidationError::TapeAlphabetMismatch(mismatches));
+        }
+        Ok(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocking::MockRepr;
+    use std::iter::FromIterator;
+
+    fn make_mock_repr<T>(v: Vec<char>) -> MockRepr<T>
+    where
+        T: StateTrait,
+    {
+        MockRepr {
+            p: Default::default(),
+            alphabet: HashSet::from_iter(v),
+        }
+    }
+
+    #[test]
+    fn check_initialization() {
+        let builder = TuringMachineBuilder::<String, ()>::new();
+        assert_eq!(builder.validate().err(), Some(ValidationError::MissingRepr));
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_panic_on_repr_non_set() {
+        let builder = TuringMachineBuilder::<usize, ()>::new();
+        builder.decompose();
+    }
+
+    #[test]
+    fn check_matching_alphabet_works() {
+        let t_vec = vec!['_', 'a', 'b', 'b', '_', 'a'];
+        let alpha_vec = vec!['_', 'a', 'b'];
+        let builder = TuringMachineBuilder::new()
+            .tape(t_vec.clone())
+            .repr(make_mock_repr::<String>(alpha_vec.clone()))
+            .validate()
+            .unwrap();
+
+        let (tape, repr) = builder.decompose();
+        assert_eq!(t_vec, tape);
+        assert_eq!(*repr.alphabet(), HashSet::from_iter(alpha_vec));
+    }
+
+    #[test]
+    fn check_non_matching_alphabet_does_not_work() {
+        let builder = TuringMachineBuilder::new()
+            .tape(vec!['_', 'a', '1', 'b', '_', 'a'])
+            .repr(make_mock_repr::<usize>(vec!['_', 'a', 'b']))
+            .validate();
+        let err = builder.err().unwrap();
+        assert_eq!(err, ValidationError::TapeAlphabetMismatch(vec![(2, '1')]));
+        assert_eq!(
+            err.to_string(),
+            "the tape contains symbols not in the alphabet: '1' in cell 2"
+        );
+    }
+}
@@ -0,0 +1,363 @@
+//! The standard notation for busy beaver candidates, and running them as the busy beaver literature does.
+//!
+//! A machine with states `A`, `B`, ... over the symbols `0`, `1`, ... is written one state after another, separated by
+//! `_`. Each state lists what it does on every symbol in order, as the symbol written, the direction and the next state,
+//! or `---` when the transition is undefined. The halting state is `Z`. The two state champion is `1RB1LB_1LA1RZ`.
+//!
+//! Symbol `0` is the blank `_`, and the others are the digits themselves. `Z` becomes an accepting state, and the
+//! undefined transitions are left to the implicit reject of
+//! [`DeterministicTuringMachine`](../deterministic_tm/struct.DeterministicTuringMachine.html).
+//!
+//! Published step counts are for a tape infinite both ways, while the tape of this crate stops on the left, so
+//! [`run`](fn.run.html) adds blank cells on the left whenever the head gets to the first cell.
+
+use std::{fmt, iter};
+
+use crate::{
+    builders::{MachineRepresentationBuilder, TuringMachineBuilder},
+    common::{Action, Motion, State},
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    machine_parser::MachineParser,
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+    TuringMachine,
+};
+
+type Repr = DeterministicMachineRepresentation<String>;
+
+/// The name of the halting state, accepting once converted
+pub const HALT: &str = "Z";
+
+/// The name of the rejecting state undefined transitions go to once converted
+pub const REJECT: &str = "reject";
+
+/// The names of the states in order, leaving out the halting one
+pub(crate) const STATES: &str = "ABCDEFGHIJKLMNOPQRSTUVWXY";
+
+/// The symbols in order, the first being the blank
+pub(crate) const SYMBOLS: &str = "0123456789";
+
+/// The fewest cells added at a time on the left of the tape
+const MARGIN: usize = 64;
+
+/// The ways a machine can fail to convert from or to the notation
+#[derive(Debug, PartialEq, Eq)]
+pub enum NotationError {
+    /// There are no states
+    Empty,
+
+    /// A transition is neither three characters like `1RB` nor `---`
+    InvalidTransition(String),
+
+    /// The states do not all have a transition for each of the same symbols
+    UnevenStates,
+
+    /// A transition goes to a state past the last one, which is not `Z` either
+    UnknownState(char),
+
+    /// A transition writes a symbol past the last one
+    UnknownSymbol(char),
+
+    /// There are more states or symbols than the notation has letters or digits for
+    TooLarge,
+
+    /// A transition leaves the head where it is, which the notation cannot express
+    Stay(String, char),
+
+    /// The starting state is final, so it has no transitions to write
+    FinalStart,
+}
+
+impl fmt::Display for NotationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            NotationError::Empty => write!(f, "the machine has no states"),
+            NotationError::InvalidTransition(t) => write!(f, "invalid transition {}", t),
+            NotationError::UnevenStates => {
+                write!(f, "the states have transitions for different symbols")
+            }
+            NotationError::UnknownState(s) => write!(f, "unknown state {}", s),
+            NotationError::UnknownSymbol(s) => write!(f, "unknown symbol {}", s),
+            NotationError::TooLarge => write!(f, "too many states or symbols for the notation"),
+            NotationError::Stay(state, c) => {
+                write!(f, "the transition of {} on {} does not move", state, c)
+            }
+            NotationError::FinalStart => write!(f, "the starting state is final"),
+        }
+    }
+}
+
+/// Convert a machine from the standard notation
+pub fn parse(notation: &str) -> Result<Repr, NotationError> {
+    let notation = notation.trim();
+    if notation.is_empty() {
+        return Err(NotationError::Empty);
+    }
+    let rows: Vec<Vec<char>> = notation.split('_').map(|r| r.chars().collect()).collect();
+    let num_symbols = rows[0].len() / 3;
+    if rows.iter().any(|r| r.len() != num_symbols * 3) || num_symbols == 0 {
+        return Err(NotationError::UnevenStates);
+    }
+    if rows.len() > STATES.len() || num_symbols > SYMBOLS.len() {
+        return Err(NotationError::TooLarge);
+    }
+
+    let state = |i: usize| STATES[i..=i].to_string();
+    let symbol = |i: usize| {
+        if i == 0 {
+            '_'
+        } else {
+            SYMBOLS[i..].chars().next().unwrap()
+        }
+    };
+
+    let mut parser = MachineParser::default();
+    for (i, row) in rows.iter().enumerate() {
+        for (c, t) in row.chunks(3).enumerate() {
+            if t == ['-', '-', '-'] {
+                continue;
+            }
+            let invalid = || NotationError::InvalidTransition(t.iter().collect());
+            let write = SYMBOLS.find(t[0]).ok_or_else(invalid)?;
+            if write >= num_symbols {
+                return Err(NotationError::UnknownSymbol(t[0]));
+            }
+            let motion = match t[1] {
+                'L' => Motion::Left,
+                'R' => Motion::Right,
+                _ => return Err(invalid()),
+            };
+            let next = match STATES.find(t[2]) {
+                Some(next) if next < rows.len() => state(next),
+                _ if t[2] == 'Z' => HALT.to_string(),
+                _ if t[2].is_ascii_uppercase() => return Err(NotationError::UnknownState(t[2])),
+                _ => return Err(invalid()),
+            };
+            parser.get_transition_builder().add_transition(
+                state(i),
+                symbol(c),
+                Action::new(next, symbol(write), motion),
+            );
+        }
+        parser
+            .add_state(state(i), State::Neutral)
+            .expect("State names are unique");
+    }
+    parser
+        .add_state(HALT.to_string(), State::Accepting)
+        .expect("State names are unique");
+    parser
+        .add_state(REJECT.to_string(), State::Rejecting)
+        .expect("State names are unique");
+    parser
+        .add_starting_state(state(0))
+        .expect("The starting state is only set once");
+    for c in 0..num_symbols {
+        parser
+            .add_alphabet_symbol(symbol(c))
+            .expect("The alphabet has no duplicates");
+    }
+
+    Ok(Repr::from_builder(&parser).expect("The notation describes a valid machine"))
+}
+
+/// Convert a machine to the standard notation.
+/// The starting state becomes `A`, and the other states which are not final follow in name order.
+/// The blank becomes `0`, and the other symbols follow in order.
+/// Accepting and halting states become `Z`, and transitions to rejecting states are undefined.
+pub fn write(repr: &Repr) -> Result<String, NotationError> {
+    if repr.state_kind(repr.starting_state()).is_final() {
+        return Err(NotationError::FinalStart);
+    }
+    let mut states: Vec<&String> = repr
+        .states()
+        .iter()
+        .filter(|s| *s != repr.starting_state() && !repr.state_kind(s).is_final())
+        .collect();
+    states.sort();
+    states.insert(0, repr.starting_state());
+
+    let mut alphabet: Vec<char> = repr
+        .alphabet()
+        .iter()
+        .cloned()
+        .filter(|c| *c != '_')
+        .collect();
+    alphabet.sort();
+    alphabet.insert(0, '_');
+    if states.len() > STATES.len() || alphabet.len() > SYMBOLS.len() {
+        return Err(NotationError::TooLarge);
+    }
+
+    let letter = |state: &String| match repr.state_kind(state) {
+        State::Accepting | State::Halting => Some('Z'),
+        State::Rejecting => None,
+        State::Neutral => states
+            .iter()
+            .position(|s| *s == state)
+            .map(|i| STATES[i..].chars().next().unwrap()),
+    };
+    let digit = |c: char| {
+        let i = alphabet.iter().position(|a| *a == c).unwrap();
+        SYMBOLS[i..].chars().next().unwrap()
+    };
+
+    let mut rows = Vec::with_capacity(states.len());
+    for state in &states {
+        let mut row = String::with_capacity(alphabet.len() * 3);
+        for c in &alphabet {
+            match repr.transition_table().apply_transition_table(state, *c) {
+                Some(act) => match letter(act.next_state()) {
+                    Some(next) => {
+                        let motion = match act.motion() {
+                            Motion::Left => 'L',
+                            Motion::Right => 'R',
+                            Motion::Stay => return Err(NotationError::Stay(state.to_string(), *c)),
+                        };
+                        row.push(digit(*act.tape_output()));
+                        row.push(motion);
+                        row.push(next);
+                    }
+                    None => row.push_str("---"),
+                },
+                None => row.push_str("---"),
+            }
+        }
+        rows.push(row);
+    }
+    Ok(rows.join("_"))
+}
+
+/// How a machine did when started on a blank tape
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Score {
+    /// How many steps it took, counting the one into the halting state
+    pub steps: usize,
+
+    /// How many cells hold something other than a blank at the end, the ones of two symbol machines
+    pub ones: usize,
+
+    /// Did it stop in the halting state, rather than on an undefined transition?
+    pub accepting: bool,
+}
+
+/// Run a machine on a blank tape infinite both ways, as in the busy beaver literature, for at most `limit` steps.
+/// Returns `None` if the machine is still running after them.
+pub fn run(repr: &Repr, limit: usize) -> Option<Score> {
+    let mut tm = DeterministicTuringMachine::<String>::from_builder(
+        TuringMachineBuilder::new()
+            .repr(repr.clone())
+            .tape(Vec::new()),
+    )
+    .expect("A blank tape is always valid");
+
+    let mut steps = 0;
+    while !tm.is_finished() {
+        if steps >= limit {
+            return None;
+        }
+        make_room(&mut tm);
+        tm.step();
+        steps += 1;
+    }
+    Some(score(&tm, steps))
+}
+
+/// Add blank cells before the first one when the head is on it, so that the tape behaves as if it was infinite both ways.
+/// Returns how many cells were added.
+pub(crate) fn make_room(tm: &mut DeterministicTuringMachine<String>) -> usize {
+    if tm.head() > 0 {
+        return 0;
+    }
+    let (tape, head, _) = tm.configuration_mut();
+    let room = tape.len().max(MARGIN);
+    tape.splice(0..0, iter::repeat_n('_', room));
+    *head += room;
+    room
+}
+
+pub(crate) fn score(tm: &DeterministicTuringMachine<String>, steps: usize) -> Score {
+    Score {
+        steps,
+        ones: tm.tape().iter().filter(|c| **c != '_').count(),
+        accepting: tm.is_accepting(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Champions, with the steps they take and the ones they leave
+    const CHAMPIONS: [(&str, usize, usize); 5] = [
+        ("1RB1LB_1LA1RZ", 6, 4),
+        ("1RB1RZ_1LB0RC_1LC1LA", 21, 5),
+        ("1RB1LB_1LA0LC_1RZ1LD_1RD0RA", 107, 13),
+        ("1RB2LB1RZ_2LA2RB1LB", 38, 9),
+        ("1RZ---", 1, 1),
+    ];
+
+    #[test]
+    fn champions_score_as_published() {
+        for (notation, steps, ones) in CHAMPIONS.iter() {
+            let repr = parse(notation).unwrap();
+            let score = run(&repr, 1000).unwrap();
+            assert_eq!(score.steps, *steps, "{}", notation);
+            assert_eq!(score.ones, *ones, "{}", notation);
+            assert!(score.accepting);
+
+            assert_eq!(run(&repr, steps - 1), None);
+        }
+    }
+
+    #[test]
+    fn converts_both_ways() {
+        for (notation, ..) in CHAMPIONS.iter() {
+            assert_eq!(write(&parse(notation).unwrap()).unwrap(), *notation);
+        }
+        let undefined = "1RB---_1LA1RZ";
+        assert_eq!(write(&parse(undefined).unwrap()).unwrap(), undefined);
+
+        // Undefined transitions reject
+        let score = run(&parse("1RB---_---1RZ").unwrap(), 100).unwrap();
+        assert_eq!(score.steps, 2);
+        assert!(!score.accepting);
+    }
+
+    #[test]
+    fn rejects_malformed_notation() {
+        assert_eq!(parse("").unwrap_err(), NotationError::Empty);
+        assert_eq!(
+            parse("1RB1LB_1LA").unwrap_err(),
+            NotationError::UnevenStates
+        );
+        assert_eq!(
+            parse("1XB1LB_1LA1RZ").unwrap_err(),
+            NotationError::InvalidTransition("1XB".to_string())
+        );
+        assert_eq!(
+            parse("1RC1LB_1LA1RZ").unwrap_err(),
+            NotationError::UnknownState('C')
+        );
+        assert_eq!(
+            parse("2RB1LB_1LA1RZ").unwrap_err(),
+            NotationError::UnknownSymbol('2')
+        );
+    }
+
+    #[test]
+    fn writes_machines_from_files() {
+        let source = "states 4\nstart\nhalt !\nreject -\nflip\nalphabet 2 a b\nstart _ flip a R\nflip _ start b L\nstart b halt a R\n";
+        let repr =
+            Repr::from_builder(&crate::machine_parser::parse(source.as_bytes()).unwrap()).unwrap();
+        assert_eq!(write(&repr).unwrap(), "1RB---1RZ_2LA------");
+
+        let source = "states 3\nstart\nhalt !\nreject -\nalphabet 1 a\nstart _ halt a S\n";
+        let repr =
+            Repr::from_builder(&crate::machine_parser::parse(source.as_bytes()).unwrap()).unwrap();
+        assert_eq!(
+            write(&repr).unwrap_err(),
+            NotationError::Stay("start".to_string(), '_')
+        );
+    }
+}
@@ -1,85 +1,113 @@
-use std::{fmt::Debug, hash::Hash};
-
-pub mod representation;
-
-pub trait StateTrait: Debug + Clone + Default + Eq + Hash {}
-impl<T> StateTrait for T where T: Debug + Clone + Default + Eq + Hash {}
-
-/// The set of movements that a [`TuringMachine`](../trait.TuringMachine.html) can take on a single transition
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Motion {
-    Left,
-    Right,
-    Stay,
-}
-
-impl Default for Motion {
-    fn default() -> Self {
-        Motion::Stay
-    }
-}
-
-/// The various characteristics a state of a [`TuringMachine`](../trait.TuringMachine.html) can have
-#[derive(Debug, Clone, PartialEq)]
-pub enum State {
-    Accepting,
-    Neutral,
-    Rejecting,
-}
-
-impl State {
-    /// Is the state an accepting state?
-    pub fn is_accepting(&self) -> bool {
-        match self {
-            State::Accepting => true,
-            _ => false,
-        }
-    }
-
-    /// Is the state a rejecting state?
-    pub fn is_rejecting(&self) -> bool {
-        match self {
-            State::Rejecting => true,
-            _ => false,
-        }
-    }
-}
-
-/// Encapsulate the possible actions that can be done on the tape on a single step
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Action<T>
-where
-    T: StateTrait,
-{
-    next_state: T,
-    tape_output: char,
-    motion: Motion,
-}
-
-impl<T> Action<T>
-where
-    T: StateTrait,
-{
-    pub fn new(next_state: T, tape_output: char, motion: Motion) -> Self {
-        Action {
-            next_state,
-            tape_output,
-            motion,
-        }
-    }
-
-    /// Get the next state to move to
-    pub fn next_state(&self) -> &T {
-        &self.next_state
-    }
-
-    /// Get the symbol to write to the tape
-    pub fn tape_output(&self) -> &char {
-        &self.tape_output
-    }
-
-    /// Get the direction of motion
-    pub fn motion(&self) -> &Motion {
-        &self.motion
-    }
-}
+use std::{
+    fmt::{self, Debug, Display},
+    hash::Hash,
+};
+
+pub mod representation;
+
+pub trait StateTrait: Debug + Clone + Default + Eq + Hash {}
+impl<T> StateTrait for T where T: Debug + Clone + Default + Eq + Hash {}
+
+/// The set of movements that a [`TuringMachine`](../trait.TuringMachine.html) can take on a single transition
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Motion {
+    Left,
+    Right,
+    Stay,
+}
+
+#[allow(clippy::derivable_impls)]
+impl Default for Motion {
+    fn default() -> Self {
+        Motion::Stay
+    }
+}
+
+impl Display for Motion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Motion::Left => write!(f, "L"),
+            Motion::Right => write!(f, "R"),
+            Motion::Stay => write!(f, "S"),
+        }
+    }
+}
+
+/// The various characteristics a state of a [`TuringMachine`](../trait.TuringMachine.html) can have
+#[derive(Debug, Clone, PartialEq)]
+pub enum State {
+    Accepting,
+    Neutral,
+    Rejecting,
+    /// A state that stops the machine without accepting or rejecting, used by machines computing functions
+    Halting,
+}
+
+impl State {
+    /// Is the state an accepting state?
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_accepting(&self) -> bool {
+        match self {
+            State::Accepting => true,
+            _ => false,
+        }
+    }
+
+    /// Is the state a rejecting state?
+    #[allow(clippy::match_like_matches_macro)]
+    pub fn is_rejecting(&self) -> bool {
+        match self {
+            State::Rejecting => true,
+            _ => false,
+        }
+    }
+
+    /// Is the state a plain halting state?
+    pub fn is_halting(&self) -> bool {
+        matches!(self, State::Halting)
+    }
+
+    /// Does the machine stop when reaching this state?
+    pub fn is_final(&self) -> bool {
+        !matches!(self, State::Neutral)
+    }
+}
+
+/// Encapsulate the possible actions that can be done on the tape on a single step
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Action<T>
+where
+    T: StateTrait,
+{
+    next_state: T,
+    tape_output: char,
+    motion: Motion,
+}
+
+impl<T> Action<T>
+where
+    T: StateTrait,
+{
+    pub fn new(next_state: T, tape_output: char, motion: Motion) -> Self {
+        Action {
+            next_state,
+            tape_output,
+            motion,
+        }
+    }
+
+    /// Get the next state to move to
+    pub fn next_state(&self) -> &T {
+        &self.next_state
+    }
+
+    /// Get the symbol to write to the tape
+    pub fn tape_output(&self) -> &char {
+        &self.tape_output
+    }
+
+    /// Get the direction of motion
+    pub fn motion(&self) -> &Motion {
+        &self.motion
+    }
+}
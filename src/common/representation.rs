@@ -1,131 +1,151 @@
-use hashbrown::HashSet;
-use std::fmt::Debug;
-
-use crate::{
-    builders::{MachineRepresentationBuilder, TransitionTableBuilder},
-    common::StateTrait,
-    machine_representation::MachineRepresentation,
-    transition_table::TransitionTable,
-};
-
-#[derive(Debug)]
-pub struct GeneralMachineRepresentation<StateTy, TableTy>
-where
-    StateTy: StateTrait,
-    TableTy: TransitionTable<StateTy, InputTy = char> + Debug,
-{
-    states: HashSet<StateTy>,
-    starting_state: StateTy,
-    accepting_state: StateTy,
-    rejecting_state: StateTy,
-    alphabet: HashSet<char>,
-    transition_table: TableTy,
-}
-
-#[derive(Debug)]
-pub enum RepresentationCreationError<StateTy, TableTy>
-where
-    StateTy: StateTrait,
-    TableTy: TransitionTable<StateTy, InputTy = char> + Debug,
-{
-    StartingStateNotSpecified,
-    AcceptStateNotSpecified,
-    RejectStateNotSpecified,
-    TransitionTableStateMismatch(HashSet<StateTy>),
-    TransitionTableAlphabetMismatch(HashSet<char>),
-    TableConstructionError(TableTy::ErrorTy),
-}
-
-impl<StateTy, TableTy> MachineRepresentation<StateTy>
-    for GeneralMachineRepresentation<StateTy, TableTy>
-where
-    StateTy: StateTrait,
-    TableTy: TransitionTable<StateTy, InputTy = char> + Debug,
-{
-    type InputTy = char;
-    type OutputTy = TableTy::OutputTy;
-    type TableTy = TableTy;
-    type ErrorTy = RepresentationCreationError<StateTy, TableTy>;
-
-    fn states(&self) -> &HashSet<StateTy> {
-        &self.states
-    }
-
-    fn starting_state(&self) -> &StateTy {
-        &self.starting_state
-    }
-
-    fn accepting_state(&self) -> &StateTy {
-        &self.accepting_state
-    }
-    fn rejecting_state(&self) -> &StateTy {
-        &self.rejecting_state
-    }
-
-    fn alphabet(&self) -> &HashSet<char> {
-        &self.alphabet
-    }
-
-    fn transition_table(&self) -> &Self::TableTy {
-        &self.transition_table
-    }
-
-    fn from_builder<Builder>(b: &Builder) -> Result<Self, Self::ErrorTy>
-    where
-        Builder: MachineRepresentationBuilder<StateTy>,
-        Builder::TableBuilder: TransitionTableBuilder<StateTy, InputTy = char>,
-    {
-        let starting_state = b
-            .starting_state()
-            .as_ref()
-            .cloned()
-            .ok_or(RepresentationCreationError::StartingStateNotSpecified)?;
-        let accepting_state = b
-            .accepting_state()
-            .as_ref()
-            .cloned()
-            .ok_or(RepresentationCreationError::AcceptStateNotSpecified)?;
-        let rejecting_state = b
-            .rejecting_state()
-            .as_ref()
-            .cloned()
-            .ok_or(RepresentationCreationError::RejectStateNotSpecified)?;
-
-        // Validate states
-
-        let state_diff: HashSet<_> = b
-            .transition_table_builder()
-            .states()
-            .difference(&b.states())
-            .cloned()
-            .collect();
-        if !state_diff.is_empty() {
-            return Err(RepresentationCreationError::TransitionTableStateMismatch(
-                state_diff,
-            ));
-        }
-
-        // Validate alphabet
-        let alpha_diff: HashSet<_> = b
-            .transition_table_builder()
-            .alphabet()
-            .difference(&b.alphabet())
-            .cloned()
-            .collect::<HashSet<_>>();
-        if !alpha_diff.is_empty() {
-            return Err(RepresentationCreationError::TransitionTableAlphabetMismatch(alpha_diff));
-        }
-
-        let transition_table = TableTy::from_builder(b.transition_table_builder())
-            .map_err(RepresentationCreationError::TableConstructionError)?;
-
-        Ok(GeneralMachineRepresentation {
-            states: b.states().clone(),
-            starting_state,
-            accepting_state,
-            rejecting_state,
-            alphabet: b.alphabet().clone(),
-            transition_table,
-        })
-    }
-}
+use hashbrown::HashSet;
+use std::fmt::Debug;
+
+use crate::{
+    builders::{MachineRepresentationBuilder, TransitionTableBuilder},
+    common::StateTrait,
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+};
+
+#[derive(Debug, Clone)]
+pub struct GeneralMachineRepresentation<StateTy, TableTy>
+where
+    StateTy: StateTrait,
+    TableTy: TransitionTable<StateTy, InputTy = char> + Debug,
+{
+    states: HashSet<StateTy>,
+    starting_state: StateTy,
+    accepting_states: HashSet<StateTy>,
+    rejecting_states: HashSet<StateTy>,
+    implicit_reject: StateTy,
+    halting_states: HashSet<StateTy>,
+    alphabet: HashSet<char>,
+    transition_table: TableTy,
+}
+
+impl<StateTy, TableTy> GeneralMachineRepresentation<StateTy, TableTy>
+where
+    StateTy: StateTrait,
+    TableTy: TransitionTable<StateTy, InputTy = char> + Debug,
+{
+    /// Get the rejecting state missing transitions move to, the first one the builder was given
+    pub fn implicit_reject(&self) -> &StateTy {
+        &self.implicit_reject
+    }
+}
+
+#[derive(Debug)]
+pub enum RepresentationCreationError<StateTy, TableTy>
+where
+    StateTy: StateTrait,
+    TableTy: TransitionTable<StateTy, InputTy = char> + Debug,
+{
+    StartingStateNotSpecified,
+    /// Neither an accepting nor a plain halting state was specified
+    AcceptStateNotSpecified,
+    /// No rejecting state was specified, so missing transitions have nowhere to go
+    RejectStateNotSpecified,
+    TransitionTableStateMismatch(HashSet<StateTy>),
+    TransitionTableAlphabetMismatch(HashSet<char>),
+    TableConstructionError(TableTy::ErrorTy),
+}
+
+impl<StateTy, TableTy> MachineRepresentation<StateTy>
+    for GeneralMachineRepresentation<StateTy, TableTy>
+where
+    StateTy: StateTrait,
+    TableTy: TransitionTable<StateTy, InputTy = char> + Debug,
+{
+    type InputTy = char;
+    type OutputTy = TableTy::OutputTy;
+    type TableTy = TableTy;
+    type ErrorTy = RepresentationCreationError<StateTy, TableTy>;
+
+    fn states(&self) -> &HashSet<StateTy> {
+        &self.states
+    }
+
+    fn starting_state(&self) -> &StateTy {
+        &self.starting_state
+    }
+
+    fn accepting_states(&self) -> &HashSet<StateTy> {
+        &self.accepting_states
+    }
+
+    fn rejecting_states(&self) -> &HashSet<StateTy> {
+        &self.rejecting_states
+    }
+
+    fn halting_states(&self) -> &HashSet<StateTy> {
+        &self.halting_states
+    }
+
+    fn alphabet(&self) -> &HashSet<char> {
+        &self.alphabet
+    }
+
+    fn transition_table(&self) -> &Self::TableTy {
+        &self.transition_table
+    }
+
+    #[allow(clippy::needless_borrow)]
+    fn from_builder<Builder>(b: &Builder) -> Result<Self, Self::ErrorTy>
+    where
+        Builder: MachineRepresentationBuilder<StateTy>,
+        Builder::TableBuilder: TransitionTableBuilder<StateTy, InputTy = char>,
+    {
+        let starting_state = b
+            .starting_state()
+            .as_ref()
+            .cloned()
+            .ok_or(RepresentationCreationError::StartingStateNotSpecified)?;
+        if b.accepting_states().is_empty() && b.halting_states().is_empty() {
+            return Err(RepresentationCreationError::AcceptStateNotSpecified);
+        }
+        let implicit_reject = b
+            .implicit_reject()
+            .cloned()
+            .ok_or(RepresentationCreationError::RejectStateNotSpecified)?;
+
+        // Validate states
+
+        let state_diff: HashSet<_> = b
+            .transition_table_builder()
+            .states()
+            .difference(&b.states())
+            .cloned()
+            .collect();
+        if !state_diff.is_empty() {
+            return Err(RepresentationCreationError::TransitionTableStateMismatch(
+                state_diff,
+            ));
+        }
+
+        // Validate alphabet
+        let alpha_diff: HashSet<_> = b
+            .transition_table_builder()
+            .alphabet()
+            .difference(&b.alphabet())
+            .cloned()
+            .collect::<HashSet<_>>();
+        if !alpha_diff.is_empty() {
+            return Err(RepresentationCreationError::TransitionTableAlphabetMismatch(alpha_diff));
+        }
+
+        let transition_table = TableTy::from_builder(b.transition_table_builder())
+            .map_err(RepresentationCreationError::TableConstructionError)?;
+
+        Ok(GeneralMachineRepresentation {
+            states: b.states().clone(),
+            starting_state,
+            accepting_states: b.accepting_states().clone(),
+            rejecting_states: b.rejecting_states().clone(),
+            implicit_reject,
+            halting_states: b.halting_states().clone(),
+            alphabet: b.alphabet().clone(),
+            transition_table,
+        })
+    }
+}
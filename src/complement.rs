@@ -0,0 +1,212 @@
+//! Complement of deterministic deciders.
+//!
+//! [`complement`](fn.complement.html) first turns every missing transition into an explicit one, doing what
+//! [`DeterministicTuringMachine::step`](../deterministic_tm/struct.DeterministicTuringMachine.html) does implicitly,
+//! then swaps the accepting and rejecting states. Plain halting states count as not accepting, so they accept in the
+//! complement.
+//!
+//! The result is only the complement on inputs the source machine stops on, as a machine that loops keeps looping, so
+//! it comes along with whether the machine is [known to be total](fn.is_known_total.html).
+
+use hashbrown::HashSet;
+
+use crate::{
+    builders::MachineRepresentationBuilder,
+    common::{Action, Motion, State},
+    deterministic_tm::DeterministicMachineRepresentation,
+    machine_parser::MachineParser,
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+};
+
+type Repr = DeterministicMachineRepresentation<String>;
+
+/// Whether the machine stops on every input, judging from its states alone.
+/// This holds when no cycle goes through the non final states reachable from the start, so that every run stops
+/// within as many steps as there are states. Machines that are total for subtler reasons are not recognised
+pub fn is_known_total(repr: &Repr) -> bool {
+    let successors = |state: &String| -> Vec<String> {
+        repr.alphabet()
+            .iter()
+            .filter_map(|c| repr.transition_table().apply_transition_table(state, *c))
+            .map(|act| act.next_state().clone())
+            .filter(|next| repr.state_kind(next) == State::Neutral)
+            .collect()
+    };
+
+    // Depth first search, looking for an edge back into the current path
+    let mut done: HashSet<String> = HashSet::new();
+    let mut path: HashSet<String> = HashSet::new();
+    let mut stack: Vec<(String, Vec<String>)> = Vec::new();
+    let start = repr.starting_state();
+    if repr.state_kind(start) == State::Neutral {
+        path.insert(start.clone());
+        stack.push((start.clone(), successors(start)));
+    }
+    while let Some((state, pending)) = stack.last_mut() {
+        match pending.pop() {
+            Some(next) if path.contains(&next) => return false,
+            Some(next) if !done.contains(&next) => {
+                path.insert(next.clone());
+                let following = successors(&next);
+                stack.push((next, following));
+            }
+            Some(_) => {}
+            None => {
+                path.remove(state);
+                done.insert(state.clone());
+                stack.pop();
+            }
+        }
+    }
+    true
+}
+
+/// A machine accepting exactly the inputs the given decider does not accept, along with whether the decider is known
+/// to be total. When it is not, the complement loops wherever the decider does
+pub fn complement(repr: &Repr) -> (Repr, bool) {
+    let mut alphabet: Vec<char> = repr.alphabet().iter().cloned().collect();
+    alphabet.sort();
+    let mut states: Vec<&String> = repr.states().iter().collect();
+    states.sort();
+    let implicit_reject = repr.implicit_reject();
+
+    let mut parser = MachineParser::default();
+    for state in &states {
+        let kind = match repr.state_kind(state) {
+            State::Accepting => State::Rejecting,
+            State::Rejecting | State::Halting => State::Accepting,
+            State::Neutral => State::Neutral,
+        };
+        parser
+            .add_state(state.to_string(), kind.clone())
+            .expect("State names are unique");
+        if kind != State::Neutral {
+            continue;
+        }
+
+        for c in &alphabet {
+            let act = repr
+                .transition_table()
+                .apply_transition_table(state, *c)
+                .unwrap_or_else(|| Action::new(implicit_reject.clone(), *c, Motion::Left));
+            parser
+                .get_transition_builder()
+                .add_transition(state.to_string(), *c, act);
+        }
+    }
+
+    // Without accepting states in the source there is nothing to reject with, but the format needs a rejecting state
+    if repr.accepting_states().is_empty() {
+        let mut name = "reject".to_string();
+        while repr.states().contains(&name) {
+            name.push('\'');
+        }
+        parser
+            .add_state(name, State::Rejecting)
+            .expect("The name is not taken");
+    }
+
+    parser
+        .add_starting_state(repr.starting_state().clone())
+        .expect("The starting state is only set once");
+    for c in alphabet {
+        parser
+            .add_alphabet_symbol(c)
+            .expect("The alphabet has no duplicates");
+    }
+
+    let complemented = Repr::from_builder(&parser).expect("The complement is a valid machine");
+    (complemented, is_known_total(repr))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder, deterministic_tm::DeterministicTuringMachine,
+        limited::LimitedTuringMachineExt, machine_parser, TuringMachine,
+    };
+
+    fn load(machine: &str) -> Repr {
+        Repr::from_builder(&machine_parser::parse(machine.as_bytes()).unwrap()).unwrap()
+    }
+
+    fn run(repr: &Repr, tape: &[char]) -> (bool, usize) {
+        let machine = DeterministicTuringMachine::<String>::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr.clone())
+                .tape(tape.to_vec()),
+        )
+        .unwrap();
+        let exe =
+            LimitedTuringMachineExt::new_with_limit(machine, 100_000).execute_and_get_result();
+        (exe.accepting, exe.num_steps)
+    }
+
+    #[test]
+    fn complements_paren() {
+        let original = load(include_str!("../data/paren.tm"));
+        let (complemented, total) = complement(&original);
+        assert!(!total);
+        let mut seed: u64 = 0x853c_49e6_748f_ea9b;
+        for _ in 0..200 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let len = (seed % 10) as usize;
+            let tape: Vec<char> = (0..len)
+                .map(|i| if (seed >> i) & 1 == 0 { '(' } else { ')' })
+                .collect();
+
+            let (accepting, steps) = run(&original, &tape);
+            assert_eq!(
+                run(&complemented, &tape),
+                (!accepting, steps),
+                "On {:?}",
+                tape
+            );
+        }
+    }
+
+    #[test]
+    fn makes_implicit_rejects_explicit() {
+        let original = load("states 3\nq0\nqa +\nqr -\nalphabet 2 a b\nq0 a qa a S");
+        let (complemented, total) = complement(&original);
+        assert!(total);
+        assert_eq!(
+            complemented
+                .transition_table()
+                .apply_transition_table(&"q0".to_string(), 'b'),
+            Some(Action::new("qr".to_string(), 'b', Motion::Left))
+        );
+        assert!(complemented.accepting_states().contains("qr"));
+        assert!(run(&complemented, &['b']).0);
+        assert!(run(&complemented, &[]).0);
+        assert!(!run(&complemented, &['a']).0);
+
+        // Complementing twice gives back the original language
+        let (twice, _) = complement(&complemented);
+        for tape in &[vec!['a'], vec!['b'], vec![]] {
+            assert_eq!(run(&twice, tape).0, run(&original, tape).0);
+        }
+    }
+
+    #[test]
+    fn halting_states_accept() {
+        let original = load("states 3\nq0\nqh !\nqr -\nalphabet 1 a\nq0 a qh a S");
+        let (complemented, _) = complement(&original);
+        assert!(complemented.accepting_states().contains("qh"));
+        assert!(complemented.rejecting_states().contains("reject"));
+        assert!(run(&complemented, &['a']).0);
+    }
+
+    #[test]
+    fn recognises_total_machines() {
+        assert!(is_known_total(&load(
+            "states 4\nq0\nq1\nqa +\nqr -\nalphabet 1 a\nq0 a q1 a R\nq1 _ qa _ S"
+        )));
+        assert!(!is_known_total(&load(include_str!("../data/paren.tm"))));
+        assert!(!is_known_total(&load(include_str!("../data/sqrt2.tm"))));
+    }
+}
@@ -0,0 +1,504 @@
+//! Empirical time complexity of a machine.
+//!
+//! Inputs are produced by a [`Generator`](struct.Generator.html), an expression such as `"(" * n + ")" * n`,
+//! the machine is run on each of them and the step counts are fitted against a few candidate
+//! [`GrowthClass`](enum.GrowthClass.html)es.
+//!
+//! Machines which never stop, as one writing the digits of a number, are rather run once with
+//! [`measure_output`](fn.measure_output.html), counting the output written every so many steps.
+
+use log::warn;
+use std::{
+    fmt::{self, Display},
+    io::{self, Write},
+    iter::Peekable,
+    str::{Chars, FromStr},
+};
+
+use crate::{
+    builders::TuringMachineBuilder, limited::LimitedTuringMachineExt,
+    machine_representation::MachineRepresentation, tape::Tape, TuringMachine,
+};
+
+#[derive(Debug, PartialEq)]
+pub enum GeneratorError {
+    /// A string literal is not terminated
+    UnterminatedString,
+
+    /// A term does not start with a string literal
+    ExpectedString(Option<char>),
+
+    /// A repetition is neither `n` nor a number
+    ExpectedFactor(Option<char>),
+
+    /// A repetition is a number too large for a `usize`
+    ConstantTooLarge,
+
+    /// The input for this `n` is too long to hold in memory
+    TooLong(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Factor {
+    N,
+    Constant(usize),
+}
+
+/// A string literal, repeated a number of times
+#[derive(Debug, Clone, PartialEq)]
+struct Term {
+    literal: String,
+    factors: Vec<Factor>,
+}
+
+/// An input generator, parametrised over `n`.
+/// The grammar is `expr := term ('+' term)*`, `term := string ('*' factor)*` and `factor := 'n' | integer`,
+/// where strings are enclosed in either single or double quotes.
+/// # Usage:
+/// ```
+/// use turing_machine::complexity::Generator;
+///
+/// let generator: Generator = "\"(\" * n + \")\" * n".parse().unwrap();
+/// assert_eq!(generator.generate(2), Ok(vec!['(', '(', ')', ')']));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Generator {
+    terms: Vec<Term>,
+}
+
+fn skip_whitespace(it: &mut Peekable<Chars>) {
+    while it.peek().is_some_and(|c| c.is_whitespace()) {
+        it.next();
+    }
+}
+
+impl FromStr for Generator {
+    type Err = GeneratorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut it = s.chars().peekable();
+        let mut terms = Vec::new();
+
+        loop {
+            skip_whitespace(&mut it);
+            let quote = match it.next() {
+                Some(q) if q == '"' || q == '\'' => q,
+                c => return Err(GeneratorError::ExpectedString(c)),
+            };
+            let mut literal = String::new();
+            loop {
+                match it.next() {
+                    Some(c) if c == quote => break,
+                    Some(c) => literal.push(c),
+                    None => return Err(GeneratorError::UnterminatedString),
+                }
+            }
+
+            let mut factors = Vec::new();
+            skip_whitespace(&mut it);
+            while it.peek() == Some(&'*') {
+                it.next();
+                skip_whitespace(&mut it);
+                let factor = match it.peek() {
+                    Some('n') => {
+                        it.next();
+                        Factor::N
+                    }
+                    Some(c) if c.is_ascii_digit() => {
+                        let mut value = 0usize;
+                        while let Some(d) = it.peek().and_then(|c| c.to_digit(10)) {
+                            value = value
+                                .checked_mul(10)
+                                .and_then(|v| v.checked_add(d as usize))
+                                .ok_or(GeneratorError::ConstantTooLarge)?;
+                            it.next();
+                        }
+                        Factor::Constant(value)
+                    }
+                    c => return Err(GeneratorError::ExpectedFactor(c.cloned())),
+                };
+                factors.push(factor);
+                skip_whitespace(&mut it);
+            }
+            terms.push(Term { literal, factors });
+
+            match it.next() {
+                Some('+') => continue,
+                None => break,
+                c => return Err(GeneratorError::ExpectedString(c)),
+            }
+        }
+
+        Ok(Generator { terms })
+    }
+}
+
+impl Generator {
+    /// Produce the input for a given `n`, or an error if it is too long to hold in memory
+    pub fn generate(&self, n: usize) -> Result<Vec<char>, GeneratorError> {
+        let repetitions = self
+            .terms
+            .iter()
+            .map(|term| {
+                term.factors
+                    .iter()
+                    .map(|f| match f {
+                        Factor::N => n,
+                        Factor::Constant(c) => *c,
+                    })
+                    .try_fold(1usize, |acc, f| acc.checked_mul(f))
+            })
+            .collect::<Option<Vec<usize>>>()
+            .ok_or(GeneratorError::TooLong(n))?;
+        let len = self
+            .terms
+            .iter()
+            .zip(&repetitions)
+            .try_fold(0usize, |len, (term, r)| {
+                r.checked_mul(term.literal.chars().count())
+                    .and_then(|l| len.checked_add(l))
+            })
+            .ok_or(GeneratorError::TooLong(n))?;
+
+        let mut tape = Vec::new();
+        tape.try_reserve_exact(len)
+            .map_err(|_| GeneratorError::TooLong(n))?;
+        for (term, r) in self.terms.iter().zip(repetitions) {
+            for _ in 0..r {
+                tape.extend(term.literal.chars());
+            }
+        }
+        Ok(tape)
+    }
+}
+
+/// A single measurement
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sample {
+    /// The length of the input
+    pub size: usize,
+
+    /// The number of steps, reported as the CLI does
+    pub steps: usize,
+
+    /// Did the machine stop within the step limit?
+    pub finished: bool,
+}
+
+/// Runs the machine on the input generated for each `n`, skipping with a warning those too long to generate
+pub fn measure<T>(
+    repr: &T::ReprTy,
+    generator: &Generator,
+    range: impl Iterator<Item = usize>,
+    limit: Option<usize>,
+) -> Result<Vec<Sample>, T::ErrorTy>
+where
+    T: TuringMachine,
+    T::ReprTy: MachineRepresentation<T::StateTy, InputTy = char> + Clone,
+{
+    let mut samples = Vec::new();
+    for n in range {
+        let tape = match generator.generate(n) {
+            Ok(tape) => tape,
+            Err(e) => {
+                warn!("Skipping n = {}: {:?}", n, e);
+                continue;
+            }
+        };
+        let size = tape.len();
+        let machine = T::from_builder(TuringMachineBuilder::new().repr(repr.clone()).tape(tape))?;
+        let machine = match limit {
+            Some(limit) => LimitedTuringMachineExt::new_with_limit(machine, limit),
+            None => LimitedTuringMachineExt::new(machine),
+        };
+        let exe = machine.execute_and_get_result();
+        samples.push(Sample {
+            size,
+            steps: exe.num_steps.saturating_sub(1),
+            finished: limit.is_none_or(|limit| exe.num_steps <= limit),
+        });
+    }
+    Ok(samples)
+}
+
+/// Runs the machine once on `tape`, counting the cells holding one of `symbols` after each number of steps in `checkpoints`.
+/// Each sample has the count as its size, so the steps are fitted against the length of the output.
+/// The checkpoints must be increasing, and there are no samples past the machine stopping.
+pub fn measure_output<T>(
+    repr: &T::ReprTy,
+    tape: Vec<char>,
+    symbols: &[char],
+    checkpoints: impl Iterator<Item = usize>,
+) -> Result<Vec<Sample>, T::ErrorTy>
+where
+    T: TuringMachine,
+    T::Tape: Tape,
+    T::ReprTy: MachineRepresentation<T::StateTy, InputTy = char> + Clone,
+{
+    let mut machine = T::from_builder(TuringMachineBuilder::new().repr(repr.clone()).tape(tape))?;
+    let mut steps = 0;
+    let mut samples = Vec::new();
+    for checkpoint in checkpoints {
+        while steps < checkpoint && !machine.is_finished() {
+            machine.step();
+            steps += 1;
+        }
+        if steps < checkpoint {
+            break;
+        }
+        samples.push(Sample {
+            size: machine
+                .tape()
+                .runs()
+                .iter()
+                .filter(|r| symbols.contains(&r.symbol))
+                .map(|r| r.len)
+                .sum(),
+            steps,
+            finished: true,
+        });
+    }
+    Ok(samples)
+}
+
+/// Write the samples as a CSV, in the same format as the ones in `data/`
+pub fn write_csv(samples: &[Sample], mut writer: impl Write) -> io::Result<()> {
+    writeln!(writer, "n,iter")?;
+    for sample in samples {
+        writeln!(writer, "{},{}", sample.size, sample.steps)?;
+    }
+    Ok(())
+}
+
+/// Write the samples of [`measure_output`](fn.measure_output.html) as a CSV, in the same format as `data/sqrt2.csv`
+pub fn write_output_csv(samples: &[Sample], mut writer: impl Write) -> io::Result<()> {
+    writeln!(writer, "steps,digits")?;
+    for sample in samples {
+        writeln!(writer, "{},{}", sample.steps, sample.size)?;
+    }
+    Ok(())
+}
+
+/// The candidate functions the step counts are fitted against
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GrowthClass {
+    Linear,
+    Linearithmic,
+    Quadratic,
+    Exponential,
+}
+
+impl GrowthClass {
+    pub const ALL: [GrowthClass; 4] = [
+        GrowthClass::Linear,
+        GrowthClass::Linearithmic,
+        GrowthClass::Quadratic,
+        GrowthClass::Exponential,
+    ];
+
+    /// Evaluate the function at `n`
+    pub fn eval(self, n: f64) -> f64 {
+        match self {
+            GrowthClass::Linear => n,
+            GrowthClass::Linearithmic if n > 1.0 => n * n.log2(),
+            GrowthClass::Linearithmic => 0.0,
+            GrowthClass::Quadratic => n * n,
+            GrowthClass::Exponential => n.exp2(),
+        }
+    }
+}
+
+impl Display for GrowthClass {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GrowthClass::Linear => write!(f, "n"),
+            GrowthClass::Linearithmic => write!(f, "n log n"),
+            GrowthClass::Quadratic => write!(f, "n^2"),
+            GrowthClass::Exponential => write!(f, "2^n"),
+        }
+    }
+}
+
+/// The least squares fit `steps ~ a * f(n) + b` for a [`GrowthClass`](enum.GrowthClass.html) `f`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fit {
+    pub class: GrowthClass,
+    pub a: f64,
+    pub b: f64,
+
+    /// The coefficient of determination, the closer to 1 the better
+    pub r_squared: f64,
+}
+
+/// Fit the finished samples against every growth class, the best fit first.
+/// Classes that cannot be evaluated on the samples (e.g. `2^n` on very long inputs) are left out.
+pub fn fit(samples: &[Sample]) -> Vec<Fit> {
+    let points: Vec<(f64, f64)> = samples
+        .iter()
+        .filter(|s| s.finished)
+        .map(|s| (s.size as f64, s.steps as f64))
+        .collect();
+    if points.len() < 2 {
+        return Vec::new();
+    }
+
+    let count = points.len() as f64;
+    let mean_y = points.iter().map(|(_, y)| y).sum::<f64>() / count;
+    let total: f64 = points.iter().map(|(_, y)| (y - mean_y).powi(2)).sum();
+
+    let mut fits: Vec<Fit> = GrowthClass::ALL
+        .iter()
+        .filter_map(|class| {
+            let xs: Vec<f64> = points.iter().map(|(n, _)| class.eval(*n)).collect();
+            if xs.iter().any(|x| !x.is_finite()) {
+                return None;
+            }
+
+            let mean_x = xs.iter().sum::<f64>() / count;
+            let var_x: f64 = xs.iter().map(|x| (x - mean_x).powi(2)).sum();
+            if var_x == 0.0 {
+                return None;
+            }
+            let cov: f64 = xs
+                .iter()
+                .zip(&points)
+                .map(|(x, (_, y))| (x - mean_x) * (y - mean_y))
+                .sum();
+
+            let a = cov / var_x;
+            let b = mean_y - a * mean_x;
+            let residual: f64 = xs
+                .iter()
+                .zip(&points)
+                .map(|(x, (_, y))| (y - (a * x + b)).powi(2))
+                .sum();
+            let r_squared = if total == 0.0 {
+                1.0
+            } else {
+                1.0 - residual / total
+            };
+
+            Some(Fit {
+                class: *class,
+                a,
+                b,
+                r_squared,
+            })
+        })
+        .collect();
+
+    fits.sort_by(|f1, f2| f2.r_squared.total_cmp(&f1.r_squared));
+    fits
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine};
+    use crate::machine_parser;
+
+    fn samples_from(f: impl Fn(usize) -> usize) -> Vec<Sample> {
+        (1..50)
+            .map(|n| Sample {
+                size: n,
+                steps: f(n),
+                finished: true,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parse_generators() {
+        let generator: Generator = "'1' * 2 * n".parse().unwrap();
+        assert_eq!(generator.generate(2), Ok(vec!['1'; 4]));
+
+        let generator: Generator = "\"1\" * n + \"#1#\" + \"0\"*n+'1'".parse().unwrap();
+        assert_eq!(
+            generator.generate(2).unwrap().iter().collect::<String>(),
+            "11#1#001"
+        );
+
+        assert_eq!(
+            "\"(\" * n +".parse::<Generator>(),
+            Err(GeneratorError::ExpectedString(None))
+        );
+        assert_eq!(
+            "\"(\" * m".parse::<Generator>(),
+            Err(GeneratorError::ExpectedFactor(Some('m')))
+        );
+        assert_eq!(
+            "\"(".parse::<Generator>(),
+            Err(GeneratorError::UnterminatedString)
+        );
+        assert_eq!(
+            "\"(\" * 99999999999999999999999".parse::<Generator>(),
+            Err(GeneratorError::ConstantTooLarge)
+        );
+
+        let generator: Generator = format!("\"((\" * n * {}", usize::MAX / 2 + 1)
+            .parse()
+            .unwrap();
+        assert_eq!(generator.generate(0), Ok(vec![]));
+        assert_eq!(generator.generate(2), Err(GeneratorError::TooLong(2)));
+        assert_eq!(generator.generate(4), Err(GeneratorError::TooLong(4)));
+
+        // Too many symbols to allocate, although they can be counted
+        let generator: Generator = format!("\"(\" * n * {}", usize::MAX / 2).parse().unwrap();
+        assert_eq!(generator.generate(1), Err(GeneratorError::TooLong(1)));
+    }
+
+    #[test]
+    fn fit_growth_classes() {
+        assert_eq!(
+            fit(&samples_from(|n| 3 * n + 2))[0].class,
+            GrowthClass::Linear
+        );
+        assert_eq!(
+            fit(&samples_from(|n| n * n + n))[0].class,
+            GrowthClass::Quadratic
+        );
+        assert_eq!(
+            fit(&samples_from(|n| 1 << (n / 2)))[0].class,
+            GrowthClass::Exponential
+        );
+        assert!(fit(&samples_from(|n| n)[..1]).is_empty());
+    }
+
+    #[test]
+    fn measure_paren() {
+        let parser = machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let generator: Generator = "\"()\" * n".parse().unwrap();
+
+        let samples =
+            measure::<DeterministicTuringMachine<String>>(&repr, &generator, 0..4, None).unwrap();
+        let steps: Vec<_> = samples.iter().map(|s| (s.size, s.steps)).collect();
+
+        // Same as data/flat_parendata.csv
+        assert_eq!(steps, vec![(0, 0), (2, 6), (4, 12), (6, 18)]);
+        assert_eq!(fit(&samples)[0].class, GrowthClass::Linear);
+    }
+
+    #[test]
+    fn measure_sqrt2() {
+        let parser = machine_parser::parse(include_str!("../data/sqrt2.tm").as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let samples = measure_output::<DeterministicTuringMachine<String>>(
+            &repr,
+            Vec::new(),
+            &['0', '1'],
+            (0..100).map(|i| i * 100),
+        )
+        .unwrap();
+
+        // Same as the start of data/sqrt2.csv
+        let mut csv = Vec::new();
+        write_output_csv(&samples, &mut csv).unwrap();
+        let expected: String = include_str!("../data/sqrt2.csv")
+            .lines()
+            .take(101)
+            .map(|l| format!("{}\n", l))
+            .collect();
+        assert_eq!(String::from_utf8(csv).unwrap(), expected);
+    }
+}
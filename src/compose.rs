@@ -0,0 +1,294 @@
+//! Combinators building new deterministic machines out of existing ones.
+//!
+//! The states of each component are prefixed with its position (`1.`, `2.`, ...) so they never collide, and every
+//! result has a single `accept` and `reject` state, plus a `halt` state when a component can halt. Transitions missing
+//! in a component still implicitly reject in the composed machine.
+//!
+//! # Usage:
+//! ```
+//! use turing_machine::{
+//!     compose, deterministic_tm::DeterministicMachineRepresentation, machine_parser,
+//!     machine_representation::MachineRepresentation,
+//! };
+//!
+//! let load = |s: &str| {
+//!     DeterministicMachineRepresentation::from_builder(&machine_parser::parse(s.as_bytes()).unwrap()).unwrap()
+//! };
+//! let to_end = load("states 3\nq0\nqa +\nqr -\nalphabet 1 a\nq0 a q0 a R\nq0 _ qa _ S");
+//! let append = load("states 3\nq0\nqa +\nqr -\nalphabet 1 a\nq0 _ qa a S");
+//!
+//! let machine = compose::sequence(&to_end, &append);
+//! assert_eq!(machine.starting_state(), "1.q0");
+//! ```
+
+use hashbrown::HashSet;
+
+use crate::{
+    builders::MachineRepresentationBuilder,
+    common::{Action, Motion, State},
+    deterministic_tm::DeterministicMachineRepresentation,
+    machine_parser::MachineParser,
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+};
+
+type Repr = DeterministicMachineRepresentation<String>;
+
+const ACCEPT: &str = "accept";
+const REJECT: &str = "reject";
+const HALT: &str = "halt";
+
+/// Where the final states of an embedded machine lead to
+struct Exits<'a> {
+    accept: &'a str,
+    reject: &'a str,
+    halt: &'a str,
+}
+
+/// Accumulates the states and transitions of the composed machine
+#[derive(Default)]
+struct Composer {
+    parser: MachineParser,
+    alphabet: HashSet<char>,
+    halts: bool,
+}
+
+impl Composer {
+    fn add_state(&mut self, state: &str) {
+        self.parser
+            .add_state(state.to_string(), State::Neutral)
+            .expect("Prefixed state names are unique");
+    }
+
+    fn add_transition(&mut self, from: &str, read: char, to: &str, write: char, motion: Motion) {
+        self.alphabet.insert(read);
+        self.alphabet.insert(write);
+        self.parser.get_transition_builder().add_transition(
+            from.to_string(),
+            read,
+            Action::new(to.to_string(), write, motion),
+        );
+    }
+
+    /// Copy the machine, prefixing its states, and return the state it starts in
+    fn embed(&mut self, repr: &Repr, prefix: &str, exits: &Exits) -> String {
+        let rename = |state: &String| match repr.state_kind(state) {
+            State::Accepting => exits.accept.to_string(),
+            State::Rejecting => exits.reject.to_string(),
+            State::Halting => exits.halt.to_string(),
+            State::Neutral => format!("{}{}", prefix, state),
+        };
+        if !repr.halting_states().is_empty() {
+            self.halts = true;
+        }
+        self.alphabet.extend(repr.alphabet());
+
+        let mut states: Vec<&String> = repr.states().iter().collect();
+        states.sort();
+        for state in states {
+            if repr.state_kind(state) != State::Neutral {
+                continue;
+            }
+            let from = rename(state);
+            self.add_state(&from);
+
+            let mut alphabet: Vec<&char> = repr.alphabet().iter().collect();
+            alphabet.sort();
+            for c in alphabet {
+                if let Some(act) = repr.transition_table().apply_transition_table(state, *c) {
+                    self.add_transition(
+                        &from,
+                        *c,
+                        &rename(act.next_state()),
+                        *act.tape_output(),
+                        *act.motion(),
+                    );
+                }
+            }
+        }
+        rename(repr.starting_state())
+    }
+
+    fn finish(mut self, start: String) -> Repr {
+        self.parser
+            .add_state(ACCEPT.to_string(), State::Accepting)
+            .expect("Prefixed state names are unique");
+        self.parser
+            .add_state(REJECT.to_string(), State::Rejecting)
+            .expect("Prefixed state names are unique");
+        if self.halts {
+            self.parser
+                .add_state(HALT.to_string(), State::Halting)
+                .expect("Prefixed state names are unique");
+        }
+        self.parser
+            .add_starting_state(start)
+            .expect("The starting state is only set once");
+
+        self.alphabet.insert('_');
+        for c in self.alphabet {
+            self.parser
+                .add_alphabet_symbol(c)
+                .expect("The alphabet is a set");
+        }
+
+        Repr::from_builder(&self.parser).expect("A composed machine is a valid machine")
+    }
+}
+
+const DEFAULT_EXITS: Exits = Exits {
+    accept: ACCEPT,
+    reject: REJECT,
+    halt: HALT,
+};
+
+/// Run `first`, then `second` on the resulting tape, starting from where the head of `first` stopped.
+/// `second` only runs if `first` accepts, and the composed machine rejects or halts as soon as `first` does
+pub fn sequence(first: &Repr, second: &Repr) -> Repr {
+    let mut composer = Composer::default();
+    let second_start = composer.embed(second, "2.", &DEFAULT_EXITS);
+    let start = composer.embed(
+        first,
+        "1.",
+        &Exits {
+            accept: &second_start,
+            ..DEFAULT_EXITS
+        },
+    );
+    composer.finish(start)
+}
+
+/// Run one of the machines, chosen by the symbol under the head; the head does not move while choosing.
+/// If a symbol is listed twice, the first machine is used, and symbols that are not listed reject
+pub fn branch(cases: &[(char, &Repr)]) -> Repr {
+    const START: &str = "branch";
+
+    let mut composer = Composer::default();
+    composer.add_state(START);
+    let mut seen = HashSet::new();
+    for (i, (c, repr)) in cases.iter().enumerate() {
+        let entry = composer.embed(repr, &format!("{}.", i + 1), &DEFAULT_EXITS);
+        if seen.insert(*c) {
+            composer.add_transition(START, *c, &entry, *c, Motion::Stay);
+        }
+    }
+    composer.finish(START.to_string())
+}
+
+/// Run `body` over and over for as long as the symbol under the head is one of `symbols`, then accept.
+/// The composed machine rejects or halts as soon as `body` does
+pub fn repeat_while(body: &Repr, symbols: &[char]) -> Repr {
+    const START: &str = "test";
+
+    let mut composer = Composer::default();
+    composer.add_state(START);
+    let entry = composer.embed(
+        body,
+        "1.",
+        &Exits {
+            accept: START,
+            ..DEFAULT_EXITS
+        },
+    );
+
+    let mut alphabet: Vec<char> = composer
+        .alphabet
+        .iter()
+        .chain(symbols)
+        .chain(&['_'])
+        .cloned()
+        .collect();
+    alphabet.sort();
+    alphabet.dedup();
+    for c in alphabet {
+        let next = if symbols.contains(&c) { &entry } else { ACCEPT };
+        composer.add_transition(START, c, next, c, Motion::Stay);
+    }
+    composer.finish(START.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder, deterministic_tm::DeterministicTuringMachine,
+        limited::LimitedTuringMachineExt, machine_parser, TuringMachine,
+    };
+
+    fn load(machine: &str) -> Repr {
+        Repr::from_builder(&machine_parser::parse(machine.as_bytes()).unwrap()).unwrap()
+    }
+
+    /// Run the machine, returning whether it accepted and the tape without trailing blanks
+    fn run(repr: &Repr, tape: &str) -> (bool, String) {
+        let machine = DeterministicTuringMachine::<String>::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr.clone())
+                .tape(tape.chars().collect()),
+        )
+        .unwrap();
+        let exe = LimitedTuringMachineExt::new_with_limit(machine, 1000).execute_and_get_result();
+        let tape: String = exe.tape.iter().collect();
+        (exe.accepting, tape.trim_end_matches('_').to_string())
+    }
+
+    fn to_end() -> Repr {
+        load("states 3\nq0\nqa +\nqr -\nalphabet 2 a b\nq0 a q0 a R\nq0 b q0 b R\nq0 _ qa _ S")
+    }
+
+    fn write(c: char) -> Repr {
+        let alphabet = if c == 'a' || c == 'b' {
+            "2 a b".to_string()
+        } else {
+            format!("3 a b {}", c)
+        };
+        load(&format!(
+            "states 3\nq0\nqa +\nqr -\nalphabet {}\nq0 a qa {} S\nq0 b qa {} S\nq0 _ qa {} S",
+            alphabet, c, c, c
+        ))
+    }
+
+    #[test]
+    fn sequences_machines() {
+        let machine = sequence(&to_end(), &write('c'));
+        assert_eq!(run(&machine, "ab"), (true, "abc".to_string()));
+        assert!(machine.states().contains("1.q0"));
+        assert!(machine.states().contains("2.q0"));
+
+        // The first machine rejecting stops everything
+        assert!(!run(&machine, "acb").0);
+
+        // Nesting keeps the names apart
+        let twice = sequence(&machine, &sequence(&write('a'), &write('b')));
+        assert!(twice.states().contains("1.2.q0"));
+        assert!(twice.states().contains("2.2.q0"));
+    }
+
+    #[test]
+    fn branches_on_symbol() {
+        let machine = branch(&[('a', &write('x')), ('b', &write('y')), ('a', &write('z'))]);
+        assert_eq!(run(&machine, "a"), (true, "x".to_string()));
+        assert_eq!(run(&machine, "b"), (true, "y".to_string()));
+        assert!(!run(&machine, "").0);
+    }
+
+    #[test]
+    fn repeats_body() {
+        let step = load("states 3\nq0\nqa +\nqr -\nalphabet 2 a b\nq0 a qa b R");
+        let machine = repeat_while(&step, &['a']);
+        assert_eq!(run(&machine, "aaab"), (true, "bbbb".to_string()));
+        assert_eq!(run(&machine, ""), (true, String::new()));
+
+        // Composed machines compose further
+        let machine = sequence(&machine, &write('c'));
+        assert_eq!(run(&machine, "aab"), (true, "bbc".to_string()));
+    }
+
+    #[test]
+    fn keeps_halting_states() {
+        let halt = load("states 3\nq0\nqh !\nqr -\nalphabet 1 a\nq0 a qh a S");
+        let machine = sequence(&halt, &write('c'));
+        assert!(machine.halting_states().contains(HALT));
+        assert!(!sequence(&to_end(), &write('c')).states().contains(HALT));
+    }
+}
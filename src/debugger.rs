@@ -0,0 +1,408 @@
+//! A step debugger, following a single run of a machine.
+//!
+//! The [`Debugger`](struct.Debugger.html) works on the representation of a machine rather than on an engine, so that
+//! it can follow one branch of a nondeterministic machine: whenever several transitions apply it stops and waits for
+//! one to be [chosen](struct.Debugger.html#method.step_with). Missing transitions reject, as they do in the engines.
+//!
+//! Runs stop on [`Breakpoint`](enum.Breakpoint.html)s, checked against each configuration reached, and on watchpoints,
+//! which trigger when a step changes the symbol in a watched cell.
+
+use std::fmt::{self, Debug};
+
+use crate::{
+    builders::{TuringMachineBuilder, ValidationError},
+    common::{representation::GeneralMachineRepresentation, Action, Motion, State},
+    machine_representation::MachineRepresentation,
+    transition_table::{ActionSet, TransitionTable},
+    utils::apply_action,
+};
+
+/// A condition on a configuration that stops the run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Breakpoint {
+    /// The machine is in this state
+    State(String),
+
+    /// The machine is in this state, reading this symbol
+    Transition(String, char),
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Breakpoint::State(s) => write!(f, "{}", s),
+            Breakpoint::Transition(s, c) => write!(f, "{} {}", s, c),
+        }
+    }
+}
+
+/// Why a run stopped before using up its steps
+#[derive(Debug, Clone, PartialEq)]
+pub enum Stop {
+    /// The machine reached a final state
+    Finished(State),
+
+    /// The breakpoint at this index matched
+    Breakpoint(usize),
+
+    /// A watched cell changed
+    Watchpoint { cell: usize, old: char, new: char },
+
+    /// Several transitions apply, and one must be chosen
+    Choice,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum DebuggerError {
+    /// The symbol is not in the alphabet of the machine
+    UnknownSymbol(char),
+
+    /// There is no transition with this index to choose
+    NoSuchChoice(usize),
+
+    /// There is no breakpoint with this index
+    NoSuchBreakpoint(usize),
+
+    /// The machine has already stopped
+    Finished,
+}
+
+/// A single run of a machine, along with its breakpoints and watchpoints
+pub struct Debugger<TableTy>
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+{
+    repr: GeneralMachineRepresentation<String, TableTy>,
+    state: String,
+    tape: Vec<char>,
+    head: usize,
+    steps: usize,
+    breakpoints: Vec<Breakpoint>,
+    watchpoints: Vec<usize>,
+}
+
+impl<TableTy> Debugger<TableTy>
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    /// Start a run from a builder, returning the [`ValidationError`](../builders/enum.ValidationError.html) if it is invalid
+    pub fn new(
+        builder: TuringMachineBuilder<String, GeneralMachineRepresentation<String, TableTy>>,
+    ) -> Result<Self, ValidationError<char>> {
+        let (tape, repr) = builder.validate()?.decompose();
+        Ok(Debugger {
+            state: repr.starting_state().clone(),
+            repr,
+            tape,
+            head: 0,
+            steps: 0,
+            breakpoints: Vec::new(),
+            watchpoints: Vec::new(),
+        })
+    }
+
+    /// Get the state the machine is in
+    pub fn state(&self) -> &String {
+        &self.state
+    }
+
+    /// Get the position of the head on the tape
+    pub fn head(&self) -> usize {
+        self.head
+    }
+
+    /// Get the tape, which may end with blanks
+    pub fn tape(&self) -> &[char] {
+        &self.tape
+    }
+
+    /// Get the number of steps taken so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Get the breakpoints, in the order they were added
+    pub fn breakpoints(&self) -> &[Breakpoint] {
+        &self.breakpoints
+    }
+
+    /// Get the watched cells
+    pub fn watchpoints(&self) -> &[usize] {
+        &self.watchpoints
+    }
+
+    /// Get the symbol under the head
+    pub fn read(&self) -> char {
+        *self.tape.get(self.head).unwrap_or(&'_')
+    }
+
+    /// Has the machine reached a final state?
+    pub fn is_finished(&self) -> bool {
+        self.repr.state_kind(&self.state).is_final()
+    }
+
+    /// The transitions that apply in the current configuration, in a stable order.
+    /// There are none when the machine has stopped, or is about to implicitly reject
+    pub fn choices(&self) -> Vec<Action<String>> {
+        if self.is_finished() {
+            return Vec::new();
+        }
+        let mut actions = self
+            .repr
+            .transition_table()
+            .apply_transition_table(&self.state, self.read())
+            .map(|out| out.actions())
+            .unwrap_or_default();
+        actions.sort_by(|a, b| {
+            (a.next_state(), a.tape_output(), a.motion()).cmp(&(
+                b.next_state(),
+                b.tape_output(),
+                b.motion(),
+            ))
+        });
+        actions
+    }
+
+    pub fn add_breakpoint(&mut self, breakpoint: Breakpoint) {
+        self.breakpoints.push(breakpoint);
+    }
+
+    pub fn remove_breakpoint(&mut self, index: usize) -> Result<Breakpoint, DebuggerError> {
+        if index < self.breakpoints.len() {
+            Ok(self.breakpoints.remove(index))
+        } else {
+            Err(DebuggerError::NoSuchBreakpoint(index))
+        }
+    }
+
+    pub fn add_watchpoint(&mut self, cell: usize) {
+        if !self.watchpoints.contains(&cell) {
+            self.watchpoints.push(cell);
+        }
+    }
+
+    /// Stop watching a cell, returning whether it was watched
+    pub fn remove_watchpoint(&mut self, cell: usize) -> bool {
+        let watched = self.watchpoints.len();
+        self.watchpoints.retain(|c| *c != cell);
+        watched != self.watchpoints.len()
+    }
+
+    /// Overwrite a cell of the tape, growing it with blanks if needed
+    pub fn set_cell(&mut self, cell: usize, symbol: char) -> Result<(), DebuggerError> {
+        if !self.repr.alphabet().contains(&symbol) {
+            return Err(DebuggerError::UnknownSymbol(symbol));
+        }
+        if cell >= self.tape.len() {
+            self.tape.resize(cell + 1, '_');
+        }
+        self.tape[cell] = symbol;
+        Ok(())
+    }
+
+    /// Take a step using the transition at `index` in [`choices`](#method.choices), or the implicit reject when there
+    /// are none and `index` is 0. Returns why the run should stop, if it should
+    pub fn step_with(&mut self, index: usize) -> Result<Option<Stop>, DebuggerError> {
+        if self.is_finished() {
+            return Err(DebuggerError::Finished);
+        }
+        let mut choices = self.choices();
+        let act = if choices.is_empty() && index == 0 {
+            let rejecting_state = self.repr.implicit_reject().clone();
+            Action::new(rejecting_state, self.read(), Motion::Left)
+        } else if index < choices.len() {
+            choices.swap_remove(index)
+        } else {
+            return Err(DebuggerError::NoSuchChoice(index));
+        };
+
+        let cell = self.head;
+        let old = self.read();
+        apply_action(act, &mut self.tape, &mut self.head, &mut self.state);
+        self.steps += 1;
+
+        let new = self.tape[cell];
+        Ok(if self.is_finished() {
+            Some(Stop::Finished(self.repr.state_kind(&self.state)))
+        } else if old != new && self.watchpoints.contains(&cell) {
+            Some(Stop::Watchpoint { cell, old, new })
+        } else {
+            self.breakpoints
+                .iter()
+                .position(|b| match b {
+                    Breakpoint::State(s) => *s == self.state,
+                    Breakpoint::Transition(s, c) => *s == self.state && *c == self.read(),
+                })
+                .map(Stop::Breakpoint)
+        })
+    }
+
+    /// Take up to `steps` steps, stopping early when the machine stops, a breakpoint or watchpoint triggers, or a
+    /// transition has to be chosen
+    pub fn run(&mut self, steps: usize) -> Option<Stop> {
+        for _ in 0..steps {
+            if self.is_finished() {
+                return Some(Stop::Finished(self.repr.state_kind(&self.state)));
+            }
+            if self.choices().len() > 1 {
+                return Some(Stop::Choice);
+            }
+            if let Some(stop) = self.step_with(0).expect("There is a single choice") {
+                return Some(stop);
+            }
+        }
+        None
+    }
+
+    /// The configuration, showing up to `radius` cells on each side of the head
+    pub fn configuration(&self, radius: usize) -> String {
+        let start = self.head.saturating_sub(radius);
+        let end = self.head + radius + 1;
+        let mut cells = String::new();
+        if start > 0 {
+            cells.push_str("...");
+        }
+        for i in start..end {
+            let c = *self.tape.get(i).unwrap_or(&'_');
+            if i == self.head {
+                cells.push_str(&format!("[{}]", c));
+            } else {
+                cells.push(c);
+            }
+        }
+        if end < self.tape.len() {
+            cells.push_str("...");
+        }
+        format!("{} {} @{} {}", self.steps, self.state, self.head, cells)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deterministic_tm::{
+            transition_table::DeterministicTransitionTable, DeterministicMachineRepresentation,
+        },
+        machine_parser,
+        non_deterministic_tm::NonDeterministicMachineRepresentation,
+    };
+
+    fn paren(tape: &str) -> Debugger<DeterministicTransitionTable<String>> {
+        let repr = DeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap(),
+        )
+        .unwrap();
+        Debugger::new(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape(tape.chars().collect()),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn runs_to_completion() {
+        let mut debugger = paren("(())");
+        assert_eq!(debugger.run(1000), Some(Stop::Finished(State::Accepting)));
+        assert_eq!(debugger.step_with(0), Err(DebuggerError::Finished));
+
+        let mut debugger = paren("(()");
+        assert_eq!(debugger.run(1000), Some(Stop::Finished(State::Rejecting)));
+    }
+
+    #[test]
+    fn rejects_symbols_outside_the_alphabet() {
+        let repr = DeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap(),
+        )
+        .unwrap();
+        let builder = TuringMachineBuilder::new()
+            .repr(repr)
+            .tape("(x)".chars().collect());
+        assert_eq!(
+            Debugger::new(builder).err(),
+            Some(ValidationError::TapeAlphabetMismatch(vec![(1, 'x')]))
+        );
+    }
+
+    #[test]
+    fn stops_on_breakpoints() {
+        let mut debugger = paren("(())");
+        let state = "close".to_string();
+        debugger.add_breakpoint(Breakpoint::Transition(state.clone(), ')'));
+        assert_eq!(debugger.run(1000), Some(Stop::Breakpoint(0)));
+        assert_eq!(debugger.read(), ')');
+        assert_eq!(debugger.state(), &state);
+
+        // Stepping on stops at the same place only once it is reached again
+        let steps = debugger.steps();
+        debugger.remove_breakpoint(0).unwrap();
+        assert_eq!(
+            debugger.remove_breakpoint(0),
+            Err(DebuggerError::NoSuchBreakpoint(0))
+        );
+        assert_eq!(debugger.run(1), None);
+        assert_eq!(debugger.steps(), steps + 1);
+    }
+
+    #[test]
+    fn stops_on_watchpoints() {
+        let mut debugger = paren("(())");
+        debugger.add_watchpoint(2);
+        let stop = debugger.run(1000);
+        match stop {
+            Some(Stop::Watchpoint { cell, old, .. }) => {
+                assert_eq!((cell, old), (2, ')'));
+                assert_eq!(debugger.head(), 1);
+            }
+            _ => panic!("Unexpected stop {:?}", stop),
+        }
+        assert!(debugger.remove_watchpoint(2));
+        assert!(!debugger.remove_watchpoint(2));
+    }
+
+    #[test]
+    fn edits_tape() {
+        let mut debugger = paren("(()");
+        assert_eq!(debugger.set_cell(3, ')'), Ok(()));
+        assert_eq!(
+            debugger.set_cell(0, 'x'),
+            Err(DebuggerError::UnknownSymbol('x'))
+        );
+        assert_eq!(debugger.run(1000), Some(Stop::Finished(State::Accepting)));
+        assert!(debugger.configuration(2).contains('['));
+    }
+
+    #[test]
+    fn follows_chosen_branch() {
+        let repr = NonDeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(
+                "states 4\nq0\nq1\nqa +\nqr -\nalphabet 1 a\nq0 a q1 a R\nq0 a qa a S\nq1 _ qr _ S"
+                    .as_bytes(),
+            )
+            .unwrap(),
+        )
+        .unwrap();
+        let builder = || {
+            TuringMachineBuilder::new()
+                .repr(repr.clone())
+                .tape(vec!['a'])
+        };
+        let mut debugger = Debugger::new(builder()).unwrap();
+        assert_eq!(debugger.run(10), Some(Stop::Choice));
+        assert_eq!(debugger.choices().len(), 2);
+        assert_eq!(debugger.step_with(2), Err(DebuggerError::NoSuchChoice(2)));
+        assert_eq!(
+            debugger.step_with(1),
+            Ok(Some(Stop::Finished(State::Accepting)))
+        );
+
+        // The choices are ordered by next state
+        let mut debugger = Debugger::new(builder()).unwrap();
+        assert_eq!(debugger.step_with(0), Ok(None));
+        assert_eq!(debugger.state(), "q1");
+        assert_eq!(debugger.run(10), Some(Stop::Finished(State::Rejecting)));
+    }
+}
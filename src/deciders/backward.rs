@@ -0,0 +1,139 @@
+use std::collections::BTreeMap;
+
+use super::{running_states, symbols, transition, Decider, Decision, Repr, Run};
+use crate::{common::Motion, TuringMachine};
+
+/// Finds machines which can never reach a halting transition, by reasoning backwards from each one.
+///
+/// Going back from a configuration which halts, only the cells the machine went over are known. A step back is only
+/// possible from a transition into the right state which wrote what the cell holds, if it is known. When every way
+/// back dies out within `depth` steps, a halting run would have to be shorter than that, so running the machine for
+/// `depth` steps tells.
+#[derive(Debug, Clone)]
+pub struct BackwardReasoning {
+    /// How many steps to go back for
+    pub depth: usize,
+
+    /// How many configurations to go back from before giving up
+    pub max_configurations: usize,
+}
+
+impl Default for BackwardReasoning {
+    fn default() -> Self {
+        Self {
+            depth: 30,
+            max_configurations: 100_000,
+        }
+    }
+}
+
+/// Where the head was before moving
+fn back(motion: Motion) -> i64 {
+    match motion {
+        Motion::Left => 1,
+        Motion::Right => -1,
+        Motion::Stay => 0,
+    }
+}
+
+impl Decider for BackwardReasoning {
+    fn name(&self) -> &'static str {
+        "backward-reasoning"
+    }
+
+    fn decide(&self, repr: &Repr) -> Decision {
+        let states = running_states(repr);
+        let symbols = symbols(repr);
+
+        // Configurations some steps before halting, with the known cells from the head
+        let mut stack: Vec<(usize, &String, BTreeMap<i64, char>)> = Vec::new();
+        for state in &states {
+            for symbol in &symbols {
+                if transition(repr, state, *symbol).is_none() {
+                    stack.push((0, state, vec![(0, *symbol)].into_iter().collect()));
+                }
+            }
+        }
+        if stack.is_empty() {
+            return Decision::non_halting("no transition halts".to_string());
+        }
+
+        let mut seen = 0;
+        while let Some((depth, state, tape)) = stack.pop() {
+            if depth >= self.depth {
+                return Decision::unknown(format!(
+                    "it may halt from a configuration {} steps back",
+                    depth
+                ));
+            }
+            seen += 1;
+            if seen > self.max_configurations {
+                return Decision::unknown(format!("went back from {} configurations", seen - 1));
+            }
+
+            for previous in &states {
+                for read in &symbols {
+                    let (_, write, motion) = match transition(repr, previous, *read) {
+                        Some(t) if t.0 == *state => t,
+                        _ => continue,
+                    };
+                    let head = back(motion);
+                    if tape.get(&head).is_some_and(|c| *c != write) {
+                        continue;
+                    }
+                    let mut tape: BTreeMap<i64, char> =
+                        tape.iter().map(|(cell, c)| (cell - head, *c)).collect();
+                    tape.insert(0, *read);
+                    stack.push((depth + 1, previous, tape));
+                }
+            }
+        }
+
+        let mut run = Run::new(repr);
+        while run.steps < self.depth && !run.tm.is_finished() {
+            run.step();
+        }
+        if run.tm.is_finished() {
+            Decision::halts(format!("halts after {} steps", run.steps))
+        } else {
+            Decision::non_halting(format!(
+                "every way back from halting dies out within {} steps",
+                self.depth
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{busy_beaver, deciders::Verdict};
+
+    fn decide(machine: &str) -> Decision {
+        BackwardReasoning::default().decide(&busy_beaver::parse(machine).unwrap())
+    }
+
+    #[test]
+    fn finds_unreachable_halting_transitions() {
+        assert_eq!(
+            decide("1RB---_1RC0LC_1LB0RB"),
+            Decision::non_halting(
+                "every way back from halting dies out within 30 steps".to_string()
+            )
+        );
+        assert_eq!(
+            decide("1RB0LB_1LA0RA"),
+            Decision::non_halting("no transition halts".to_string())
+        );
+    }
+
+    #[test]
+    fn runs_machines_halting_soon() {
+        // Nothing goes to the halting transitions of B, and nothing goes back to A
+        assert_eq!(
+            decide("1RB---_------"),
+            Decision::halts("halts after 2 steps".to_string())
+        );
+        assert_eq!(decide("1RB---_1LC1RA_0RA0LC").verdict, Verdict::Unknown);
+    }
+}
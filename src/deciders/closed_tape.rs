@@ -0,0 +1,143 @@
+use hashbrown::HashSet;
+
+use super::{transition, Decider, Decision, Repr};
+use crate::{common::Motion, machine_representation::MachineRepresentation};
+
+/// Finds machines whose reachable configurations fit in a set which never reaches a halting transition.
+///
+/// Rather than the whole tape, only the `n` cells on each side of the head are kept, along with the state and the
+/// symbol under the head. The sequences of `n` cells which can appear on each side are collected too, to tell what may
+/// come next to the head when it moves. Starting from a blank tape, everything which can follow is added until nothing
+/// new can, which covers every configuration the machine really reaches. If no halting transition is in there, the
+/// machine never halts.
+#[derive(Debug, Clone)]
+pub struct ClosedTapeLanguage {
+    /// How many cells to keep on each side of the head, at least one
+    pub n: usize,
+
+    /// How many configurations to collect before giving up
+    pub max_configurations: usize,
+}
+
+impl Default for ClosedTapeLanguage {
+    fn default() -> Self {
+        Self {
+            n: 3,
+            max_configurations: 10_000,
+        }
+    }
+}
+
+/// The state, the cells on the left of the head, the symbol under it and the cells on its right, in tape order
+type Local = (String, Vec<char>, char, Vec<char>);
+
+impl Decider for ClosedTapeLanguage {
+    fn name(&self) -> &'static str {
+        "closed-tape-language"
+    }
+
+    fn decide(&self, repr: &Repr) -> Decision {
+        if self.n == 0 {
+            return Decision::unknown("no cells are kept around the head".to_string());
+        }
+        let blanks = vec!['_'; self.n];
+        // The sequences of cells found anywhere on each side of the head
+        let mut lefts: HashSet<Vec<char>> = vec![blanks.clone()].into_iter().collect();
+        let mut rights = lefts.clone();
+        let mut locals: HashSet<Local> = HashSet::new();
+        locals.insert((repr.starting_state().clone(), blanks.clone(), '_', blanks));
+
+        loop {
+            let mut found = Vec::new();
+            for (state, left, read, right) in &locals {
+                let (next, write, motion) = match transition(repr, state, *read) {
+                    Some(t) => t,
+                    None => {
+                        return Decision::unknown(format!(
+                            "it may reach the halting transition of {} on {}",
+                            state, read
+                        ))
+                    }
+                };
+                match motion {
+                    Motion::Stay => found.push((next, left.clone(), write, right.clone())),
+                    Motion::Right => {
+                        let left: Vec<char> =
+                            left[1..].iter().cloned().chain(Some(write)).collect();
+                        for cells in rights.iter().filter(|r| r[..self.n - 1] == right[1..]) {
+                            found.push((next.clone(), left.clone(), right[0], cells.clone()));
+                        }
+                        lefts.insert(left);
+                    }
+                    Motion::Left => {
+                        let right: Vec<char> = Some(write)
+                            .into_iter()
+                            .chain(right[..self.n - 1].iter().cloned())
+                            .collect();
+                        for cells in lefts.iter().filter(|l| l[1..] == left[..self.n - 1]) {
+                            found.push((
+                                next.clone(),
+                                cells.clone(),
+                                left[self.n - 1],
+                                right.clone(),
+                            ));
+                        }
+                        rights.insert(right);
+                    }
+                }
+            }
+
+            let before = (locals.len(), lefts.len(), rights.len());
+            locals.extend(found);
+            if (locals.len(), lefts.len(), rights.len()) == before {
+                return Decision::non_halting(format!(
+                    "{} configurations of {} cells around the head are closed and never halt",
+                    locals.len(),
+                    2 * self.n + 1
+                ));
+            }
+            if locals.len() > self.max_configurations {
+                return Decision::unknown(format!(
+                    "found over {} configurations",
+                    self.max_configurations
+                ));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{busy_beaver, deciders::Verdict};
+
+    fn decide(machine: &str, n: usize) -> Decision {
+        ClosedTapeLanguage {
+            n,
+            max_configurations: 10_000,
+        }
+        .decide(&busy_beaver::parse(machine).unwrap())
+    }
+
+    #[test]
+    fn finds_closed_sets() {
+        let decision = decide("1RB1LA_1RC1RB_1LA---", 3);
+        assert_eq!(decision.verdict, Verdict::NonHalting);
+        assert!(decision
+            .justification
+            .ends_with("of 7 cells around the head are closed and never halt"));
+        assert_eq!(decide("1RB1LA_1RC1RB_1LA---", 1).verdict, Verdict::Unknown);
+        assert_eq!(
+            decide("1RB1LA_1RC1RB_1LA---", 0),
+            Decision::unknown("no cells are kept around the head".to_string())
+        );
+    }
+
+    #[test]
+    fn leaves_halting_machines() {
+        assert_eq!(
+            decide("1RB1LB_1LA1RZ", 3),
+            Decision::unknown("it may reach the halting transition of B on 1".to_string())
+        );
+    }
+}
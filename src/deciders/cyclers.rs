@@ -0,0 +1,83 @@
+use hashbrown::HashMap;
+
+use super::{Decider, Decision, Repr, Run};
+use crate::TuringMachine;
+
+/// Finds machines which come back to a configuration they were in, so repeat forever.
+/// Every configuration is kept, so this is for short runs.
+#[derive(Debug, Clone)]
+pub struct Cyclers {
+    /// How many steps to run the machine for
+    pub limit: usize,
+}
+
+impl Default for Cyclers {
+    fn default() -> Self {
+        Self { limit: 1000 }
+    }
+}
+
+impl Decider for Cyclers {
+    fn name(&self) -> &'static str {
+        "cyclers"
+    }
+
+    fn decide(&self, repr: &Repr) -> Decision {
+        let mut run = Run::new(repr);
+        let mut seen = HashMap::new();
+        loop {
+            if run.tm.is_finished() {
+                return Decision::halts(format!("halts after {} steps", run.steps));
+            }
+            let (start, written) = run.written();
+            let configuration = (
+                run.tm.current_state().clone(),
+                run.head(),
+                start,
+                written.to_vec(),
+            );
+            if let Some(before) = seen.insert(configuration, run.steps) {
+                return Decision::non_halting(format!(
+                    "the configuration after {} steps comes back after {}",
+                    before, run.steps
+                ));
+            }
+            if run.steps >= self.limit {
+                return Decision::unknown(format!(
+                    "no configuration repeats in {} steps",
+                    run.steps
+                ));
+            }
+            run.step();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{busy_beaver, deciders::Verdict};
+
+    fn decide(machine: &str, limit: usize) -> Decision {
+        Cyclers { limit }.decide(&busy_beaver::parse(machine).unwrap())
+    }
+
+    #[test]
+    fn finds_cycles() {
+        // Goes back and forth between two cells
+        assert_eq!(
+            decide("0RB---_0LA---", 100),
+            Decision::non_halting("the configuration after 0 steps comes back after 2".to_string())
+        );
+
+        // Keeps moving right, which is not a cycle
+        assert_eq!(decide("1RA---", 100).verdict, Verdict::Unknown);
+    }
+
+    #[test]
+    fn finds_halting_machines() {
+        let decision = decide("1RB1LB_1LA1RZ", 100);
+        assert_eq!(decision, Decision::halts("halts after 6 steps".to_string()));
+        assert_eq!(decide("1RB1LB_1LA1RZ", 5).verdict, Verdict::Unknown);
+    }
+}
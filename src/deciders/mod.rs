@@ -0,0 +1,320 @@
+//! Deciders proving whether machines halt from a blank tape, for the machines left undecided by
+//! [`enumeration`](../enumeration/index.html).
+//!
+//! Each [`Decider`](trait.Decider.html) looks at a machine on its own and answers with a [`Decision`](struct.Decision.html),
+//! which is [`Unknown`](enum.Verdict.html#variant.Unknown) whenever it cannot tell. A [`Pipeline`](struct.Pipeline.html)
+//! tries deciders in order until one can tell, and collects the answers in a [`Report`](struct.Report.html).
+//!
+//! As in the busy beaver literature, the tape is infinite both ways, and the machine halts when it goes to a final
+//! state or reaches an undefined transition.
+
+mod backward;
+mod closed_tape;
+mod cyclers;
+mod translated_cyclers;
+
+pub use self::backward::BackwardReasoning;
+pub use self::closed_tape::ClosedTapeLanguage;
+pub use self::cyclers::Cyclers;
+pub use self::translated_cyclers::TranslatedCyclers;
+
+use std::{
+    fmt,
+    io::{self, Write},
+};
+
+use crate::{
+    builders::TuringMachineBuilder,
+    busy_beaver,
+    common::Motion,
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+    TuringMachine,
+};
+
+type Repr = DeterministicMachineRepresentation<String>;
+
+/// Whether a machine halts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Verdict {
+    Halts,
+    NonHalting,
+    Unknown,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Halts => write!(f, "halts"),
+            Verdict::NonHalting => write!(f, "non-halting"),
+            Verdict::Unknown => write!(f, "unknown"),
+        }
+    }
+}
+
+/// A verdict, and why it was given
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Decision {
+    pub verdict: Verdict,
+    pub justification: String,
+}
+
+impl Decision {
+    pub fn halts(justification: String) -> Self {
+        Self {
+            verdict: Verdict::Halts,
+            justification,
+        }
+    }
+
+    pub fn non_halting(justification: String) -> Self {
+        Self {
+            verdict: Verdict::NonHalting,
+            justification,
+        }
+    }
+
+    pub fn unknown(justification: String) -> Self {
+        Self {
+            verdict: Verdict::Unknown,
+            justification,
+        }
+    }
+}
+
+/// A way of telling whether a machine halts from a blank tape
+pub trait Decider {
+    /// A short name for the reports
+    fn name(&self) -> &'static str;
+
+    fn decide(&self, repr: &Repr) -> Decision;
+}
+
+/// What a machine does in a state on a symbol, or `None` if it halts there
+fn transition(repr: &Repr, state: &str, symbol: char) -> Option<(String, char, Motion)> {
+    repr.transition_table()
+        .apply_transition_table(&state.to_string(), symbol)
+        .filter(|act| !repr.state_kind(act.next_state()).is_final())
+        .map(|act| (act.next_state().clone(), *act.tape_output(), *act.motion()))
+}
+
+/// The symbols of a machine, blank first then in order
+fn symbols(repr: &Repr) -> Vec<char> {
+    let mut symbols: Vec<char> = repr
+        .alphabet()
+        .iter()
+        .cloned()
+        .filter(|c| *c != '_')
+        .collect();
+    symbols.sort();
+    symbols.insert(0, '_');
+    symbols
+}
+
+/// The states of a machine which are not final, in name order
+fn running_states(repr: &Repr) -> Vec<&String> {
+    let mut states: Vec<&String> = repr
+        .states()
+        .iter()
+        .filter(|s| !repr.state_kind(s).is_final())
+        .collect();
+    states.sort();
+    states
+}
+
+/// A machine running on a blank tape infinite both ways, which knows where the cell it started on is
+struct Run {
+    tm: DeterministicTuringMachine<String>,
+    /// Where the starting cell is in the tape, which moves as room is made on the left
+    origin: usize,
+    steps: usize,
+}
+
+impl Run {
+    fn new(repr: &Repr) -> Self {
+        Self {
+            tm: DeterministicTuringMachine::from_builder(
+                TuringMachineBuilder::new()
+                    .repr(repr.clone())
+                    .tape(Vec::new()),
+            )
+            .expect("A blank tape is always valid"),
+            origin: 0,
+            steps: 0,
+        }
+    }
+
+    fn step(&mut self) {
+        if self.tm.is_finished() {
+            return;
+        }
+        self.origin += busy_beaver::make_room(&mut self.tm);
+        self.tm.step();
+        self.steps += 1;
+    }
+
+    /// Where the head is, from the starting cell
+    fn head(&self) -> i64 {
+        self.tm.head() as i64 - self.origin as i64
+    }
+
+    /// Read a cell, from the starting cell
+    fn read(&self, cell: i64) -> char {
+        let index = cell + self.origin as i64;
+        if index < 0 {
+            '_'
+        } else {
+            self.tm.tape().get(index as usize).cloned().unwrap_or('_')
+        }
+    }
+
+    /// Where the written part of the tape starts, from the starting cell, and what it holds
+    fn written(&self) -> (i64, &[char]) {
+        let tape = self.tm.tape();
+        match tape.iter().position(|c| *c != '_') {
+            Some(start) => {
+                let end = tape.iter().rposition(|c| *c != '_').unwrap() + 1;
+                (start as i64 - self.origin as i64, &tape[start..end])
+            }
+            None => (0, &[]),
+        }
+    }
+}
+
+/// Deciders tried one after the other, until one can tell
+pub struct Pipeline {
+    deciders: Vec<Box<dyn Decider>>,
+}
+
+impl Default for Pipeline {
+    /// Every decider of this module with its default bounds, the cheapest first
+    fn default() -> Self {
+        Self::new()
+            .with(Cyclers::default())
+            .with(TranslatedCyclers::default())
+            .with(BackwardReasoning::default())
+            .with(ClosedTapeLanguage::default())
+    }
+}
+
+impl Pipeline {
+    /// A pipeline without deciders, to add them with [`with`](#method.with)
+    pub fn new() -> Self {
+        Self {
+            deciders: Vec::new(),
+        }
+    }
+
+    pub fn with(mut self, decider: impl Decider + 'static) -> Self {
+        self.deciders.push(Box::new(decider));
+        self
+    }
+
+    /// Try the deciders in order, returning the first decision which is not unknown along with the name of its decider
+    pub fn decide(&self, repr: &Repr) -> Option<(&'static str, Decision)> {
+        self.deciders
+            .iter()
+            .map(|d| (d.name(), d.decide(repr)))
+            .find(|(_, decision)| decision.verdict != Verdict::Unknown)
+    }
+
+    /// Decide every machine, named as they should be in the report
+    pub fn report(&self, machines: impl IntoIterator<Item = (String, Repr)>) -> Report {
+        Report {
+            entries: machines
+                .into_iter()
+                .map(|(name, repr)| {
+                    let decision = self.decide(&repr);
+                    (name, decision)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// The decisions taken on a list of machines
+#[derive(Debug)]
+pub struct Report {
+    /// Each machine, with the decider which could tell and its decision, if any
+    pub entries: Vec<(String, Option<(&'static str, Decision)>)>,
+}
+
+impl Report {
+    /// How many machines got the verdict
+    pub fn count(&self, verdict: Verdict) -> usize {
+        self.entries
+            .iter()
+            .filter(|(_, d)| d.as_ref().map_or(Verdict::Unknown, |(_, d)| d.verdict) == verdict)
+            .count()
+    }
+
+    /// Write a line per machine, with its verdict, the decider and the justification separated by tabs
+    pub fn write(&self, mut w: impl Write) -> io::Result<()> {
+        for (machine, decision) in &self.entries {
+            match decision {
+                Some((decider, decision)) => writeln!(
+                    w,
+                    "{}\t{}\t{}\t{}",
+                    machine, decision.verdict, decider, decision.justification
+                )?,
+                None => writeln!(w, "{}\t{}\t-\t-", machine, Verdict::Unknown)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tries_deciders_in_order() {
+        let machines = [
+            "1RB1LB_1LA1RZ",
+            "0RB---_0LA---",
+            "1RA---",
+            "1RB---_1RC0LC_1LB0RB",
+            "1RB1LA_1RC1RB_1LA---",
+            "1RB---_1LC1RA_0RA0LC",
+        ];
+        let report = Pipeline::default().report(
+            machines
+                .iter()
+                .map(|m| (m.to_string(), busy_beaver::parse(m).unwrap())),
+        );
+        let deciders: Vec<Option<&str>> = report
+            .entries
+            .iter()
+            .map(|(_, d)| d.as_ref().map(|(name, _)| *name))
+            .collect();
+        assert_eq!(
+            deciders,
+            vec![
+                Some("cyclers"),
+                Some("cyclers"),
+                Some("translated-cyclers"),
+                Some("backward-reasoning"),
+                Some("closed-tape-language"),
+                None,
+            ]
+        );
+        assert_eq!(report.count(Verdict::Halts), 1);
+        assert_eq!(report.count(Verdict::NonHalting), 4);
+        assert_eq!(report.count(Verdict::Unknown), 1);
+
+        let mut written = Vec::new();
+        report.write(&mut written).unwrap();
+        let written = String::from_utf8(written).unwrap();
+        assert!(written.starts_with("1RB1LB_1LA1RZ\thalts\tcyclers\t"));
+        assert!(written.ends_with("1RB---_1LC1RA_0RA0LC\tunknown\t-\t-\n"));
+        assert_eq!(written.lines().count(), 6);
+
+        let report = Pipeline::new().report(vec![(
+            machines[1].to_string(),
+            busy_beaver::parse(machines[1]).unwrap(),
+        )]);
+        assert_eq!(report.count(Verdict::Unknown), 1);
+    }
+}
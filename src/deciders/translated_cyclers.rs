@@ -0,0 +1,163 @@
+use super::{Decider, Decision, Repr, Run};
+use crate::TuringMachine;
+
+/// Finds machines which repeat the same steps further and further along the tape, as one going right forever over blanks.
+///
+/// The configurations where the head is on a cell it never visited before, a record, are kept. When two records to the
+/// right are in the same state, and between them the head never went left of some cell, the machine only read the
+/// cells from there on. If those cells hold the same at both records, shifted as far as the head went, the machine
+/// does the same again from the second record, and so on forever. Records to the left work the same way.
+#[derive(Debug, Clone)]
+pub struct TranslatedCyclers {
+    /// How many steps to run the machine for
+    pub limit: usize,
+}
+
+impl Default for TranslatedCyclers {
+    fn default() -> Self {
+        Self { limit: 1000 }
+    }
+}
+
+/// A configuration with the head on a new cell
+struct Record {
+    steps: usize,
+    state: String,
+    head: i64,
+    start: i64,
+    tape: Vec<char>,
+}
+
+impl Record {
+    fn new(run: &Run) -> Self {
+        let (start, tape) = run.written();
+        Self {
+            steps: run.steps,
+            state: run.tm.current_state().clone(),
+            head: run.head(),
+            start,
+            tape: tape.to_vec(),
+        }
+    }
+
+    fn read(&self, cell: i64) -> char {
+        if cell < self.start {
+            return '_';
+        }
+        self.tape
+            .get((cell - self.start) as usize)
+            .cloned()
+            .unwrap_or('_')
+    }
+}
+
+/// Look for an earlier record the run repeats from.
+/// `direction` is 1 for records to the right and -1 for records to the left, where everything is mirrored.
+fn find_repeat(records: &[Record], run: &Run, heads: &[i64], direction: i64) -> Option<Decision> {
+    let state = run.tm.current_state();
+    let head = run.head();
+
+    // The furthest the head went back, from the latest record to the current step
+    let mut furthest = head;
+    let mut step = run.steps;
+    for record in records.iter().rev() {
+        while step > record.steps {
+            step -= 1;
+            furthest = if direction > 0 {
+                furthest.min(heads[step])
+            } else {
+                furthest.max(heads[step])
+            };
+        }
+        if record.state != *state {
+            continue;
+        }
+
+        let shift = head - record.head;
+        let same = (0..=(record.head - furthest) * direction)
+            .map(|i| record.head - i * direction)
+            .all(|cell| record.read(cell) == run.read(cell + shift));
+        if same {
+            return Some(Decision::non_halting(format!(
+                "from step {} it repeats every {} steps, moving {} cells {}",
+                record.steps,
+                run.steps - record.steps,
+                shift.abs(),
+                if direction > 0 { "right" } else { "left" }
+            )));
+        }
+    }
+    None
+}
+
+impl Decider for TranslatedCyclers {
+    fn name(&self) -> &'static str {
+        "translated-cyclers"
+    }
+
+    fn decide(&self, repr: &Repr) -> Decision {
+        let mut run = Run::new(repr);
+        let mut heads = vec![run.head()];
+        let mut right = vec![Record::new(&run)];
+        let mut left = vec![Record::new(&run)];
+        let (mut max, mut min) = (0, 0);
+        loop {
+            if run.tm.is_finished() {
+                return Decision::halts(format!("halts after {} steps", run.steps));
+            }
+            if run.steps >= self.limit {
+                return Decision::unknown(format!("no records repeat in {} steps", run.steps));
+            }
+            run.step();
+            let head = run.head();
+            heads.push(head);
+
+            if head > max {
+                max = head;
+                if let Some(decision) = find_repeat(&right, &run, &heads, 1) {
+                    return decision;
+                }
+                right.push(Record::new(&run));
+            }
+            if head < min {
+                min = head;
+                if let Some(decision) = find_repeat(&left, &run, &heads, -1) {
+                    return decision;
+                }
+                left.push(Record::new(&run));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{busy_beaver, deciders::Verdict};
+
+    fn decide(machine: &str) -> Decision {
+        TranslatedCyclers { limit: 1000 }.decide(&busy_beaver::parse(machine).unwrap())
+    }
+
+    #[test]
+    fn finds_machines_going_away() {
+        assert_eq!(
+            decide("1RA---"),
+            Decision::non_halting(
+                "from step 0 it repeats every 1 steps, moving 1 cells right".to_string()
+            )
+        );
+
+        // Writes 1 0 1 0 ... going left
+        let decision = decide("1LB---_0LA---");
+        assert_eq!(decision.verdict, Verdict::NonHalting);
+        assert!(decision.justification.ends_with("moving 2 cells left"));
+    }
+
+    #[test]
+    fn leaves_other_machines() {
+        // Goes back over all it wrote every time, so its records never repeat
+        assert_eq!(decide("1RB1LA_1LA1RB").verdict, Verdict::Unknown);
+        assert_eq!(decide("1RB1LB_1LA1RZ").verdict, Verdict::Halts);
+    }
+}
@@ -1,125 +1,180 @@
-pub mod transition_table;
-
-use log::debug;
-use std::fmt;
-use transition_table::DeterministicTransitionTable;
-
-use crate::{
-    common::{representation::GeneralMachineRepresentation, Action, Motion, StateTrait},
-    machine_representation::MachineRepresentation,
-    transition_table::TransitionTable,
-    utils::apply_action,
-    TuringMachine, TuringMachineBuilder,
-};
-
-pub type DeterministicMachineRepresentation<StateTy> =
-    GeneralMachineRepresentation<StateTy, DeterministicTransitionTable<StateTy>>;
-
-/// Struct representing a TM with deterministic behaviour, singly infinite tape and variable alphabet  
-/// This is (almost) the most basic TM that one can conceive.
-#[derive(Debug)]
-pub struct DeterministicTuringMachine<StateTy>
-where
-    StateTy: StateTrait,
-{
-    tape: Vec<char>,
-    representation: DeterministicMachineRepresentation<StateTy>,
-    current_cell: usize,
-    current_state: StateTy,
-}
-
-#[derive(Debug)]
-pub enum MachineCreationError {
-    TapeAlphabetMismatch,
-}
-
-impl<StateTy> TuringMachine for DeterministicTuringMachine<StateTy>
-where
-    StateTy: StateTrait,
-{
-    type Tape = Vec<char>;
-    type StateTy = StateTy;
-    type ReprTy = DeterministicMachineRepresentation<StateTy>;
-    type ErrorTy = MachineCreationError;
-
-    fn from_builder(
-        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
-    ) -> Result<Self, Self::ErrorTy> {
-        // TODO proper validation
-        let (tape, repr) = builder
-            .validate()
-            .ok_or(MachineCreationError::TapeAlphabetMismatch)?
-            .decompose();
-
-        Ok(Self {
-            tape,
-            current_state: repr.starting_state().clone(),
-            representation: repr,
-            current_cell: 0,
-        })
-    }
-
-    fn step(&mut self) {
-        if self.is_accepting() || self.is_rejecting() {
-            return;
-        }
-
-        let input_char = self.tape.get(self.current_cell).unwrap_or(&'_');
-
-        debug!(
-            "Read {} while in state {:?}",
-            input_char, self.current_state
-        );
-        let action = self
-            .representation
-            .transition_table()
-            .apply_transition_table(&self.current_state, *input_char)
-            .unwrap_or_else(|| {
-                Action::new(
-                    self.representation.rejecting_state().clone(),
-                    *input_char,
-                    Motion::Left,
-                )
-            });
-
-        apply_action(
-            action,
-            &mut self.tape,
-            &mut self.current_cell,
-            &mut self.current_state,
-        );
-    }
-
-    fn tape(&self) -> &Self::Tape {
-        &self.tape
-    }
-
-    fn is_accepting(&self) -> bool {
-        &self.current_state == self.representation.accepting_state()
-    }
-
-    fn is_rejecting(&self) -> bool {
-        &self.current_state == self.representation.rejecting_state()
-    }
-}
-
-impl<StateTy> fmt::Display for DeterministicTuringMachine<StateTy>
-where
-    StateTy: StateTrait + fmt::Display,
-{
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        let s = self
-            .tape
-            .iter()
-            .collect::<String>()
-            .trim_end_matches('_')
-            .to_string();
-        if s == "" {
-            writeln!(f, "_")?;
-            return Ok(());
-        }
-        writeln!(f, "{}", s)?;
-
-        Ok(())
-    }
-}
+pub mod transition_table;
+
+use log::debug;
+use std::fmt;
+use transition_table::DeterministicTransitionTable;
+
+use crate::{
+    builders::ValidationError,
+    common::{representation::GeneralMachineRepresentation, Action, Motion, StateTrait},
+    machine_representation::MachineRepresentation,
+    tape::Tape,
+    transition_table::TransitionTable,
+    utils::apply_action,
+    TuringMachine, TuringMachineBuilder,
+};
+
+pub type DeterministicMachineRepresentation<StateTy> =
+    GeneralMachineRepresentation<StateTy, DeterministicTransitionTable<StateTy>>;
+
+/// Struct representing a TM with deterministic behaviour, singly infinite tape and variable alphabet  
+/// This is (almost) the most basic TM that one can conceive.  
+/// The tape is stored in a `Vec<char>` unless another [`Tape`](../tape/trait.Tape.html) is asked for.
+#[derive(Debug)]
+pub struct DeterministicTuringMachine<StateTy, TapeTy = Vec<char>>
+where
+    StateTy: StateTrait,
+    TapeTy: Tape,
+{
+    tape: TapeTy,
+    representation: DeterministicMachineRepresentation<StateTy>,
+    current_cell: usize,
+    current_state: StateTy,
+}
+
+#[derive(Debug)]
+pub enum MachineCreationError {
+    InvalidBuilder(ValidationError<char>),
+}
+
+impl From<ValidationError<char>> for MachineCreationError {
+    fn from(err: ValidationError<char>) -> Self {
+        MachineCreationError::InvalidBuilder(err)
+    }
+}
+
+impl fmt::Display for MachineCreationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MachineCreationError::InvalidBuilder(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl<StateTy, TapeTy> DeterministicTuringMachine<StateTy, TapeTy>
+where
+    StateTy: StateTrait,
+    TapeTy: Tape,
+{
+    /// Get the position of the head on the tape
+    pub fn head(&self) -> usize {
+        self.current_cell
+    }
+
+    /// Get the state the machine is currently in
+    pub fn current_state(&self) -> &StateTy {
+        &self.current_state
+    }
+
+    /// Get the representation the machine is running
+    pub fn representation(&self) -> &DeterministicMachineRepresentation<StateTy> {
+        &self.representation
+    }
+
+    /// Get mutable access to the configuration, in the order [`apply_action`](../utils/fn.apply_action.html) takes it
+    pub(crate) fn configuration_mut(&mut self) -> (&mut TapeTy, &mut usize, &mut StateTy) {
+        (
+            &mut self.tape,
+            &mut self.current_cell,
+            &mut self.current_state,
+        )
+    }
+}
+
+impl<StateTy, TapeTy> TuringMachine for DeterministicTuringMachine<StateTy, TapeTy>
+where
+    StateTy: StateTrait,
+    TapeTy: Tape,
+{
+    type Tape = TapeTy;
+    type StateTy = StateTy;
+    type ReprTy = DeterministicMachineRepresentation<StateTy>;
+    type ErrorTy = MachineCreationError;
+
+    fn from_builder(
+        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
+    ) -> Result<Self, Self::ErrorTy> {
+        let (tape, repr) = builder.validate()?.decompose();
+
+        Ok(Self {
+            tape: tape.into(),
+            current_state: repr.starting_state().clone(),
+            representation: repr,
+            current_cell: 0,
+        })
+    }
+
+    fn step(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+
+        let input_char = self.tape.read(self.current_cell);
+
+        debug!(
+            "Read {} while in state {:?}",
+            input_char, self.current_state
+        );
+        let action = self
+            .representation
+            .transition_table()
+            .apply_transition_table(&self.current_state, input_char)
+            .unwrap_or_else(|| {
+                // Missing transitions implicitly move to a rejecting state
+                let rejecting_state = self.representation.implicit_reject().clone();
+                Action::new(rejecting_state, input_char, Motion::Left)
+            });
+
+        apply_action(
+            action,
+            &mut self.tape,
+            &mut self.current_cell,
+            &mut self.current_state,
+        );
+    }
+
+    fn tape(&self) -> &Self::Tape {
+        &self.tape
+    }
+
+    fn is_accepting(&self) -> bool {
+        self.representation
+            .accepting_states()
+            .contains(&self.current_state)
+    }
+
+    fn is_rejecting(&self) -> bool {
+        self.representation
+            .rejecting_states()
+            .contains(&self.current_state)
+    }
+
+    fn is_halting(&self) -> bool {
+        self.representation
+            .halting_states()
+            .contains(&self.current_state)
+    }
+}
+
+impl<StateTy, TapeTy> fmt::Display for DeterministicTuringMachine<StateTy, TapeTy>
+where
+    StateTy: StateTrait + fmt::Display,
+    TapeTy: Tape,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Written run by run, so that long runs are never expanded in memory
+        let runs = self.tape.runs();
+        if runs.is_empty() {
+            writeln!(f, "_")?;
+            return Ok(());
+        }
+        for run in runs {
+            for _ in 0..run.len {
+                write!(f, "{}", run.symbol)?;
+            }
+        }
+        writeln!(f)?;
+
+        Ok(())
+    }
+}
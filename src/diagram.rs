@@ -0,0 +1,403 @@
+//! Space-time diagrams of [`DeterministicTuringMachine`](../deterministic_tm/struct.DeterministicTuringMachine.html) runs.
+//!
+//! Each row of the diagram is a configuration and each column a tape cell. Symbols are coloured, and the cell under the
+//! head is marked with a colour depending on the state. The rows are collected by a [`SpaceTimeRecorder`](struct.SpaceTimeRecorder.html),
+//! a [`StepHook`](../hooked/trait.StepHook.html), and can then be written out as PNG, plain PPM or SVG.
+
+use hashbrown::HashMap;
+use std::{
+    io::{self, Write},
+    ops::Range,
+};
+
+use crate::{
+    common::StateTrait, deterministic_tm::DeterministicTuringMachine, hooked::StepHook,
+    TuringMachine,
+};
+
+/// The colours symbols and states are drawn with, the blank is always white
+const PALETTE: [[u8; 3]; 12] = [
+    [31, 119, 180],
+    [255, 127, 14],
+    [44, 160, 44],
+    [214, 39, 40],
+    [148, 103, 189],
+    [140, 86, 75],
+    [227, 119, 194],
+    [127, 127, 127],
+    [188, 189, 34],
+    [23, 190, 207],
+    [0, 0, 0],
+    [255, 215, 0],
+];
+
+const BLANK: [u8; 3] = [255, 255, 255];
+
+/// A single configuration, restricted to the cells in the window
+#[derive(Debug, Clone)]
+struct Row {
+    cells: Vec<char>,
+    head: usize,
+    state: usize,
+}
+
+/// A [`StepHook`](../hooked/trait.StepHook.html) recording the configurations inside a window of steps and cells
+#[derive(Debug, Clone, Default)]
+pub struct SpaceTimeRecorder<StateTy>
+where
+    StateTy: StateTrait,
+{
+    steps: Option<Range<usize>>,
+    cells: Option<Range<usize>>,
+    step: usize,
+    states: Vec<StateTy>,
+    rows: Vec<Row>,
+}
+
+impl<StateTy> SpaceTimeRecorder<StateTy>
+where
+    StateTy: StateTrait,
+{
+    /// Record every step and every cell
+    pub fn new() -> Self {
+        Self {
+            steps: None,
+            cells: None,
+            step: 0,
+            states: Vec::new(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Only record the configurations after the steps in the range, step 0 being the starting configuration
+    pub fn steps(mut self, steps: Range<usize>) -> Self {
+        self.steps = Some(steps);
+        self
+    }
+
+    /// Only record the cells in the range
+    pub fn cells(mut self, cells: Range<usize>) -> Self {
+        self.cells = Some(cells);
+        self
+    }
+
+    /// Turn the recorded rows into a diagram
+    pub fn diagram(&self) -> SpaceTimeDiagram {
+        let offset = self.cells.as_ref().map_or(0, |c| c.start);
+        let width = match &self.cells {
+            Some(cells) => cells.end.saturating_sub(cells.start),
+            None => self
+                .rows
+                .iter()
+                .map(|r| r.cells.len().max(r.head + 1))
+                .max()
+                .unwrap_or(0),
+        };
+
+        // Give symbols colours in a stable order
+        let mut symbols: Vec<char> = self
+            .rows
+            .iter()
+            .flat_map(|r| r.cells.iter().cloned())
+            .filter(|c| *c != '_')
+            .collect();
+        symbols.sort();
+        symbols.dedup();
+        let colours = symbols
+            .into_iter()
+            .enumerate()
+            .map(|(i, c)| (c, PALETTE[i % PALETTE.len()]))
+            .collect();
+
+        SpaceTimeDiagram {
+            width,
+            offset,
+            rows: self.rows.clone(),
+            colours,
+            states: self
+                .states
+                .iter()
+                .map(|s| format!("{:?}", s).trim_matches('"').to_string())
+                .collect(),
+        }
+    }
+}
+
+impl<StateTy> StepHook<DeterministicTuringMachine<StateTy>> for SpaceTimeRecorder<StateTy>
+where
+    StateTy: StateTrait,
+{
+    fn on_step(&mut self, tm: &DeterministicTuringMachine<StateTy>) {
+        let step = self.step;
+        self.step += 1;
+        if self.steps.as_ref().is_some_and(|s| !s.contains(&step)) {
+            return;
+        }
+
+        let state = match self.states.iter().position(|s| s == tm.current_state()) {
+            Some(i) => i,
+            None => {
+                self.states.push(tm.current_state().clone());
+                self.states.len() - 1
+            }
+        };
+
+        let tape = tm.tape();
+        let cells = match &self.cells {
+            Some(cells) => (cells.start..cells.end)
+                .map(|i| *tape.get(i).unwrap_or(&'_'))
+                .collect(),
+            None => tape.clone(),
+        };
+
+        self.rows.push(Row {
+            cells,
+            head: tm.head(),
+            state,
+        });
+    }
+}
+
+/// A recorded run, ready to be drawn
+#[derive(Debug, Clone)]
+pub struct SpaceTimeDiagram {
+    width: usize,
+    offset: usize,
+    rows: Vec<Row>,
+    colours: HashMap<char, [u8; 3]>,
+    states: Vec<String>,
+}
+
+impl SpaceTimeDiagram {
+    /// The number of configurations in the diagram
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// The number of cells in each configuration
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    fn symbol_colour(&self, c: char) -> [u8; 3] {
+        self.colours.get(&c).cloned().unwrap_or(BLANK)
+    }
+
+    fn state_colour(state: usize) -> [u8; 3] {
+        // Go backwards through the palette, so states and symbols rarely clash
+        PALETTE[PALETTE.len() - 1 - state % PALETTE.len()]
+    }
+
+    /// The column the head is on, if it is inside the window
+    fn head_column(&self, row: &Row) -> Option<usize> {
+        row.head
+            .checked_sub(self.offset)
+            .filter(|col| *col < self.width)
+    }
+
+    /// Rasterise to RGB, drawing each cell as a `scale x scale` square
+    fn raster(&self, scale: usize) -> Vec<u8> {
+        let scale = scale.max(1);
+        let row_len = self.width * scale * 3;
+        let mut pixels = Vec::with_capacity(row_len * self.rows.len() * scale);
+
+        for row in &self.rows {
+            let head = self.head_column(row);
+            for y in 0..scale {
+                for col in 0..self.width {
+                    let symbol = self.symbol_colour(*row.cells.get(col).unwrap_or(&'_'));
+                    let state = Self::state_colour(row.state);
+                    for x in 0..scale {
+                        // The head is drawn as a square in the middle of the cell, or the full cell if too small
+                        let inner = scale < 3 || (x > 0 && x < scale - 1 && y > 0 && y < scale - 1);
+                        let colour = if head == Some(col) && inner {
+                            state
+                        } else {
+                            symbol
+                        };
+                        pixels.extend_from_slice(&colour);
+                    }
+                }
+            }
+        }
+        pixels
+    }
+
+    /// Write as a plain (ASCII) PPM image
+    pub fn write_ppm(&self, scale: usize, mut w: impl Write) -> io::Result<()> {
+        let scale = scale.max(1);
+        writeln!(w, "P3")?;
+        writeln!(w, "{} {}", self.width * scale, self.rows.len() * scale)?;
+        writeln!(w, "255")?;
+        for line in self.raster(scale).chunks((self.width * scale * 3).max(1)) {
+            let values: Vec<String> = line.iter().map(u8::to_string).collect();
+            writeln!(w, "{}", values.join(" "))?;
+        }
+        Ok(())
+    }
+
+    /// Write as a PNG image
+    pub fn write_png(&self, scale: usize, w: impl Write) -> io::Result<()> {
+        let scale = scale.max(1);
+        let mut encoder = png::Encoder::new(
+            w,
+            (self.width * scale) as u32,
+            (self.rows.len() * scale) as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.raster(scale)))
+            .map_err(io::Error::other)
+    }
+
+    /// Write as an SVG image, where every cell shows its symbol and the head is outlined
+    pub fn write_svg(&self, scale: usize, mut w: impl Write) -> io::Result<()> {
+        let scale = scale.max(1);
+        writeln!(
+            w,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" font-family="monospace" font-size="{}">"#,
+            self.width * scale,
+            self.rows.len() * scale,
+            scale * 3 / 4
+        )?;
+        for (y, row) in self.rows.iter().enumerate() {
+            for col in 0..self.width {
+                let symbol = *row.cells.get(col).unwrap_or(&'_');
+                let [r, g, b] = self.symbol_colour(symbol);
+                writeln!(
+                    w,
+                    r#"<rect x="{}" y="{}" width="{s}" height="{s}" fill="rgb({},{},{})"/>"#,
+                    col * scale,
+                    y * scale,
+                    r,
+                    g,
+                    b,
+                    s = scale
+                )?;
+                if symbol != '_' {
+                    writeln!(
+                        w,
+                        r#"<text x="{}" y="{}" text-anchor="middle">{}</text>"#,
+                        col * scale + scale / 2,
+                        y * scale + scale * 3 / 4,
+                        escape(&symbol.to_string())
+                    )?;
+                }
+            }
+            if let Some(col) = self.head_column(row) {
+                let [r, g, b] = Self::state_colour(row.state);
+                writeln!(
+                    w,
+                    r#"<rect x="{}" y="{}" width="{s}" height="{s}" fill="none" stroke="rgb({},{},{})" stroke-width="2"><title>{}</title></rect>"#,
+                    col * scale,
+                    y * scale,
+                    r,
+                    g,
+                    b,
+                    escape(&self.states[row.state]),
+                    s = scale
+                )?;
+            }
+        }
+        writeln!(w, "</svg>")
+    }
+}
+
+/// Escape text for SVG, as symbols and state names may hold `<`, `>` or `&`
+fn escape(s: &str) -> String {
+    s.chars()
+        .map(|c| match c {
+            '<' => "&lt;".to_string(),
+            '>' => "&gt;".to_string(),
+            '&' => "&amp;".to_string(),
+            _ => c.to_string(),
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder, deterministic_tm::DeterministicMachineRepresentation,
+        hooked::HookedTuringMachineExt, machine_parser,
+        machine_representation::MachineRepresentation, TuringMachine,
+    };
+
+    fn record(tape: &str, recorder: SpaceTimeRecorder<String>) -> SpaceTimeDiagram {
+        record_machine(include_str!("../data/paren.tm"), tape, recorder)
+    }
+
+    fn record_machine(
+        source: &str,
+        tape: &str,
+        recorder: SpaceTimeRecorder<String>,
+    ) -> SpaceTimeDiagram {
+        let parser = machine_parser::parse(source.as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let machine = DeterministicTuringMachine::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape(tape.chars().collect()),
+        )
+        .unwrap();
+
+        let mut machine = HookedTuringMachineExt::new(machine, recorder);
+        machine.run();
+        machine.hook().diagram()
+    }
+
+    #[test]
+    fn records_every_step() {
+        let diagram = record("()", SpaceTimeRecorder::new());
+        // The CLI reports 6 steps, plus the starting configuration and the final step
+        assert_eq!(diagram.height(), 8);
+        assert!(diagram.width() >= 2);
+    }
+
+    #[test]
+    fn records_window() {
+        let diagram = record("(())", SpaceTimeRecorder::new().steps(2..5).cells(1..3));
+        assert_eq!(diagram.height(), 3);
+        assert_eq!(diagram.width(), 2);
+
+        let mut ppm = Vec::new();
+        diagram.write_ppm(2, &mut ppm).unwrap();
+        let ppm = String::from_utf8(ppm).unwrap();
+        let mut lines = ppm.lines();
+        assert_eq!(lines.next(), Some("P3"));
+        assert_eq!(lines.next(), Some("4 6"));
+        assert_eq!(lines.next(), Some("255"));
+        assert_eq!(lines.count(), 6);
+    }
+
+    #[test]
+    fn writes_png_and_svg() {
+        let diagram = record("(())", SpaceTimeRecorder::new());
+
+        let mut png = Vec::new();
+        diagram.write_png(4, &mut png).unwrap();
+        assert_eq!(&png[1..4], b"PNG");
+
+        let mut svg = Vec::new();
+        diagram.write_svg(10, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+    }
+
+    #[test]
+    fn escapes_svg_text() {
+        let source = "states 3\nq<&>\nqa +\nqr -\nalphabet 1 <\nq<&> < qa < R";
+        let diagram = record_machine(source, "<", SpaceTimeRecorder::new());
+
+        let mut svg = Vec::new();
+        diagram.write_svg(10, &mut svg).unwrap();
+        let svg = String::from_utf8(svg).unwrap();
+        assert!(svg.contains("<title>q&lt;&amp;&gt;</title>"));
+        assert!(svg.contains(r#"text-anchor="middle">&lt;</text>"#));
+        assert!(!svg.contains("q<&>"));
+    }
+}
@@ -0,0 +1,334 @@
+//! Enumeration of busy beaver candidates in tree normal form.
+//!
+//! Rather than going through every transition table, machines start with every transition undefined and are run on a
+//! blank tape. When the run reaches an undefined transition, the machine is forked into one machine per way to define
+//! it, each carrying on from there. One of them defines it to halt, which gives a halting machine right away. So only
+//! transitions which are ever used get defined, and machines differing only on the others are run once.
+//!
+//! Renamings of the states are cut by only going to states which already appear in the table, or to the first one which
+//! does not. Mirror images are cut by only moving right on the first transition. The last undefined transition of a
+//! machine is only ever defined to halt.
+//!
+//! Machines are run on a tape infinite both ways, as [`busy_beaver::run`](../busy_beaver/fn.run.html) does, up to a
+//! step limit. Machines which come back to a configuration they were in are known to never halt. The rest of those
+//! reaching the limit are left undecided.
+
+use crate::{
+    builders::TuringMachineBuilder,
+    busy_beaver::{self, Score, HALT, STATES, SYMBOLS},
+    common::Motion,
+    deterministic_tm::DeterministicTuringMachine,
+    TuringMachine,
+};
+
+/// A transition table being filled in, indexed by state then symbol
+#[derive(Debug, Clone)]
+struct Table {
+    symbols: usize,
+    transitions: Vec<Option<(usize, Motion, Option<usize>)>>,
+}
+
+impl Table {
+    fn new(states: usize, symbols: usize) -> Self {
+        Self {
+            symbols,
+            transitions: vec![None; states * symbols],
+        }
+    }
+
+    fn states(&self) -> usize {
+        self.transitions.len() / self.symbols
+    }
+
+    /// How many states appear in the table, which are the first ones as states are added in order
+    fn used_states(&self) -> usize {
+        self.transitions
+            .iter()
+            .enumerate()
+            .filter_map(|(i, t)| {
+                let next = t.and_then(|(_, _, next)| next).unwrap_or(0);
+                t.map(|_| (i / self.symbols).max(next) + 1)
+            })
+            .max()
+            .unwrap_or(1)
+    }
+
+    fn undefined(&self) -> usize {
+        self.transitions.iter().filter(|t| t.is_none()).count()
+    }
+
+    /// Write the table in busy beaver notation, with `None` as the next state meaning halting
+    fn notation(&self) -> String {
+        let rows: Vec<String> = self
+            .transitions
+            .chunks(self.symbols)
+            .map(|row| {
+                row.iter()
+                    .map(|t| match t {
+                        None => "---".to_string(),
+                        Some((write, motion, next)) => format!(
+                            "{}{}{}",
+                            &SYMBOLS[*write..=*write],
+                            motion,
+                            next.map_or(HALT, |n| &STATES[n..=n])
+                        ),
+                    })
+                    .collect()
+            })
+            .collect();
+        rows.join("_")
+    }
+}
+
+/// What came out of enumerating all the machines of a size
+#[derive(Debug, Default)]
+pub struct Enumeration {
+    /// How many machines were run, each to a verdict or to the limit
+    pub machines: usize,
+
+    /// How many of them halt
+    pub halting: usize,
+
+    /// How many of them were found to come back to a configuration, and so to never halt
+    pub cycling: usize,
+
+    /// The halting machine taking the most steps, in busy beaver notation
+    pub most_steps: Option<(Score, String)>,
+
+    /// The halting machine leaving the most ones, in busy beaver notation
+    pub most_ones: Option<(Score, String)>,
+
+    /// The machines still running after the limit, in busy beaver notation
+    pub undecided: Vec<String>,
+}
+
+impl Enumeration {
+    fn halted(&mut self, score: Score, notation: String) {
+        self.machines += 1;
+        self.halting += 1;
+        if self
+            .most_ones
+            .as_ref()
+            .is_none_or(|(best, _)| score.ones > best.ones)
+        {
+            self.most_ones = Some((score.clone(), notation.clone()));
+        }
+        if self
+            .most_steps
+            .as_ref()
+            .is_none_or(|(best, _)| score.steps > best.steps)
+        {
+            self.most_steps = Some((score, notation));
+        }
+    }
+}
+
+/// A machine in the middle of its run, waiting to carry on with a newly defined transition
+struct Fork {
+    table: Table,
+    tape: Vec<char>,
+    head: usize,
+    state: usize,
+    steps: usize,
+}
+
+/// The configuration of a machine as a key for finding cycles
+type Configuration = (usize, String, Vec<char>);
+
+fn configuration(tm: &DeterministicTuringMachine<String>) -> Configuration {
+    (tm.head(), tm.current_state().clone(), written(tm).to_vec())
+}
+
+/// The tape up to the last cell which is not blank
+fn written(tm: &DeterministicTuringMachine<String>) -> &[char] {
+    let tape = tm.tape();
+    let end = tape.iter().rposition(|c| *c != '_').map_or(0, |i| i + 1);
+    &tape[..end]
+}
+
+/// Is the machine in the given configuration? This is checked every step, so without copying the tape
+fn is_in(tm: &DeterministicTuringMachine<String>, (head, state, tape): &Configuration) -> bool {
+    tm.head() == *head && tm.current_state() == state && written(tm) == tape.as_slice()
+}
+
+/// Run a fork until it halts, cycles, reaches the limit or reaches an undefined transition, returning the forks of the latter
+fn run(fork: Fork, limit: usize, enumeration: &mut Enumeration) -> Vec<Fork> {
+    let Fork {
+        table,
+        tape,
+        head,
+        state,
+        mut steps,
+    } = fork;
+    let notation = table.notation();
+    let repr = busy_beaver::parse(&notation).expect("The table is written in valid notation");
+    let mut tm = DeterministicTuringMachine::<String>::from_builder(
+        TuringMachineBuilder::new().repr(repr).tape(tape),
+    )
+    .expect("The tape only holds symbols of the alphabet");
+    {
+        let (_, tm_head, tm_state) = tm.configuration_mut();
+        *tm_head = head;
+        *tm_state = STATES[state..=state].to_string();
+    }
+
+    // Brent's cycle detection, comparing with the configuration at the last power of two
+    let mut saved = configuration(&tm);
+    let mut power = 1;
+    let mut since = 0;
+    loop {
+        if busy_beaver::make_room(&mut tm) > 0 {
+            saved = configuration(&tm);
+            power = 1;
+            since = 0;
+        }
+
+        let state = STATES
+            .find(tm.current_state().as_str())
+            .expect("Only the lettered states are not final");
+        let read = tm.tape().get(tm.head()).cloned().unwrap_or('_');
+        let symbol = SYMBOLS.find(read).unwrap_or(0);
+        if table.transitions[state * table.symbols + symbol].is_none() {
+            return branch(&table, &tm, state, symbol, steps, enumeration);
+        }
+
+        if steps >= limit {
+            enumeration.machines += 1;
+            enumeration.undecided.push(notation);
+            return Vec::new();
+        }
+        tm.step();
+        steps += 1;
+        if tm.is_finished() {
+            enumeration.halted(busy_beaver::score(&tm, steps), notation);
+            return Vec::new();
+        }
+
+        since += 1;
+        if is_in(&tm, &saved) {
+            enumeration.machines += 1;
+            enumeration.cycling += 1;
+            return Vec::new();
+        }
+        if since == power {
+            saved = configuration(&tm);
+            power *= 2;
+            since = 0;
+        }
+    }
+}
+
+/// Define the transition the machine reached in every way, halting included
+fn branch(
+    table: &Table,
+    tm: &DeterministicTuringMachine<String>,
+    state: usize,
+    symbol: usize,
+    steps: usize,
+    enumeration: &mut Enumeration,
+) -> Vec<Fork> {
+    let index = state * table.symbols + symbol;
+
+    // Halting writes a one, which is the most the halting step can add
+    let mut halting = table.clone();
+    halting.transitions[index] = Some((1.min(table.symbols - 1), Motion::Right, None));
+    let mut score = busy_beaver::score(tm, steps + 1);
+    if symbol == 0 && table.symbols > 1 {
+        score.ones += 1;
+    }
+    score.accepting = true;
+    enumeration.halted(score, halting.notation());
+
+    let mut forks = Vec::new();
+    if table.undefined() == 1 {
+        return forks;
+    }
+    let next_states = (table.used_states() + 1).min(table.states());
+    let motions: &[Motion] = if steps == 0 {
+        &[Motion::Right]
+    } else {
+        &[Motion::Left, Motion::Right]
+    };
+    for next in 0..next_states {
+        for write in 0..table.symbols {
+            for motion in motions {
+                let mut table = table.clone();
+                table.transitions[index] = Some((write, *motion, Some(next)));
+                forks.push(Fork {
+                    table,
+                    tape: tm.tape().clone(),
+                    head: tm.head(),
+                    state,
+                    steps,
+                });
+            }
+        }
+    }
+    forks
+}
+
+/// Enumerate all machines with `states` states and `symbols` symbols in tree normal form, running each for at most `limit` steps.
+/// Panics unless there are between 1 and 25 states and between 2 and 10 symbols, as the busy beaver notation has letters and digits for.
+pub fn enumerate(states: usize, symbols: usize, limit: usize) -> Enumeration {
+    assert!(
+        (1..=STATES.len()).contains(&states),
+        "There must be between 1 and {} states",
+        STATES.len()
+    );
+    assert!(
+        (2..=SYMBOLS.len()).contains(&symbols),
+        "There must be between 2 and {} symbols",
+        SYMBOLS.len()
+    );
+
+    let mut enumeration = Enumeration::default();
+    let mut forks = vec![Fork {
+        table: Table::new(states, symbols),
+        tape: Vec::new(),
+        head: 0,
+        state: 0,
+        steps: 0,
+    }];
+    while let Some(fork) = forks.pop() {
+        forks.extend(run(fork, limit, &mut enumeration));
+    }
+    enumeration
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_the_busy_beavers() {
+        // The steps and ones of the busy beavers, found by separate machines for three states
+        for (states, symbols, steps, ones) in
+            [(1, 2, 1, 1), (2, 2, 6, 4), (3, 2, 21, 6), (2, 3, 38, 9)].iter()
+        {
+            let enumeration = enumerate(*states, *symbols, 200);
+            let (most_steps, machine) = enumeration.most_steps.unwrap();
+            assert_eq!(most_steps.steps, *steps);
+            assert_eq!(enumeration.most_ones.unwrap().0.ones, *ones);
+
+            // The champion scores the same when run on its own
+            let score = busy_beaver::run(&busy_beaver::parse(&machine).unwrap(), 1000).unwrap();
+            assert_eq!(score, most_steps);
+        }
+    }
+
+    #[test]
+    fn leaves_the_rest_undecided() {
+        let enumeration = enumerate(2, 2, 200);
+        assert_eq!(
+            enumeration.machines,
+            enumeration.halting + enumeration.cycling + enumeration.undecided.len()
+        );
+        assert!(enumeration.cycling > 0);
+
+        // Every undecided machine is still running at the limit
+        for machine in &enumeration.undecided {
+            let repr = busy_beaver::parse(machine).unwrap();
+            assert_eq!(busy_beaver::run(&repr, 200), None, "{}", machine);
+        }
+    }
+}
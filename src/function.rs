@@ -0,0 +1,308 @@
+//! Support for machines computing functions `f(input)` rather than deciding languages.
+//! A [`FunctionSpec`](struct.FunctionSpec.html) describes how the arguments are written on the tape, where the output
+//! can be found once the machine stops and how it should be read back.
+
+use std::{convert::TryFrom, fmt, str::FromStr};
+
+/// The longest unary number [`Codec::encode`](enum.Codec.html#method.encode) writes, a GiB of tape
+pub const MAX_UNARY_LEN: usize = 1 << 28;
+
+/// A value that is passed to or returned by a machine computing a function
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Value {
+    /// A raw string of tape symbols
+    Symbols(String),
+
+    /// A natural number
+    Number(u64),
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Value::Symbols(s) => write!(f, "{}", s),
+            Value::Number(n) => write!(f, "{}", n),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CodecError {
+    /// The value could not be parsed as a number
+    InvalidNumber(String),
+
+    /// A symbol that the codec does not know how to decode
+    InvalidSymbol(char),
+
+    /// The decoded number does not fit in a `u64`
+    Overflow,
+
+    /// The number is longer than [`MAX_UNARY_LEN`](constant.MAX_UNARY_LEN.html) written in unary
+    TooLong(u64),
+
+    /// The region holding the output could not be found on the tape
+    MissingOutputRegion,
+
+    /// The codec string is not recognised
+    UnknownCodec(String),
+
+    /// The output region string is not recognised
+    UnknownRegion(String),
+}
+
+/// How values are written on and read from the tape
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Codec {
+    /// Symbols are copied verbatim
+    #[default]
+    Raw,
+
+    /// A number `n` is written as `n` copies of the symbol
+    Unary(char),
+
+    /// A number is written in binary, most significant bit first
+    Binary,
+}
+
+impl FromStr for Codec {
+    type Err = CodecError;
+
+    /// Accepts `raw`, `binary`, `unary` (using `1`) or `unary:<symbol>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "raw" => Ok(Codec::Raw),
+            "binary" => Ok(Codec::Binary),
+            "unary" => Ok(Codec::Unary('1')),
+            _ => {
+                let mut symbol = s.strip_prefix("unary:").map(str::chars);
+                match symbol.as_mut().map(|c| (c.next(), c.next())) {
+                    Some((Some(c), None)) => Ok(Codec::Unary(c)),
+                    _ => Err(CodecError::UnknownCodec(s.to_string())),
+                }
+            }
+        }
+    }
+}
+
+impl Codec {
+    /// Parse a user supplied value (e.g. from the command line) into the kind of value this codec handles
+    pub fn parse_value(&self, s: &str) -> Result<Value, CodecError> {
+        match self {
+            Codec::Raw => Ok(Value::Symbols(s.to_string())),
+            Codec::Unary(_) | Codec::Binary => s
+                .trim()
+                .parse::<u64>()
+                .map(Value::Number)
+                .map_err(|_| CodecError::InvalidNumber(s.to_string())),
+        }
+    }
+
+    /// Write a value as tape symbols
+    pub fn encode(&self, value: &Value) -> Result<Vec<char>, CodecError> {
+        match (self, value) {
+            (_, Value::Symbols(s)) => Ok(s.chars().collect()),
+            (Codec::Raw, Value::Number(n)) => Ok(n.to_string().chars().collect()),
+            (Codec::Unary(c), Value::Number(n)) => match usize::try_from(*n) {
+                Ok(len) if len <= MAX_UNARY_LEN => Ok(vec![*c; len]),
+                _ => Err(CodecError::TooLong(*n)),
+            },
+            (Codec::Binary, Value::Number(n)) => Ok(format!("{:b}", n).chars().collect()),
+        }
+    }
+
+    /// Read a value back from tape symbols
+    pub fn decode(&self, symbols: &[char]) -> Result<Value, CodecError> {
+        match self {
+            Codec::Raw => Ok(Value::Symbols(symbols.iter().collect())),
+            Codec::Unary(c) => match symbols.iter().find(|s| *s != c) {
+                Some(s) => Err(CodecError::InvalidSymbol(*s)),
+                None => Ok(Value::Number(symbols.len() as u64)),
+            },
+            Codec::Binary => symbols
+                .iter()
+                .try_fold(0u64, |acc, s| {
+                    let bit = match s {
+                        '0' => 0,
+                        '1' => 1,
+                        _ => return Err(CodecError::InvalidSymbol(*s)),
+                    };
+                    acc.checked_mul(2)
+                        .and_then(|acc| acc.checked_add(bit))
+                        .ok_or(CodecError::Overflow)
+                })
+                .map(Value::Number),
+        }
+    }
+}
+
+/// Where the output of the machine is located on the final tape
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OutputRegion {
+    /// The whole tape, without trailing blanks
+    #[default]
+    Tape,
+
+    /// From the head up to the first blank
+    FromHead,
+
+    /// Strictly between the first occurrence of the opening marker and the following closing marker
+    Between(char, char),
+}
+
+impl FromStr for OutputRegion {
+    type Err = CodecError;
+
+    /// Accepts `tape`, `head` or `between:<open><close>`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tape" => Ok(OutputRegion::Tape),
+            "head" => Ok(OutputRegion::FromHead),
+            _ => {
+                let markers: Option<Vec<char>> =
+                    s.strip_prefix("between:").map(|m| m.chars().collect());
+                match markers.as_deref() {
+                    Some([open, close]) => Ok(OutputRegion::Between(*open, *close)),
+                    _ => Err(CodecError::UnknownRegion(s.to_string())),
+                }
+            }
+        }
+    }
+}
+
+impl OutputRegion {
+    /// Extract the output symbols from the tape, given the final head position
+    pub fn extract(&self, tape: &[char], head: usize) -> Result<Vec<char>, CodecError> {
+        match self {
+            OutputRegion::Tape => {
+                let end = tape.iter().rposition(|c| *c != '_').map_or(0, |i| i + 1);
+                Ok(tape[..end].to_vec())
+            }
+            OutputRegion::FromHead => Ok(tape
+                .iter()
+                .skip(head)
+                .take_while(|c| **c != '_')
+                .cloned()
+                .collect()),
+            OutputRegion::Between(open, close) => {
+                let start = tape
+                    .iter()
+                    .position(|c| c == open)
+                    .ok_or(CodecError::MissingOutputRegion)?
+                    + 1;
+                let len = tape[start..]
+                    .iter()
+                    .position(|c| c == close)
+                    .ok_or(CodecError::MissingOutputRegion)?;
+                Ok(tape[start..start + len].to_vec())
+            }
+        }
+    }
+}
+
+/// Describes how a machine computes a function: how the arguments are encoded and how the result is read back
+#[derive(Debug, Clone, Default)]
+pub struct FunctionSpec {
+    pub input: Codec,
+    pub output: Codec,
+    pub region: OutputRegion,
+
+    /// The symbol placed between consecutive arguments
+    pub separator: Option<char>,
+}
+
+impl FunctionSpec {
+    /// Write the arguments on a fresh tape
+    pub fn encode_input(&self, args: &[Value]) -> Result<Vec<char>, CodecError> {
+        let mut tape = Vec::new();
+        for (i, arg) in args.iter().enumerate() {
+            if i > 0 {
+                tape.extend(self.separator);
+            }
+            tape.extend(self.input.encode(arg)?);
+        }
+        Ok(tape)
+    }
+
+    /// Read the result from the final tape and head position
+    pub fn decode_output(&self, tape: &[char], head: usize) -> Result<Value, CodecError> {
+        self.output.decode(&self.region.extract(tape, head)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_tape(s: &str) -> Vec<char> {
+        s.chars().collect()
+    }
+
+    #[test]
+    fn parse_codecs() {
+        assert_eq!("raw".parse(), Ok(Codec::Raw));
+        assert_eq!("binary".parse(), Ok(Codec::Binary));
+        assert_eq!("unary".parse(), Ok(Codec::Unary('1')));
+        assert_eq!("unary:a".parse(), Ok(Codec::Unary('a')));
+        assert!("unary:ab".parse::<Codec>().is_err());
+        assert!("decimal".parse::<Codec>().is_err());
+    }
+
+    #[test]
+    fn unary_round_trip() {
+        let codec = Codec::Unary('1');
+        let tape = codec.encode(&Value::Number(4)).unwrap();
+        assert_eq!(tape, to_tape("1111"));
+        assert_eq!(codec.decode(&tape), Ok(Value::Number(4)));
+        assert_eq!(
+            codec.decode(&to_tape("11a")),
+            Err(CodecError::InvalidSymbol('a'))
+        );
+        assert_eq!(
+            codec.encode(&Value::Number(u64::MAX)),
+            Err(CodecError::TooLong(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn binary_round_trip() {
+        let codec = Codec::Binary;
+        let tape = codec.encode(&Value::Number(10)).unwrap();
+        assert_eq!(tape, to_tape("1010"));
+        assert_eq!(codec.decode(&tape), Ok(Value::Number(10)));
+        assert_eq!(codec.decode(&to_tape("0011")), Ok(Value::Number(3)));
+        assert_eq!(codec.decode(&[]), Ok(Value::Number(0)));
+        assert_eq!(codec.decode(&vec!['1'; 65]), Err(CodecError::Overflow));
+    }
+
+    #[test]
+    fn extract_regions() {
+        let tape = to_tape("^#[101]#11__");
+        assert_eq!(
+            OutputRegion::Tape.extract(&tape, 0),
+            Ok(to_tape("^#[101]#11"))
+        );
+        assert_eq!(OutputRegion::FromHead.extract(&tape, 8), Ok(to_tape("11")));
+        assert_eq!(
+            OutputRegion::Between('[', ']').extract(&tape, 0),
+            Ok(to_tape("101"))
+        );
+        assert_eq!(
+            OutputRegion::Between('<', '>').extract(&tape, 0),
+            Err(CodecError::MissingOutputRegion)
+        );
+        assert_eq!("between:[]".parse(), Ok(OutputRegion::Between('[', ']')));
+    }
+
+    #[test]
+    fn spec_encodes_arguments() {
+        let spec = FunctionSpec {
+            input: Codec::Binary,
+            separator: Some('#'),
+            ..Default::default()
+        };
+        let tape = spec
+            .encode_input(&[Value::Number(1), Value::Number(2), Value::Number(3)])
+            .unwrap();
+        assert_eq!(tape, to_tape("1#10#11"));
+    }
+}
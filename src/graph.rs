@@ -0,0 +1,261 @@
+//! State diagrams of machines, and their export to Graphviz DOT.
+//!
+//! A [`StateGraph`](struct.StateGraph.html) can be built either from a parsed machine (any
+//! [`MachineRepresentationBuilder`](../builders/trait.MachineRepresentationBuilder.html), such as the
+//! [`MachineParser`](../machine_parser/struct.MachineParser.html)) or from a
+//! [`GeneralMachineRepresentation`](../common/representation/struct.GeneralMachineRepresentation.html).
+//! Transitions between the same pair of states are merged into a single edge.
+
+use hashbrown::{HashMap, HashSet};
+use std::fmt::{Display, Write};
+
+use crate::{
+    builders::{MachineRepresentationBuilder, TransitionTableBuilder},
+    common::{Action, Motion, State, StateTrait},
+    machine_representation::MachineRepresentation,
+    transition_table::{ActionSet, TransitionTable},
+};
+
+/// A single transition on an edge, `read → write, motion`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Label {
+    pub read: char,
+    pub write: char,
+    pub motion: Motion,
+}
+
+/// All the transitions between two states
+#[derive(Debug, Clone, PartialEq)]
+pub struct Edge {
+    pub from: String,
+    pub to: String,
+    pub labels: Vec<Label>,
+}
+
+/// The states and transitions of a machine, with names already rendered and in a stable order
+#[derive(Debug, Clone, PartialEq)]
+pub struct StateGraph {
+    pub states: Vec<(String, State)>,
+    pub start: String,
+    pub edges: Vec<Edge>,
+}
+
+impl StateGraph {
+    fn new<StateTy>(
+        states: &HashSet<StateTy>,
+        start: &StateTy,
+        kind: impl Fn(&StateTy) -> State,
+        transitions: impl Iterator<Item = (StateTy, char, Action<StateTy>)>,
+    ) -> Self
+    where
+        StateTy: StateTrait + Display,
+    {
+        let mut states: Vec<(String, State)> =
+            states.iter().map(|s| (s.to_string(), kind(s))).collect();
+        states.sort_by(|(s1, _), (s2, _)| s1.cmp(s2));
+
+        let mut merged: HashMap<(String, String), Vec<Label>> = HashMap::new();
+        for (from, read, act) in transitions {
+            merged
+                .entry((from.to_string(), act.next_state().to_string()))
+                .or_default()
+                .push(Label {
+                    read,
+                    write: *act.tape_output(),
+                    motion: *act.motion(),
+                });
+        }
+
+        let mut edges: Vec<Edge> = merged
+            .into_iter()
+            .map(|((from, to), mut labels)| {
+                labels.sort();
+                labels.dedup();
+                Edge { from, to, labels }
+            })
+            .collect();
+        edges.sort_by(|e1, e2| (&e1.from, &e1.to).cmp(&(&e2.from, &e2.to)));
+
+        StateGraph {
+            states,
+            start: start.to_string(),
+            edges,
+        }
+    }
+
+    /// Build from a parsed, not yet validated, machine
+    pub fn from_builder<StateTy, Builder>(b: &Builder) -> Option<Self>
+    where
+        StateTy: StateTrait + Display,
+        Builder: MachineRepresentationBuilder<StateTy>,
+        Builder::TableBuilder: TransitionTableBuilder<StateTy, InputTy = char>,
+    {
+        let kind = |s: &StateTy| {
+            if b.accepting_states().contains(s) {
+                State::Accepting
+            } else if b.rejecting_states().contains(s) {
+                State::Rejecting
+            } else if b.halting_states().contains(s) {
+                State::Halting
+            } else {
+                State::Neutral
+            }
+        };
+        let table = b.transition_table_builder();
+        let transitions = b.states().iter().flat_map(|s| {
+            table
+                .get_state_transitions(s)
+                .into_iter()
+                .map(move |(c, act)| (s.clone(), c, act))
+        });
+
+        Some(Self::new(
+            b.states(),
+            b.starting_state().as_ref()?,
+            kind,
+            transitions,
+        ))
+    }
+
+    /// Build from a representation, looking up every `(state, symbol)` pair of the transition table
+    pub fn from_repr<StateTy, Repr>(repr: &Repr) -> Self
+    where
+        StateTy: StateTrait + Display,
+        Repr: MachineRepresentation<StateTy, InputTy = char>,
+        Repr::OutputTy: ActionSet<StateTy>,
+    {
+        let table = repr.transition_table();
+        let transitions = repr.states().iter().flat_map(|s| {
+            repr.alphabet().iter().flat_map(move |c| {
+                table
+                    .apply_transition_table(s, *c)
+                    .map(|out| out.actions())
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(move |act| (s.clone(), *c, act))
+            })
+        });
+
+        Self::new(
+            repr.states(),
+            repr.starting_state(),
+            |s| repr.state_kind(s),
+            transitions,
+        )
+    }
+
+    /// Render as a Graphviz DOT digraph
+    pub fn to_dot(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "digraph machine {{").unwrap();
+        writeln!(out, "    rankdir=LR;").unwrap();
+        writeln!(out, "    node [shape=circle];").unwrap();
+        writeln!(out, "    __start [shape=point];").unwrap();
+        writeln!(out, "    __start -> {};", quote(&self.start)).unwrap();
+
+        for (state, kind) in &self.states {
+            let style = match kind {
+                State::Accepting => " [shape=doublecircle, color=darkgreen]",
+                State::Rejecting => " [shape=circle, color=red]",
+                State::Halting => " [shape=doubleoctagon, color=blue]",
+                State::Neutral => "",
+            };
+            writeln!(out, "    {}{};", quote(state), style).unwrap();
+        }
+
+        for edge in &self.edges {
+            let label: Vec<String> = edge
+                .labels
+                .iter()
+                .map(|l| format!("{}→{},{}", l.read, l.write, l.motion))
+                .collect();
+            writeln!(
+                out,
+                "    {} -> {} [label={}];",
+                quote(&edge.from),
+                quote(&edge.to),
+                quote(&label.join("\n"))
+            )
+            .unwrap();
+        }
+        writeln!(out, "}}").unwrap();
+        out
+    }
+}
+
+/// Quote an identifier for DOT, escaping as needed
+fn quote(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deterministic_tm::DeterministicMachineRepresentation;
+    use crate::machine_parser;
+
+    const MACHINE: &str = "states 4\nq0\nq1\nqa +\nqr -\nalphabet 2 a b\nq0 a q1 b R\nq0 b q1 a R\nq1 a q1 a L\nq1 _ qa _ S";
+
+    #[test]
+    fn merges_parallel_edges() {
+        let parser = machine_parser::parse(MACHINE.as_bytes()).unwrap();
+        let graph = StateGraph::from_builder(&parser).unwrap();
+
+        assert_eq!(graph.start, "q0");
+        assert_eq!(graph.states.len(), 4);
+        assert_eq!(graph.states[2], ("qa".to_string(), State::Accepting));
+        assert_eq!(graph.edges.len(), 3);
+        assert_eq!(
+            graph.edges[0],
+            Edge {
+                from: "q0".to_string(),
+                to: "q1".to_string(),
+                labels: vec![
+                    Label {
+                        read: 'a',
+                        write: 'b',
+                        motion: Motion::Right
+                    },
+                    Label {
+                        read: 'b',
+                        write: 'a',
+                        motion: Motion::Right
+                    },
+                ],
+            }
+        );
+    }
+
+    #[test]
+    fn builder_and_repr_agree() {
+        let parser = machine_parser::parse(MACHINE.as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        assert_eq!(
+            StateGraph::from_builder(&parser).unwrap(),
+            StateGraph::from_repr(&repr)
+        );
+    }
+
+    #[test]
+    fn renders_dot() {
+        let parser = machine_parser::parse(MACHINE.as_bytes()).unwrap();
+        let dot = StateGraph::from_builder(&parser).unwrap().to_dot();
+
+        assert!(dot.starts_with("digraph machine {"));
+        assert!(dot.contains("__start -> \"q0\";"));
+        assert!(dot.contains("\"qa\" [shape=doublecircle, color=darkgreen];"));
+        assert!(dot.contains("\"qr\" [shape=circle, color=red];"));
+        assert!(dot.contains("\"q0\" -> \"q1\" [label=\"a→b,R\\nb→a,R\"];"));
+    }
+}
@@ -0,0 +1,108 @@
+use crate::builders::TuringMachineBuilder;
+use crate::TuringMachine;
+
+/// Something that observes a [`TuringMachine`](trait.TuringMachine.html) as it runs
+pub trait StepHook<T> {
+    /// Called with the starting configuration, and then after every step
+    fn on_step(&mut self, tm: &T);
+}
+
+/// A wrapper struct, which takes a [`TuringMachine`](trait.TuringMachine.html) and calls a [`StepHook`](trait.StepHook.html) on each of its configurations
+pub struct HookedTuringMachineExt<T, H> {
+    tm: T,
+    hook: H,
+}
+
+impl<T: TuringMachine, H: StepHook<T>> HookedTuringMachineExt<T, H> {
+    /// Decorate an existing [`TuringMachine`](trait.TuringMachine.html) with the hook, which immediately sees the starting configuration
+    pub fn new(tm: T, mut hook: H) -> Self {
+        hook.on_step(&tm);
+        Self { tm, hook }
+    }
+
+    /// Get the hook, e.g. to look at what it recorded so far
+    pub fn hook(&self) -> &H {
+        &self.hook
+    }
+
+    /// Get the machine and the hook back
+    pub fn into_parts(self) -> (T, H) {
+        (self.tm, self.hook)
+    }
+}
+
+impl<T, H> TuringMachine for HookedTuringMachineExt<T, H>
+where
+    T: TuringMachine,
+    H: StepHook<T> + Default,
+{
+    type Tape = T::Tape;
+    type StateTy = T::StateTy;
+    type ReprTy = T::ReprTy;
+    type ErrorTy = T::ErrorTy;
+
+    fn from_builder(
+        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
+    ) -> Result<Self, Self::ErrorTy> {
+        Ok(Self::new(T::from_builder(builder)?, H::default()))
+    }
+
+    fn step(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+        self.tm.step();
+        self.hook.on_step(&self.tm);
+    }
+
+    fn tape(&self) -> &Self::Tape {
+        self.tm.tape()
+    }
+
+    fn is_accepting(&self) -> bool {
+        self.tm.is_accepting()
+    }
+
+    fn is_rejecting(&self) -> bool {
+        self.tm.is_rejecting()
+    }
+
+    fn is_halting(&self) -> bool {
+        self.tm.is_halting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mocking::MockMachine;
+
+    #[derive(Default)]
+    struct CountingHook {
+        seen: usize,
+    }
+
+    impl StepHook<MockMachine<usize>> for CountingHook {
+        fn on_step(&mut self, tm: &MockMachine<usize>) {
+            assert_eq!(tm.tape, vec!['1', '2']);
+            self.seen += 1;
+        }
+    }
+
+    #[test]
+    fn check_hook_sees_every_configuration() {
+        let mock = MockMachine::<usize> {
+            p: Default::default(),
+            tape: vec!['1', '2'],
+        };
+
+        let mut hooked = HookedTuringMachineExt::new(mock, CountingHook::default());
+        assert_eq!(hooked.hook().seen, 1);
+        for _ in 0..10 {
+            hooked.step();
+        }
+
+        let (_, hook) = hooked.into_parts();
+        assert_eq!(hook.seen, 11);
+    }
+}
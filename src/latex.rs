@@ -0,0 +1,333 @@
+//! LaTeX output, for typesetting machines and their runs.
+//!
+//! [`tikz`](fn.tikz.html) turns a [`StateGraph`](../graph/struct.StateGraph.html) into a `tikzpicture` using the
+//! `automata` library, while a [`ConfigurationRecorder`](struct.ConfigurationRecorder.html) collects the configurations
+//! of a run and writes them in the usual `u q v` notation, one `\vdash` per step.
+
+use hashbrown::{HashMap, HashSet};
+use std::{
+    collections::VecDeque,
+    fmt::{Display, Write},
+};
+
+use crate::{
+    common::{State, StateTrait},
+    deterministic_tm::DeterministicTuringMachine,
+    graph::StateGraph,
+    hooked::StepHook,
+    TuringMachine,
+};
+
+/// The preamble needed by the output of [`tikz`](fn.tikz.html), defining the `rejecting` style of rejecting states
+pub const TIKZ_PREAMBLE: &str = concat!(
+    "\\usepackage{tikz}\n",
+    "\\usetikzlibrary{automata,positioning,arrows}\n",
+    "\\tikzset{rejecting/.style={draw=red, text=red}}\n"
+);
+
+/// Escape text so that it can be used inside `\texttt` or `\mathit`
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '_' => escaped.push_str("\\_"),
+            '#' | '$' | '%' | '&' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\\' => escaped.push_str("\\textbackslash{}"),
+            '^' => escaped.push_str("\\textasciicircum{}"),
+            '~' => escaped.push_str("\\textasciitilde{}"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Typeset tape symbols, drawing blanks as `␣`
+fn symbols(s: &str) -> String {
+    let cells: Vec<String> = s
+        .chars()
+        .map(|c| match c {
+            '_' => "\\textvisiblespace{}".to_string(),
+            _ => escape(&c.to_string()),
+        })
+        .collect();
+    format!("\\texttt{{{}}}", cells.concat())
+}
+
+fn state_name(s: &str) -> String {
+    format!("\\mathit{{{}}}", escape(s))
+}
+
+/// Order the states breadth first from the start, so that the layout roughly follows the transitions
+fn layout_order(graph: &StateGraph) -> Vec<usize> {
+    let index: HashMap<&str, usize> = graph
+        .states
+        .iter()
+        .enumerate()
+        .map(|(i, (s, _))| (s.as_str(), i))
+        .collect();
+
+    let mut order = Vec::with_capacity(graph.states.len());
+    let mut seen = HashSet::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    queue.push_back(&graph.start);
+
+    // Unreachable states are placed last, in name order
+    let mut roots = graph.states.iter().map(|(s, _)| s.as_str());
+    loop {
+        while let Some(state) = queue.pop_front() {
+            if !seen.insert(state) {
+                continue;
+            }
+            order.push(index[state]);
+            for edge in graph.edges.iter().filter(|e| e.from == state) {
+                queue.push_back(&edge.to);
+            }
+        }
+        match roots.find(|s| !seen.contains(s)) {
+            Some(root) => queue.push_back(root),
+            None => break,
+        }
+    }
+    order
+}
+
+/// Render a state diagram as a `tikzpicture`, placing the states on a grid with `columns` columns.
+/// Accepting states are drawn with a double circle, rejecting ones with a single red circle and halting ones with a
+/// dashed double circle.
+/// The [`TIKZ_PREAMBLE`](constant.TIKZ_PREAMBLE.html) is needed to compile it.
+pub fn tikz(graph: &StateGraph, columns: usize) -> String {
+    let columns = columns.max(1);
+    let ids: HashMap<&str, String> = graph
+        .states
+        .iter()
+        .enumerate()
+        .map(|(i, (s, _))| (s.as_str(), format!("s{}", i)))
+        .collect();
+
+    let mut out = String::new();
+    writeln!(
+        out,
+        "\\begin{{tikzpicture}}[->, >=stealth', shorten >=1pt, auto, node distance=3cm, semithick]"
+    )
+    .unwrap();
+
+    for (position, i) in layout_order(graph).into_iter().enumerate() {
+        let (state, kind) = &graph.states[i];
+        let mut style = vec!["state"];
+        if *state == graph.start {
+            style.push("initial");
+        }
+        match kind {
+            State::Accepting => style.push("accepting"),
+            State::Rejecting => style.push("rejecting"),
+            State::Halting => style.extend(&["accepting", "dashed"]),
+            State::Neutral => {}
+        }
+        writeln!(
+            out,
+            "    \\node[{}] ({}) at ({}, {}) {{${}$}};",
+            style.join(", "),
+            ids[state.as_str()],
+            3 * (position % columns),
+            -3 * (position / columns) as isize,
+            state_name(state)
+        )
+        .unwrap();
+    }
+
+    if !graph.edges.is_empty() {
+        writeln!(out, "    \\path").unwrap();
+        for edge in &graph.edges {
+            let shape = if edge.from == edge.to {
+                "loop above"
+            } else if graph
+                .edges
+                .iter()
+                .any(|e| e.from == edge.to && e.to == edge.from)
+            {
+                "bend left"
+            } else {
+                ""
+            };
+            let labels: Vec<String> = edge
+                .labels
+                .iter()
+                .map(|l| {
+                    format!(
+                        "${} \\rightarrow {}, \\mathrm{{{}}}$",
+                        symbols(&l.read.to_string()),
+                        symbols(&l.write.to_string()),
+                        l.motion
+                    )
+                })
+                .collect();
+            writeln!(
+                out,
+                "        ({}) edge [{}] node [align=center] {{{}}} ({})",
+                ids[edge.from.as_str()],
+                shape,
+                labels.join(" \\\\ "),
+                ids[edge.to.as_str()]
+            )
+            .unwrap();
+        }
+        writeln!(out, "    ;").unwrap();
+    }
+
+    writeln!(out, "\\end{{tikzpicture}}").unwrap();
+    out
+}
+
+/// Wrap a LaTeX fragment into a `standalone` document that can be compiled on its own
+pub fn standalone(body: &str) -> String {
+    format!(
+        "\\documentclass{{standalone}}\n\\usepackage{{amsmath}}\n{}\\begin{{document}}\n{}\\end{{document}}\n",
+        TIKZ_PREAMBLE, body
+    )
+}
+
+/// A configuration `u q v`: the machine is in state `q`, the tape holds `uv` and the head is on the first symbol of `v`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Configuration {
+    pub left: String,
+    pub state: String,
+    pub right: String,
+}
+
+impl Configuration {
+    /// Typeset the configuration, for use in math mode
+    pub fn to_latex(&self) -> String {
+        let mut parts = Vec::with_capacity(3);
+        if !self.left.is_empty() {
+            parts.push(symbols(&self.left));
+        }
+        parts.push(state_name(&self.state));
+        parts.push(symbols(&self.right));
+        parts.join("\\,")
+    }
+}
+
+/// A [`StepHook`](../hooked/trait.StepHook.html) recording every configuration of a run
+#[derive(Debug, Clone, Default)]
+pub struct ConfigurationRecorder {
+    configurations: Vec<Configuration>,
+}
+
+impl ConfigurationRecorder {
+    pub fn new() -> Self {
+        Self {
+            configurations: Vec::new(),
+        }
+    }
+
+    pub fn configurations(&self) -> &[Configuration] {
+        &self.configurations
+    }
+
+    /// Typeset the run as an `align*` environment, one configuration per line
+    pub fn to_latex(&self) -> String {
+        let mut out = String::new();
+        writeln!(out, "\\begin{{align*}}").unwrap();
+        for (i, configuration) in self.configurations.iter().enumerate() {
+            let yields = if i == 0 { "" } else { "\\vdash{} " };
+            let end = if i + 1 == self.configurations.len() {
+                ""
+            } else {
+                " \\\\"
+            };
+            writeln!(out, "    {}&{}{}", yields, configuration.to_latex(), end).unwrap();
+        }
+        writeln!(out, "\\end{{align*}}").unwrap();
+        out
+    }
+}
+
+impl<StateTy> StepHook<DeterministicTuringMachine<StateTy>> for ConfigurationRecorder
+where
+    StateTy: StateTrait + Display,
+{
+    fn on_step(&mut self, tm: &DeterministicTuringMachine<StateTy>) {
+        let tape = tm.tape();
+        let head = tm.head().min(tape.len());
+
+        // Trailing blanks are left out, except for the one under the head
+        let mut right: String = tape[head..].iter().collect();
+        right.truncate(right.trim_end_matches('_').len());
+        if right.is_empty() {
+            right.push('_');
+        }
+
+        self.configurations.push(Configuration {
+            left: tape[..head].iter().collect(),
+            state: tm.current_state().to_string(),
+            right,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder, deterministic_tm::DeterministicMachineRepresentation,
+        hooked::HookedTuringMachineExt, machine_parser,
+        machine_representation::MachineRepresentation,
+    };
+
+    #[test]
+    fn escapes_symbols() {
+        assert_eq!(symbols("(_$"), "\\texttt{(\\textvisiblespace{}\\$}");
+        assert_eq!(state_name("q_1"), "\\mathit{q\\_1}");
+        assert_eq!(escape("^"), "\\textasciicircum{}");
+    }
+
+    #[test]
+    fn renders_tikz() {
+        let parser = machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap();
+        let graph = StateGraph::from_builder(&parser).unwrap();
+        let tikz = tikz(&graph, 3);
+
+        assert!(tikz.starts_with("\\begin{tikzpicture}"));
+        assert!(tikz.trim_end().ends_with("\\end{tikzpicture}"));
+        // The start state is the first one placed
+        assert!(tikz.contains("[state, initial] (s4) at (0, 0) {$\\mathit{start}$};"));
+        assert!(tikz.contains("[state, rejecting]"));
+        assert!(tikz.contains("[state, accepting]"));
+        assert!(tikz.contains("(s1) edge [loop above]"));
+        assert_eq!(tikz.matches("\\node").count(), graph.states.len());
+    }
+
+    #[test]
+    fn records_configurations() {
+        let parser = machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let machine = DeterministicTuringMachine::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape("()".chars().collect()),
+        )
+        .unwrap();
+
+        let mut machine = HookedTuringMachineExt::new(machine, ConfigurationRecorder::new());
+        machine.run();
+        let configurations = machine.hook().configurations();
+
+        assert_eq!(
+            configurations[0],
+            Configuration {
+                left: String::new(),
+                state: "start".to_string(),
+                right: "()".to_string(),
+            }
+        );
+        assert_eq!(configurations[1].left, "^");
+        assert_eq!(configurations[1].state, "close");
+
+        let latex = machine.hook().to_latex();
+        assert!(latex.starts_with("\\begin{align*}\n    &\\mathit{start}\\,\\texttt{()} \\\\\n"));
+        assert_eq!(latex.matches("\\vdash").count(), configurations.len() - 1);
+    }
+}
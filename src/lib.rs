@@ -1,56 +1,88 @@
-pub mod builders;
-pub mod common;
-pub mod deterministic_tm;
-pub mod limited;
-pub mod machine_parser;
-pub mod machine_representation;
-#[cfg(test)]
-pub mod mocking;
-pub mod non_deterministic_tm;
-pub mod stats;
-pub mod transition_table;
-pub mod utils;
-
-use std::fmt::Debug;
-
-use crate::{
-    builders::TuringMachineBuilder, common::StateTrait,
-    machine_representation::MachineRepresentation,
-};
-
-/// A trait encapsulating the behavior of a general TM
-/// Should be general enough to account for [`DeterministicTuringMachine`](struct.DeterministicTuringMachine.html), k-tape machines, NDTMs and so on
-pub trait TuringMachine: Sized {
-    /// Associated tape
-    type Tape: Clone + Debug;
-    type StateTy: StateTrait;
-    type ReprTy: MachineRepresentation<Self::StateTy>;
-
-    /// The error raised on failed construction
-    type ErrorTy: Debug;
-
-    /// Takes a single step
-    fn step(&mut self);
-
-    /// Is the machine currently in an accepting state?
-    fn is_accepting(&self) -> bool;
-
-    /// Is the machine currently in a rejecting state?
-    fn is_rejecting(&self) -> bool;
-
-    /// Get the tape the machine is currently using
-    fn tape(&self) -> &Self::Tape;
-
-    /// Runs the TM until either an accepting or a rejecting state is reached.  
-    /// Note this method might not return at all! Use with caution!
-    fn run(&mut self) -> bool {
-        while !(self.is_accepting() || self.is_rejecting()) {
-            self.step();
-        }
-        self.is_accepting()
-    }
-
-    fn from_builder(
-        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
-    ) -> Result<Self, Self::ErrorTy>;
-}
+pub mod accelerate;
+pub mod big;
+pub mod binary;
+pub mod builders;
+pub mod busy_beaver;
+pub mod common;
+pub mod complement;
+pub mod complexity;
+pub mod compose;
+pub mod debugger;
+pub mod deciders;
+pub mod deterministic_tm;
+pub mod diagram;
+pub mod enumeration;
+pub mod function;
+pub mod graph;
+pub mod hooked;
+pub mod latex;
+pub mod limited;
+pub mod lint;
+pub mod machine_parser;
+pub mod machine_representation;
+pub mod macro_machine;
+pub mod minimize;
+#[cfg(test)]
+pub mod mocking;
+pub mod non_deterministic_tm;
+pub mod product;
+pub mod recording;
+pub mod snapshot;
+pub mod stats;
+pub mod tape;
+pub mod test_suite;
+pub mod transition_table;
+pub mod universal;
+pub mod utils;
+
+use std::fmt::Debug;
+
+use crate::{
+    builders::TuringMachineBuilder, common::StateTrait,
+    machine_representation::MachineRepresentation,
+};
+
+/// A trait encapsulating the behavior of a general TM
+/// Should be general enough to account for [`DeterministicTuringMachine`](struct.DeterministicTuringMachine.html), k-tape machines, NDTMs and so on
+pub trait TuringMachine: Sized {
+    /// Associated tape
+    type Tape: Clone + Debug;
+    type StateTy: StateTrait;
+    type ReprTy: MachineRepresentation<Self::StateTy>;
+
+    /// The error raised on failed construction
+    type ErrorTy: Debug;
+
+    /// Takes a single step
+    fn step(&mut self);
+
+    /// Is the machine currently in an accepting state?
+    fn is_accepting(&self) -> bool;
+
+    /// Is the machine currently in a rejecting state?
+    fn is_rejecting(&self) -> bool;
+
+    /// Is the machine currently in a plain halting state (neither accepting nor rejecting)?
+    fn is_halting(&self) -> bool;
+
+    /// Has the machine stopped, either accepting, rejecting or halting?
+    fn is_finished(&self) -> bool {
+        self.is_accepting() || self.is_rejecting() || self.is_halting()
+    }
+
+    /// Get the tape the machine is currently using
+    fn tape(&self) -> &Self::Tape;
+
+    /// Runs the TM until an accepting, rejecting or halting state is reached.  
+    /// Note this method might not return at all! Use with caution!
+    fn run(&mut self) -> bool {
+        while !self.is_finished() {
+            self.step();
+        }
+        self.is_accepting()
+    }
+
+    fn from_builder(
+        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
+    ) -> Result<Self, Self::ErrorTy>;
+}
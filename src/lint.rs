@@ -0,0 +1,230 @@
+//! Static analysis of machines.
+//!
+//! [`check`](fn.check.html) looks at a parsed, not yet validated, machine without running it and reports the
+//! [`Lint`](enum.Lint.html)s it finds, from transitions that can never be taken to accidental non-determinism.
+
+use hashbrown::{HashMap, HashSet};
+use std::fmt::{self, Display};
+
+use crate::{
+    builders::{MachineRepresentationBuilder, TransitionTableBuilder},
+    common::StateTrait,
+};
+
+/// How much a [`Lint`](enum.Lint.html) matters
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    /// The machine cannot be loaded as a deterministic machine
+    Error,
+
+    /// The machine is most likely not what was intended
+    Warning,
+
+    /// The machine is fine, but relies on a shorthand
+    Info,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Severity::Error => write!(f, "error"),
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Lint<StateTy> {
+    /// There are several transitions for the same `(state, symbol)` pair, which a deterministic machine rejects
+    NonDeterministic(StateTy, char, usize),
+
+    /// The state cannot be reached from the starting state
+    UnreachableState(StateTy),
+
+    /// The state is neither accepting, rejecting nor halting, but has no outgoing transitions
+    DeadEnd(StateTy),
+
+    /// A transition out of a final state, which will never be taken
+    TransitionFromFinalState(StateTy, char),
+
+    /// The symbol is in the alphabet, but no transition reads or writes it
+    UnusedSymbol(char),
+
+    /// Reading the symbol in the state falls through to the implicit reject
+    ImplicitReject(StateTy, char),
+}
+
+impl<StateTy> Lint<StateTy> {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Lint::NonDeterministic(..) => Severity::Error,
+            Lint::ImplicitReject(..) => Severity::Info,
+            _ => Severity::Warning,
+        }
+    }
+}
+
+impl<StateTy> Display for Lint<StateTy>
+where
+    StateTy: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Lint::NonDeterministic(s, c, n) => {
+                write!(f, "{} transitions for state {} reading '{}'", n, s, c)
+            }
+            Lint::UnreachableState(s) => write!(f, "state {} is unreachable", s),
+            Lint::DeadEnd(s) => write!(
+                f,
+                "state {} has no outgoing transitions but is not final",
+                s
+            ),
+            Lint::TransitionFromFinalState(s, c) => write!(
+                f,
+                "transition out of final state {} reading '{}' is never taken",
+                s, c
+            ),
+            Lint::UnusedSymbol(c) => write!(f, "symbol '{}' is never read or written", c),
+            Lint::ImplicitReject(s, c) => {
+                write!(f, "state {} reading '{}' implicitly rejects", s, c)
+            }
+        }
+    }
+}
+
+/// Analyse the machine, returning the lints sorted by severity first
+pub fn check<StateTy, Builder>(b: &Builder) -> Vec<Lint<StateTy>>
+where
+    StateTy: StateTrait + Ord,
+    Builder: MachineRepresentationBuilder<StateTy>,
+    Builder::TableBuilder: TransitionTableBuilder<StateTy, InputTy = char>,
+{
+    let table = b.transition_table_builder();
+    let is_final = |s: &StateTy| {
+        b.accepting_states().contains(s)
+            || b.rejecting_states().contains(s)
+            || b.halting_states().contains(s)
+    };
+
+    let mut lints = Vec::new();
+    let mut used_symbols = HashSet::new();
+    for state in b.states() {
+        let transitions = table.get_state_transitions(state);
+
+        let mut counts: HashMap<char, usize> = HashMap::new();
+        for (c, act) in &transitions {
+            *counts.entry(*c).or_default() += 1;
+            used_symbols.insert(*c);
+            used_symbols.insert(*act.tape_output());
+        }
+
+        if is_final(state) {
+            lints.extend(
+                counts
+                    .keys()
+                    .map(|c| Lint::TransitionFromFinalState(state.clone(), *c)),
+            );
+            continue;
+        }
+
+        lints.extend(
+            counts
+                .iter()
+                .filter(|(_, n)| **n > 1)
+                .map(|(c, n)| Lint::NonDeterministic(state.clone(), *c, *n)),
+        );
+
+        if transitions.is_empty() {
+            lints.push(Lint::DeadEnd(state.clone()));
+        } else {
+            lints.extend(
+                b.alphabet()
+                    .iter()
+                    .filter(|c| !counts.contains_key(c))
+                    .map(|c| Lint::ImplicitReject(state.clone(), *c)),
+            );
+        }
+    }
+
+    lints.extend(
+        b.alphabet()
+            .iter()
+            .filter(|c| **c != '_' && !used_symbols.contains(c))
+            .map(|c| Lint::UnusedSymbol(*c)),
+    );
+
+    // Walk the transitions from the starting state, final states have no way out
+    if let Some(start) = b.starting_state() {
+        let mut reachable = HashSet::new();
+        let mut stack = vec![start.clone()];
+        while let Some(state) = stack.pop() {
+            if !reachable.insert(state.clone()) || is_final(&state) {
+                continue;
+            }
+            stack.extend(
+                table
+                    .get_state_transitions(&state)
+                    .into_iter()
+                    .map(|(_, act)| act.next_state().clone()),
+            );
+        }
+        // A rejecting state is mandatory, even when the machine never rejects
+        lints.extend(
+            b.states()
+                .iter()
+                .filter(|s| !reachable.contains(*s) && !b.rejecting_states().contains(*s))
+                .map(|s| Lint::UnreachableState(s.clone())),
+        );
+    }
+
+    lints.sort_by(|l1, l2| (l1.severity(), l1).cmp(&(l2.severity(), l2)));
+    lints
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::machine_parser;
+
+    fn lints(machine: &str) -> Vec<Lint<String>> {
+        check(&machine_parser::parse(machine.as_bytes()).unwrap())
+    }
+
+    #[test]
+    fn clean_machine() {
+        let machine = "states 3\nq0\nqa +\nqr -\nalphabet 1 a\nq0 a q0 a R\nq0 _ qa _ S";
+        assert_eq!(lints(machine), vec![]);
+    }
+
+    #[test]
+    fn finds_every_lint() {
+        let machine = "states 6\nq0\nq1\nq2\nq3\nqa +\nqr -\nalphabet 3 a b c\nq0 a q1 a R\nq0 a q0 b R\nq1 _ qa _ S\nqa _ q0 _ S\nq3 a q0 a S";
+        let lints = lints(machine);
+
+        assert_eq!(lints[0], Lint::NonDeterministic("q0".to_string(), 'a', 2));
+        assert_eq!(lints[0].severity(), Severity::Error);
+        for lint in &[
+            Lint::UnreachableState("q2".to_string()),
+            Lint::UnreachableState("q3".to_string()),
+            Lint::DeadEnd("q2".to_string()),
+            Lint::TransitionFromFinalState("qa".to_string(), '_'),
+            Lint::UnusedSymbol('c'),
+            Lint::ImplicitReject("q0".to_string(), 'b'),
+            Lint::ImplicitReject("q1".to_string(), 'a'),
+        ] {
+            assert!(lints.contains(lint), "{} missing", lint);
+        }
+        assert!(!lints.contains(&Lint::UnreachableState("q0".to_string())));
+        assert!(!lints.contains(&Lint::UnreachableState("qr".to_string())));
+        assert_eq!(lints.last().unwrap().severity(), Severity::Info);
+    }
+
+    #[test]
+    fn paren_has_no_warnings() {
+        let parser = machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap();
+        assert!(check(&parser)
+            .iter()
+            .all(|lint| lint.severity() == Severity::Info));
+    }
+}
@@ -1,10 +1,12 @@
 use hashbrown::{HashMap, HashSet};
 use lazy_static::lazy_static;
-use std::io::{self, BufRead, BufReader, Read};
+use std::io::{self, BufRead, BufReader, Read, Write};
 
 use crate::{
     builders::{MachineRepresentationBuilder, TransitionTableBuilder},
     common::{Action, Motion, State},
+    machine_representation::MachineRepresentation,
+    transition_table::{ActionSet, TransitionTable},
 };
 
 /// A Error type for errors returned by [`parse`](fn.parse.html).  
@@ -30,8 +32,6 @@ pub enum StateError {
     InvalidStateHeader,
     HeaderIntParsing,
     DuplicateState(String),
-    DuplicateAcceptingState,
-    DuplicateRejectingState,
     StartingStateSetTwice,
     InvalidStateLine(String),
     InvalidStateName(String),
@@ -97,8 +97,18 @@ pub struct MachineTableParser {
     transitions: HashMap<String, Vec<(char, Action<String>)>>,
 }
 
+impl MachineTableParser {
+    /// Add a transition directly, without going through the textual format
+    pub fn add_transition(&mut self, state: String, symbol: char, action: Action<String>) {
+        self.transitions
+            .entry(state)
+            .or_default()
+            .push((symbol, action));
+    }
+}
+
 fn convert_to_char(s: &str) -> Option<char> {
-    if s.len() != 1 {
+    if s.chars().count() != 1 {
         return None;
     }
     s.chars().next()
@@ -108,6 +118,7 @@ impl TransitionTableBuilder<String> for MachineTableParser {
     type InputTy = char;
     type ErrorTy = TransitionTableError;
 
+    #[allow(clippy::comparison_to_empty, clippy::unwrap_or_default)]
     fn parse_line(&mut self, line: &str) -> Result<(), TransitionTableError> {
         let line = line.trim();
         // Skip whitespace
@@ -153,6 +164,7 @@ impl TransitionTableBuilder<String> for MachineTableParser {
         Ok(())
     }
 
+    #[allow(clippy::map_flatten)]
     fn states(&self) -> HashSet<String> {
         //self.transitions.keys().cloned().collect()
         self.transitions
@@ -167,6 +179,7 @@ impl TransitionTableBuilder<String> for MachineTableParser {
             .collect()
     }
 
+    #[allow(clippy::map_flatten)]
     fn alphabet(&self) -> HashSet<char> {
         self.transitions
             .values()
@@ -191,8 +204,10 @@ impl TransitionTableBuilder<String> for MachineTableParser {
 #[derive(Default, Debug)]
 pub struct MachineParser {
     starting_state: Option<String>,
-    accept_state: Option<String>,
-    reject_state: Option<String>,
+    accept_states: HashSet<String>,
+    reject_states: HashSet<String>,
+    implicit_reject: Option<String>,
+    halt_states: HashSet<String>,
 
     states: HashSet<String>,
     alphabet: HashSet<char>,
@@ -200,12 +215,12 @@ pub struct MachineParser {
 }
 
 impl MachineParser {
-    fn has_accept_state(&self) -> bool {
-        self.accept_state.is_some()
+    fn has_accept_or_halt_state(&self) -> bool {
+        !(self.accept_states.is_empty() && self.halt_states.is_empty())
     }
 
     fn has_reject_state(&self) -> bool {
-        self.reject_state.is_some()
+        !self.reject_states.is_empty()
     }
 
     fn alphabet_len(&self) -> usize {
@@ -219,27 +234,19 @@ impl MachineRepresentationBuilder<String> for MachineParser {
     type ErrorTy = ParsingError;
 
     fn add_state(&mut self, state: String, value: State) -> Result<(), ParsingError> {
-        if let Some(accept_state) = &self.accept_state {
-            if accept_state == &state || value.is_accepting() {
-                return Err(ParsingError::States(StateError::DuplicateAcceptingState));
-            }
-        }
-
-        if let Some(reject_state) = &self.reject_state {
-            if reject_state == &state || value.is_rejecting() {
-                return Err(ParsingError::States(StateError::DuplicateRejectingState));
-            }
-        }
-
         if !self.states.insert(state.clone()) {
             return Err(ParsingError::States(StateError::DuplicateState(state)));
         }
 
         match value {
-            State::Accepting => self.accept_state = Some(state),
-            State::Rejecting => self.reject_state = Some(state),
-            _ => {}
-        }
+            State::Accepting => self.accept_states.insert(state),
+            State::Rejecting => {
+                self.implicit_reject.get_or_insert_with(|| state.clone());
+                self.reject_states.insert(state)
+            }
+            State::Halting => self.halt_states.insert(state),
+            State::Neutral => true,
+        };
         Ok(())
     }
 
@@ -272,11 +279,20 @@ impl MachineRepresentationBuilder<String> for MachineParser {
         &self.starting_state
     }
 
-    fn accepting_state(&self) -> &Option<String> {
-        &self.accept_state
+    fn accepting_states(&self) -> &HashSet<String> {
+        &self.accept_states
     }
-    fn rejecting_state(&self) -> &Option<String> {
-        &self.reject_state
+
+    fn rejecting_states(&self) -> &HashSet<String> {
+        &self.reject_states
+    }
+
+    fn implicit_reject(&self) -> Option<&String> {
+        self.implicit_reject.as_ref()
+    }
+
+    fn halting_states(&self) -> &HashSet<String> {
+        &self.halt_states
     }
 
     fn alphabet(&self) -> &HashSet<char> {
@@ -290,10 +306,11 @@ impl MachineRepresentationBuilder<String> for MachineParser {
 
 lazy_static! {
     static ref INVALID_STATE_NAMES: HashSet<&'static str> = {
-        let mut s = HashSet::with_capacity(3);
+        let mut s = HashSet::with_capacity(4);
         s.insert("alphabet");
         s.insert("+");
         s.insert("-");
+        s.insert("!");
         s
     };
 }
@@ -367,6 +384,7 @@ pub fn parse(source: impl Read) -> Result<MachineParser, ParsingError> {
         let acceptance = match tokens.get(1).map(|s| s.trim()) {
             Some("+") => State::Accepting,
             Some("-") => State::Rejecting,
+            Some("!") => State::Halting,
             None => State::Neutral,
             Some(symb) => {
                 return Err(ParsingError::States(StateError::InvalidStateSymbol(
@@ -378,10 +396,10 @@ pub fn parse(source: impl Read) -> Result<MachineParser, ParsingError> {
         starting_state.get_or_insert(state_name.to_string());
     }
 
-    // If we haven't set the starting state, error out. Same if no accepting state or reject states
+    // If we haven't set the starting state, error out. Same if no reject states, or neither accept nor halt states
     if starting_state.is_none()
         || !repr_builder.has_reject_state()
-        || !repr_builder.has_accept_state()
+        || !repr_builder.has_accept_or_halt_state()
     {
         return Err(ParsingError::States(StateError::MandatoryStatesNotSet));
     }
@@ -415,7 +433,7 @@ pub fn parse(source: impl Read) -> Result<MachineParser, ParsingError> {
         if token.is_empty() {
             continue;
         }
-        if token.len() != 1 {
+        if token.chars().count() != 1 {
             return Err(ParsingError::Alphabet(AlphabetError::TokenNotAChar(
                 token.to_string(),
             )));
@@ -448,6 +466,88 @@ pub fn parse(source: impl Read) -> Result<MachineParser, ParsingError> {
     Ok(repr_builder)
 }
 
+/// Function used to write a representation in the format read by [`parse`](fn.parse.html).  
+/// The starting state comes first, as the format requires, followed by the others in name order.
+/// The format needs a symbol besides the blank, so a machine without one is an `InvalidInput` error, and nothing is written
+pub fn write<Repr>(repr: &Repr, w: impl Write) -> io::Result<()>
+where
+    Repr: MachineRepresentation<String, InputTy = char>,
+    Repr::OutputTy: ActionSet<String>,
+{
+    if repr.alphabet().iter().all(|c| *c == '_') {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "the alphabet needs a symbol besides the blank",
+        ));
+    }
+    write_unchecked(repr, w)
+}
+
+/// Write a representation as [`write`](fn.write.html) does, even when [`parse`](fn.parse.html) cannot read it back
+pub(crate) fn write_unchecked<Repr>(repr: &Repr, mut w: impl Write) -> io::Result<()>
+where
+    Repr: MachineRepresentation<String, InputTy = char>,
+    Repr::OutputTy: ActionSet<String>,
+{
+    let mut states: Vec<&String> = repr
+        .states()
+        .iter()
+        .filter(|s| *s != repr.starting_state())
+        .collect();
+    states.sort();
+    states.insert(0, repr.starting_state());
+
+    writeln!(w, "states {}", states.len())?;
+    for state in &states {
+        match repr.state_kind(state) {
+            State::Accepting => writeln!(w, "{} +", state)?,
+            State::Rejecting => writeln!(w, "{} -", state)?,
+            State::Halting => writeln!(w, "{} !", state)?,
+            State::Neutral => writeln!(w, "{}", state)?,
+        }
+    }
+
+    let mut alphabet: Vec<char> = repr
+        .alphabet()
+        .iter()
+        .cloned()
+        .filter(|c| *c != '_')
+        .collect();
+    alphabet.sort();
+    let symbols: Vec<String> = alphabet.iter().map(char::to_string).collect();
+    writeln!(w, "alphabet {} {}", symbols.len(), symbols.join(" "))?;
+
+    alphabet.insert(0, '_');
+    for state in &states {
+        for c in &alphabet {
+            let mut actions = repr
+                .transition_table()
+                .apply_transition_table(state, *c)
+                .map(|out| out.actions())
+                .unwrap_or_default();
+            actions.sort_by(|a1, a2| {
+                (a1.next_state(), a1.tape_output(), a1.motion()).cmp(&(
+                    a2.next_state(),
+                    a2.tape_output(),
+                    a2.motion(),
+                ))
+            });
+            for act in actions {
+                writeln!(
+                    w,
+                    "{} {} {} {} {}",
+                    state,
+                    c,
+                    act.next_state(),
+                    act.tape_output(),
+                    act.motion()
+                )?;
+            }
+        }
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -459,6 +559,7 @@ mod tests {
         &transition_builder.transitions
     }
 
+    #[allow(clippy::needless_return)]
     fn are_vecs_equal(a: &[(char, Action<String>)], b: &[(char, Action<String>)]) -> bool {
         if a.len() != b.len() {
             return false;
@@ -480,6 +581,10 @@ mod tests {
         return true;
     }
 
+    fn set(state: String) -> HashSet<String> {
+        vec![state].into_iter().collect()
+    }
+
     #[test]
     fn valid_example() {
         let test_string = "states 4\ns0\ns1\ns2 +\nqr -\nalphabet 2 a b\ns0 a s0 a R\ns0 b s1 b R\ns0 _ s2 _ S\ns1 b s1 b R\ns1 _ s2 _ S";
@@ -493,8 +598,9 @@ mod tests {
         let accepting_state = "s2".to_string();
         let rejecting_state = "qr".to_string();
         let states_test = ["s0", "s1"];
-        assert_eq!(*representation.accepting_state(), Some(accepting_state));
-        assert_eq!(*representation.rejecting_state(), Some(rejecting_state));
+        assert_eq!(*representation.accepting_states(), set(accepting_state));
+        assert_eq!(*representation.rejecting_states(), set(rejecting_state));
+        assert!(representation.halting_states().is_empty());
         for s in states_test.iter() {
             assert!(states.contains(*s));
         }
@@ -547,8 +653,8 @@ mod tests {
         let accepting_state = "qa".to_string();
         let rejecting_state = "qr".to_string();
         let states_test = ["q0", "q1"];
-        assert_eq!(*representation.accepting_state(), Some(accepting_state));
-        assert_eq!(*representation.rejecting_state(), Some(rejecting_state));
+        assert_eq!(*representation.accepting_states(), set(accepting_state));
+        assert_eq!(*representation.rejecting_states(), set(rejecting_state));
         for s in states_test.iter() {
             assert!(states.contains(*s));
         }
@@ -589,6 +695,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn multiple_final_states() {
+        let test_string =
+            "states 5\nq0\nqa +\nqb +\nqh !\nqr -\nalphabet 1 a\nq0 a qa a R\nq0 _ qh _ S";
+        let result = parse(test_string.as_bytes().by_ref());
+        let representation = result.expect("The parse should have succeded");
+
+        assert_eq!(representation.accepting_states().len(), 2);
+        assert!(representation.accepting_states().contains("qa"));
+        assert!(representation.accepting_states().contains("qb"));
+        assert_eq!(representation.rejecting_states().len(), 1);
+        assert_eq!(representation.halting_states().len(), 1);
+        assert!(representation.halting_states().contains("qh"));
+    }
+
+    #[test]
+    fn implicit_reject_is_the_first_rejecting_state() {
+        let test_string = "states 4\nq0\nqr -\nqa +\nqe -\nalphabet 1 a\nq0 a qa a R";
+        let result = parse(test_string.as_bytes().by_ref());
+        let representation = result.expect("The parse should have succeded");
+
+        assert_eq!(representation.implicit_reject(), Some(&"qr".to_string()));
+    }
+
+    #[test]
+    fn halting_state_without_accepting_state() {
+        let test_string = "states 3\nq0\nqh !\nqr -\nalphabet 1 a\nq0 a qh a R";
+        let result = parse(test_string.as_bytes().by_ref());
+        assert!(result.is_ok());
+    }
+
+    #[test]
+    fn missing_rejecting_state() {
+        let test_string = "states 2\nq0\nqa +\nalphabet 1 a\nq0 a qa a R";
+        let result = parse(test_string.as_bytes().by_ref());
+        match result {
+            Err(ParsingError::States(StateError::MandatoryStatesNotSet)) => {}
+            _ => panic!("Invalid Enum Variant"),
+        }
+    }
+
+    #[test]
+    fn duplicate_state() {
+        let test_string = "states 3\nq0\nqa +\nqa -\nalphabet 1 a";
+        let result = parse(test_string.as_bytes().by_ref());
+        match result {
+            Err(ParsingError::States(StateError::DuplicateState(s))) => assert_eq!(s, "qa"),
+            _ => panic!("Invalid Enum Variant"),
+        }
+    }
+
     /// TODO, add a test for each error type
 
     #[test]
@@ -612,4 +769,37 @@ mod tests {
             _ => panic!("Invalid Enum Variant"),
         }
     }
+
+    #[test]
+    fn write_rejects_blank_only_alphabets() {
+        // The busy beaver notation can describe machines which only ever write blanks
+        let repr = crate::busy_beaver::parse("0RZ").unwrap();
+        let mut written = Vec::new();
+        let e = write(&repr, &mut written).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+        assert!(written.is_empty());
+
+        // Snapshots still identify them
+        crate::snapshot::machine_hash(&repr);
+    }
+
+    #[test]
+    fn write_round_trip() {
+        use crate::deterministic_tm::DeterministicMachineRepresentation;
+
+        let parser = parse(include_str!("../data/paren.tm").as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let mut written = Vec::new();
+        write(&repr, &mut written).unwrap();
+        assert!(written.starts_with(b"states 6\nstart\n"));
+
+        let reparsed = parse(&written[..]).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&reparsed).unwrap();
+        let mut rewritten = Vec::new();
+        write(&repr, &mut rewritten).unwrap();
+        assert_eq!(
+            String::from_utf8(written).unwrap(),
+            String::from_utf8(rewritten).unwrap()
+        );
+    }
 }
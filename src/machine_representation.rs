@@ -1,54 +1,70 @@
-use hashbrown::HashSet;
-use std::{fmt::Debug, hash::Hash};
-
-use crate::{
-    builders::{MachineRepresentationBuilder, TransitionTableBuilder},
-    common::StateTrait,
-    transition_table::TransitionTable,
-};
-
-/// The representation of a Turing Machine. Note that, as from lecture, the only difference is in the Function (aka the [`TransitionTable`](../transition_table/trait.TransitionTable.html))  
-pub trait MachineRepresentation<StateTy>: Sized
-where
-    StateTy: StateTrait,
-{
-    /// The `InputTy` of the underlying `TransitionTable`
-    type InputTy: Eq + Hash;
-
-    /// The `OutputTy` of the underlying `TransitionTable`
-    type OutputTy;
-
-    /// The underlying `TransitionTable`
-    type TableTy: TransitionTable<StateTy, InputTy = Self::InputTy, OutputTy = Self::OutputTy>;
-
-    /// The Error type raised on invalid construction
-    type ErrorTy: Debug;
-
-    /// Get the list of states in the representation
-    fn states(&self) -> &HashSet<StateTy>;
-
-    /// Get the starting state
-    fn starting_state(&self) -> &StateTy;
-
-    /// Get the accepting state
-    fn accepting_state(&self) -> &StateTy;
-
-    /// Get the rejecting state
-    fn rejecting_state(&self) -> &StateTy;
-
-    /// Get the alphabet
-    fn alphabet(&self) -> &HashSet<Self::InputTy>;
-
-    /// Get the transition table
-    fn transition_table(&self) -> &Self::TableTy;
-
-    /// Build the representation from a [`MachineRepresentationBuilder`](../builders/trait.MachineRepresentationBuilder.html)  
-    /// Note that we ensure the types match
-    fn from_builder<Builder>(b: &Builder) -> Result<Self, Self::ErrorTy>
-    where
-        Builder: MachineRepresentationBuilder<StateTy>,
-        Builder::TableBuilder: TransitionTableBuilder<
-            StateTy,
-            InputTy = <Self::TableTy as TransitionTable<StateTy>>::InputTy,
-        >;
-}
+use hashbrown::HashSet;
+use std::{fmt::Debug, hash::Hash};
+
+use crate::{
+    builders::{MachineRepresentationBuilder, TransitionTableBuilder},
+    common::{State, StateTrait},
+    transition_table::TransitionTable,
+};
+
+/// The representation of a Turing Machine. Note that, as from lecture, the only difference is in the Function (aka the [`TransitionTable`](../transition_table/trait.TransitionTable.html))  
+pub trait MachineRepresentation<StateTy>: Sized
+where
+    StateTy: StateTrait,
+{
+    /// The `InputTy` of the underlying `TransitionTable`
+    type InputTy: Eq + Hash;
+
+    /// The `OutputTy` of the underlying `TransitionTable`
+    type OutputTy;
+
+    /// The underlying `TransitionTable`
+    type TableTy: TransitionTable<StateTy, InputTy = Self::InputTy, OutputTy = Self::OutputTy>;
+
+    /// The Error type raised on invalid construction
+    type ErrorTy: Debug;
+
+    /// Get the list of states in the representation
+    fn states(&self) -> &HashSet<StateTy>;
+
+    /// Get the starting state
+    fn starting_state(&self) -> &StateTy;
+
+    /// Get the set of accepting states
+    fn accepting_states(&self) -> &HashSet<StateTy>;
+
+    /// Get the set of rejecting states
+    fn rejecting_states(&self) -> &HashSet<StateTy>;
+
+    /// Get the set of plain halting states, which stop the machine without accepting or rejecting
+    fn halting_states(&self) -> &HashSet<StateTy>;
+
+    /// Get the characteristic of a state
+    fn state_kind(&self, state: &StateTy) -> State {
+        if self.accepting_states().contains(state) {
+            State::Accepting
+        } else if self.rejecting_states().contains(state) {
+            State::Rejecting
+        } else if self.halting_states().contains(state) {
+            State::Halting
+        } else {
+            State::Neutral
+        }
+    }
+
+    /// Get the alphabet
+    fn alphabet(&self) -> &HashSet<Self::InputTy>;
+
+    /// Get the transition table
+    fn transition_table(&self) -> &Self::TableTy;
+
+    /// Build the representation from a [`MachineRepresentationBuilder`](../builders/trait.MachineRepresentationBuilder.html)  
+    /// Note that we ensure the types match
+    fn from_builder<Builder>(b: &Builder) -> Result<Self, Self::ErrorTy>
+    where
+        Builder: MachineRepresentationBuilder<StateTy>,
+        Builder::TableBuilder: TransitionTableBuilder<
+            StateTy,
+            InputTy = <Self::TableTy as TransitionTable<StateTy>>::InputTy,
+        >;
+}
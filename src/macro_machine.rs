@@ -0,0 +1,643 @@
+use hashbrown::HashMap;
+use log::debug;
+use std::{convert::TryFrom, fmt};
+
+use crate::{
+    big::BigUint,
+    builders::TuringMachineBuilder,
+    common::{Motion, StateTrait},
+    deterministic_tm::{DeterministicMachineRepresentation, MachineCreationError},
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+    TuringMachine,
+};
+
+/// How many cells make a block for [`MacroTuringMachine::from_builder`](struct.MacroTuringMachine.html#method.from_builder)
+pub const DEFAULT_BLOCK_SIZE: usize = 1;
+
+/// Tapes with more cells than this are displayed [`compressed`](struct.MacroTape.html#method.compressed) rather than cell by cell
+pub const MAX_DISPLAYED_CELLS: u64 = 1 << 20;
+
+/// One of the two edges of a block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Side {
+    Left,
+    Right,
+}
+
+/// `count` copies of the same block next to each other
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BlockRun {
+    pub block: Vec<char>,
+    pub count: u64,
+}
+
+/// The tape of a [`MacroTuringMachine`](struct.MacroTuringMachine.html), as runs of blocks either side of the head.
+/// Past the last run on the right the tape is blank.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MacroTape {
+    block_size: usize,
+    /// The runs left of the head, the closest last
+    left: Vec<BlockRun>,
+    /// The runs right of the head, the closest last
+    right: Vec<BlockRun>,
+}
+
+impl MacroTape {
+    fn new(block_size: usize, tape: &[char]) -> Self {
+        let mut res = Self {
+            block_size,
+            left: Vec::new(),
+            right: Vec::new(),
+        };
+        for chunk in tape.chunks(block_size).rev() {
+            let mut block = chunk.to_vec();
+            block.resize(block_size, '_');
+            res.push(Side::Right, block, 1);
+        }
+        res
+    }
+
+    fn blank(&self) -> Vec<char> {
+        vec!['_'; self.block_size]
+    }
+
+    fn stack(&mut self, side: Side) -> &mut Vec<BlockRun> {
+        match side {
+            Side::Left => &mut self.left,
+            Side::Right => &mut self.right,
+        }
+    }
+
+    /// Put `count` copies of a block next to the head on the given side
+    fn push(&mut self, side: Side, block: Vec<char>, count: u64) {
+        if side == Side::Right && self.right.is_empty() && block == self.blank() {
+            return;
+        }
+        let stack = self.stack(side);
+        match stack.last_mut() {
+            Some(run) if run.block == block => run.count += count,
+            _ => stack.push(BlockRun { block, count }),
+        }
+    }
+
+    /// Take up to `count` copies of the block next to the head on the given side, blank past the end of the tape
+    fn pop(&mut self, side: Side, count: u64) -> (Vec<char>, u64) {
+        let blank = self.blank();
+        let stack = self.stack(side);
+        match stack.last_mut() {
+            Some(run) if run.count > count => {
+                run.count -= count;
+                (run.block.clone(), count)
+            }
+            Some(_) => {
+                let run = stack.pop().unwrap();
+                (run.block, run.count)
+            }
+            None => (blank, 1),
+        }
+    }
+
+    /// The size of blocks the tape is made of
+    pub fn block_size(&self) -> usize {
+        self.block_size
+    }
+
+    /// Get the runs from the first cell to the last one stored
+    pub fn runs(&self) -> impl Iterator<Item = &BlockRun> {
+        self.left.iter().chain(self.right.iter().rev())
+    }
+
+    /// How many cells are left of the head's block
+    fn cells_left(&self) -> u64 {
+        self.left.iter().map(|r| r.count).sum::<u64>() * self.block_size as u64
+    }
+
+    /// How many stored cells hold something other than a blank
+    pub fn non_blank(&self) -> u64 {
+        self.runs()
+            .map(|r| r.block.iter().filter(|c| **c != '_').count() as u64 * r.count)
+            .sum()
+    }
+
+    /// The runs from the first cell, without the blank ones at the end
+    fn trimmed_runs(&self) -> Vec<&BlockRun> {
+        let blank = self.blank();
+        let mut runs: Vec<&BlockRun> = self.runs().collect();
+        while runs.last().is_some_and(|r| r.block == blank) {
+            runs.pop();
+        }
+        runs
+    }
+
+    /// Write the runs from the first cell, without the blank ones at the end, or nothing for a blank tape.
+    /// Runs are separated by spaces, and a run of more than one block is written as the block followed by `^` and the count,
+    /// as [snapshots](../snapshot/struct.Snapshot.html) write tapes. Blocks are always `block_size` cells, so this reads back
+    /// unambiguously whatever the symbols are.
+    pub fn compressed(&self) -> String {
+        self.trimmed_runs()
+            .iter()
+            .map(|run| {
+                let block: String = run.block.iter().collect();
+                match run.count {
+                    1 => block,
+                    count => format!("{}^{}", block, count),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
+    /// Get the stored cells one by one, which might not fit in memory for huge tapes.
+    /// Those are displayed [`compressed`](#method.compressed) instead.
+    pub fn symbols(&self) -> Vec<char> {
+        let mut symbols = Vec::new();
+        for run in self.runs() {
+            for _ in 0..run.count {
+                symbols.extend_from_slice(&run.block);
+            }
+        }
+        symbols
+    }
+}
+
+/// Write the cells from the first one, without the blanks at the end, as the plain engine does.
+/// Tapes longer than [`MAX_DISPLAYED_CELLS`](constant.MAX_DISPLAYED_CELLS.html) are written [`compressed`](struct.MacroTape.html#method.compressed) instead.
+impl fmt::Display for MacroTape {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let runs = self.trimmed_runs();
+        let cells = runs.iter().try_fold(0u64, |cells, run| {
+            run.count
+                .checked_mul(self.block_size as u64)
+                .and_then(|n| cells.checked_add(n))
+        });
+        match cells {
+            Some(0) => return write!(f, "_"),
+            Some(n) if n <= MAX_DISPLAYED_CELLS => {}
+            _ => return write!(f, "{}", self.compressed()),
+        }
+
+        let (last, rest) = runs.split_last().unwrap();
+        for run in rest {
+            let block: String = run.block.iter().collect();
+            for _ in 0..run.count {
+                write!(f, "{}", block)?;
+            }
+        }
+        let block: String = last.block.iter().collect();
+        for _ in 1..last.count {
+            write!(f, "{}", block)?;
+        }
+        write!(f, "{}", block.trim_end_matches('_'))
+    }
+}
+
+/// What the machine does once it enters a block, until it leaves it
+#[derive(Debug, Clone)]
+enum Outcome<StateTy> {
+    /// Left through an edge, maybe in a final state
+    Exit {
+        state: StateTy,
+        block: Vec<char>,
+        side: Side,
+        steps: u64,
+    },
+    /// Reached a final state with the head inside the block
+    Stop {
+        state: StateTy,
+        block: Vec<char>,
+        cell: usize,
+        steps: u64,
+    },
+    /// Never leaves the block nor stops
+    Loop,
+}
+
+/// The block a macro transition starts from: the state, the block, the edge the head enters from, and whether it is the first block of the tape
+type Entry<StateTy> = (StateTy, Vec<char>, Side, bool);
+
+/// A deterministic machine simulated as a macro machine, as is done to run busy beaver candidates.
+///
+/// The tape is split in blocks of `block_size` cells, and the machine is run a block at a time: from entering a block at one edge, to leaving it at either.
+/// These macro transitions are found by simulating the machine inside the block, and remembered.
+/// The tape is stored as runs of repeated blocks, and a macro transition which leaves the state as it was and crosses the block is applied to the whole run at once.
+/// This makes machines which sweep back and forth over growing regions take time linear in the number of sweeps rather than steps.
+///
+/// Steps are counted as a [`BigUint`](../big/struct.BigUint.html), as runs can take more steps than a `usize` holds in reasonable time.
+/// A machine which is found to be stuck inside a block is reported as rejecting, as [`LimitedTuringMachineExt`](../limited/struct.LimitedTuringMachineExt.html) does for runs over the limit.
+#[derive(Debug)]
+pub struct MacroTuringMachine<StateTy>
+where
+    StateTy: StateTrait,
+{
+    representation: DeterministicMachineRepresentation<StateTy>,
+    tape: MacroTape,
+    state: StateTy,
+    /// The head is on the first cell of the closest block on the right, or the last cell of the closest block on the left
+    facing: Side,
+    /// The cell of the block the head stopped on, when a machine stops inside a block
+    offset: usize,
+    looping: bool,
+    steps: BigUint,
+    macro_steps: u64,
+    /// An upper bound on the steps the machine can take in a block without looping
+    cap: u64,
+    transitions: HashMap<Entry<StateTy>, Outcome<StateTy>>,
+}
+
+impl<StateTy> MacroTuringMachine<StateTy>
+where
+    StateTy: StateTrait,
+{
+    /// Build a machine with the given block size.
+    /// Panics if `block_size` is 0.
+    pub fn with_block_size(
+        builder: TuringMachineBuilder<StateTy, DeterministicMachineRepresentation<StateTy>>,
+        block_size: usize,
+    ) -> Result<Self, MachineCreationError> {
+        assert!(block_size > 0, "The block size must be positive");
+        let (tape, repr) = builder.validate()?.decompose();
+
+        // There are at most this many configurations inside a block, counting the blank in case it is not in the alphabet
+        let symbols = repr.alphabet().len() as u64 + 1;
+        let cap = u32::try_from(block_size)
+            .ok()
+            .and_then(|k| symbols.checked_pow(k))
+            .and_then(|n| n.checked_mul(repr.states().len() as u64 * block_size as u64))
+            .unwrap_or(u64::MAX);
+
+        Ok(Self {
+            tape: MacroTape::new(block_size, &tape),
+            state: repr.starting_state().clone(),
+            representation: repr,
+            facing: Side::Right,
+            offset: 0,
+            looping: false,
+            steps: BigUint::zero(),
+            macro_steps: 0,
+            cap,
+            transitions: HashMap::new(),
+        })
+    }
+
+    /// Return how many steps of the machine have been taken so far
+    pub fn get_number_of_steps(&self) -> &BigUint {
+        &self.steps
+    }
+
+    /// Return how many macro transitions the steps so far took, a run of blocks crossed at once counting as one
+    pub fn get_number_of_macro_steps(&self) -> u64 {
+        self.macro_steps
+    }
+
+    /// How many different macro transitions have been needed so far
+    pub fn get_number_of_macro_transitions(&self) -> usize {
+        self.transitions.len()
+    }
+
+    /// Get the state the machine is currently in
+    pub fn current_state(&self) -> &StateTy {
+        &self.state
+    }
+
+    /// Get the position of the head on the tape
+    pub fn head(&self) -> u64 {
+        match self.facing {
+            Side::Right => self.tape.cells_left() + self.offset as u64,
+            Side::Left => self.tape.cells_left() - 1,
+        }
+    }
+
+    /// Has the machine been found to never leave a block nor stop?
+    pub fn is_looping(&self) -> bool {
+        self.looping
+    }
+
+    /// Find what happens when the machine enters a block, simulating it step by step
+    fn simulate(&self, entry: &Entry<StateTy>) -> Outcome<StateTy> {
+        let (state, block, side, first) = entry;
+        let (mut state, mut block) = (state.clone(), block.clone());
+        let mut cell = match side {
+            Side::Left => 0,
+            Side::Right => self.tape.block_size - 1,
+        };
+
+        let mut steps = 0;
+        loop {
+            if self.representation.state_kind(&state).is_final() {
+                return Outcome::Stop {
+                    state,
+                    block,
+                    cell,
+                    steps,
+                };
+            }
+            if steps > self.cap {
+                return Outcome::Loop;
+            }
+
+            let read = block[cell];
+            let (next, output, motion) = match self
+                .representation
+                .transition_table()
+                .apply_transition_table(&state, read)
+            {
+                Some(act) => (act.next_state().clone(), *act.tape_output(), *act.motion()),
+                // Missing transitions implicitly move to a rejecting state, as in the plain engine
+                None => (
+                    self.representation.implicit_reject().clone(),
+                    read,
+                    Motion::Left,
+                ),
+            };
+            block[cell] = output;
+            state = next;
+            steps += 1;
+
+            match motion {
+                Motion::Right if cell + 1 == block.len() => {
+                    return Outcome::Exit {
+                        state,
+                        block,
+                        side: Side::Right,
+                        steps,
+                    }
+                }
+                Motion::Right => cell += 1,
+                // Moving left from the first cell of the tape stays there
+                Motion::Left if cell == 0 && *first => {}
+                Motion::Left if cell == 0 => {
+                    return Outcome::Exit {
+                        state,
+                        block,
+                        side: Side::Left,
+                        steps,
+                    }
+                }
+                Motion::Left => cell -= 1,
+                Motion::Stay => {}
+            }
+        }
+    }
+
+    fn transition(&mut self, entry: Entry<StateTy>) -> Outcome<StateTy> {
+        if let Some(outcome) = self.transitions.get(&entry) {
+            return outcome.clone();
+        }
+        let outcome = self.simulate(&entry);
+        debug!("New macro transition {:?} -> {:?}", entry, outcome);
+        self.transitions.insert(entry, outcome.clone());
+        outcome
+    }
+
+    /// Apply a single macro transition, or cross a whole run of blocks.
+    /// Returns the number of steps this took.
+    pub fn macro_step(&mut self) -> BigUint {
+        if self.is_finished() {
+            return BigUint::zero();
+        }
+
+        // The head comes into the closest block from the edge it faces, and from the other side that is the edge it leaves through to cross it
+        let from = self.facing;
+        let into = match from {
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        };
+        let (block, available) = match self.tape.stack(from).last() {
+            Some(run) => (run.block.clone(), run.count),
+            None => (self.tape.blank(), 1),
+        };
+
+        // Only the first block of the tape keeps the head from moving left, so it is never part of a crossed run
+        let (first, rest) = match from {
+            Side::Right if self.tape.left.is_empty() => (true, 0),
+            Side::Left if self.tape.left.len() == 1 => (available == 1, available - 1),
+            _ => (false, available),
+        };
+
+        let outcome = self.transition((self.state.clone(), block, into, first));
+        let steps = match outcome {
+            Outcome::Exit {
+                state,
+                block,
+                side,
+                steps,
+            } => {
+                let crossing = state == self.state && side == from && rest > 0;
+                let (_, count) = self.tape.pop(from, if crossing { rest } else { 1 });
+                let behind = match side {
+                    Side::Left => Side::Right,
+                    Side::Right => Side::Left,
+                };
+                self.tape.push(behind, block, count);
+                self.facing = side;
+                self.state = state;
+                u128::from(steps) * u128::from(count)
+            }
+            Outcome::Stop {
+                state,
+                block,
+                cell,
+                steps,
+            } => {
+                // Blank blocks are not pushed at the end of the tape, but head() needs the head's block to be there
+                self.tape.pop(from, 1);
+                self.tape.right.push(BlockRun { block, count: 1 });
+                self.facing = Side::Right;
+                self.offset = cell;
+                self.state = state;
+                u128::from(steps)
+            }
+            // The block is left as it was entered, and the machine never takes another step
+            Outcome::Loop => {
+                self.looping = true;
+                0
+            }
+        };
+
+        let steps = BigUint::from(steps);
+        self.steps += &steps;
+        self.macro_steps += 1;
+        steps
+    }
+}
+
+impl<StateTy> TuringMachine for MacroTuringMachine<StateTy>
+where
+    StateTy: StateTrait,
+{
+    type Tape = MacroTape;
+    type StateTy = StateTy;
+    type ReprTy = DeterministicMachineRepresentation<StateTy>;
+    type ErrorTy = MachineCreationError;
+
+    /// Build a machine with blocks of [`DEFAULT_BLOCK_SIZE`](constant.DEFAULT_BLOCK_SIZE.html) cells
+    fn from_builder(
+        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
+    ) -> Result<Self, Self::ErrorTy> {
+        Self::with_block_size(builder, DEFAULT_BLOCK_SIZE)
+    }
+
+    /// Take a [`macro_step`](struct.MacroTuringMachine.html#method.macro_step), which may stand for many steps of the machine
+    fn step(&mut self) {
+        self.macro_step();
+    }
+
+    fn tape(&self) -> &Self::Tape {
+        &self.tape
+    }
+
+    fn is_accepting(&self) -> bool {
+        self.representation.accepting_states().contains(&self.state)
+    }
+
+    fn is_rejecting(&self) -> bool {
+        self.looping || self.representation.rejecting_states().contains(&self.state)
+    }
+
+    fn is_halting(&self) -> bool {
+        self.representation.halting_states().contains(&self.state)
+    }
+}
+
+impl<StateTy> fmt::Display for MacroTuringMachine<StateTy>
+where
+    StateTy: StateTrait,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{}", self.tape)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{deterministic_tm::DeterministicTuringMachine, machine_parser, tape::Tape};
+
+    fn builder(
+        source: &str,
+        tape: &str,
+    ) -> TuringMachineBuilder<String, DeterministicMachineRepresentation<String>> {
+        let repr = DeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(source.as_bytes()).unwrap(),
+        )
+        .unwrap();
+        TuringMachineBuilder::new()
+            .repr(repr)
+            .tape(tape.chars().collect())
+    }
+
+    fn trimmed(mut symbols: Vec<char>) -> Vec<char> {
+        while symbols.last() == Some(&'_') {
+            symbols.pop();
+        }
+        symbols
+    }
+
+    /// Check the macro machine is in the same configuration as the plain one after each of its first `macro_steps` macro steps
+    fn follows(source: &str, tape: &str, block_size: usize, macro_steps: usize) {
+        let mut plain =
+            DeterministicTuringMachine::<String>::from_builder(builder(source, tape)).unwrap();
+        let mut fast =
+            MacroTuringMachine::with_block_size(builder(source, tape), block_size).unwrap();
+        let mut steps = 0;
+        for _ in 0..macro_steps {
+            fast.step();
+            let target = fast.get_number_of_steps().to_u128().unwrap();
+            while steps < target {
+                plain.step();
+                steps += 1;
+            }
+
+            assert_eq!(fast.current_state(), plain.current_state());
+            assert_eq!(fast.head(), plain.head() as u64);
+            assert_eq!(
+                trimmed(fast.tape().symbols()),
+                trimmed(plain.tape().symbols())
+            );
+            assert_eq!(fast.is_accepting(), plain.is_accepting());
+            assert_eq!(fast.is_finished(), plain.is_finished());
+            if fast.is_finished() {
+                break;
+            }
+        }
+    }
+
+    #[test]
+    fn follows_the_plain_machine() {
+        let sqrt2 = include_str!("../data/sqrt2.tm");
+        let paren = include_str!("../data/paren.tm");
+        let binadd = include_str!("../data/binadd.tm");
+        for block_size in 1..5 {
+            follows(sqrt2, "", block_size, 2000);
+            follows(paren, "(()(()))()", block_size, 2000);
+            follows(paren, "(()(())", block_size, 2000);
+            follows(
+                paren,
+                &format!("{}{}", "(".repeat(40), ")".repeat(40)),
+                block_size,
+                2000,
+            );
+            follows(binadd, "1011#110", block_size, 2000);
+        }
+    }
+
+    #[test]
+    fn counts_steps_past_the_plain_engine() {
+        // Nested parentheses take 2n^2 + 4n + 1 steps
+        let n = 100_000;
+        let paren = include_str!("../data/paren.tm");
+        let nested = format!("{}{}", "(".repeat(n), ")".repeat(n));
+        let mut tm = MacroTuringMachine::with_block_size(builder(paren, &nested), 1).unwrap();
+        assert!(tm.run());
+
+        let n = n as u128;
+        assert_eq!(
+            tm.get_number_of_steps().to_u128(),
+            Some(2 * n * n + 4 * n + 1)
+        );
+        assert!(u128::from(tm.get_number_of_macro_steps()) * 1000 < 2 * n * n);
+        assert_eq!(tm.tape().non_blank(), 2 * n as u64);
+        assert_eq!(tm.tape().compressed(), "[ $^199999");
+        assert_eq!(tm.to_string(), format!("[{}\n", "$".repeat(199_999)));
+    }
+
+    #[test]
+    fn displays_runs() {
+        let paren = include_str!("../data/paren.tm");
+        let mut tm = MacroTuringMachine::with_block_size(builder(paren, "((((()"), 2).unwrap();
+        assert_eq!(tm.tape().compressed(), "((^2 ()");
+        assert_eq!(tm.to_string(), "((((()\n");
+        tm.run();
+        assert_eq!(tm.tape().compressed(), "^( (( $$");
+        assert_eq!(tm.to_string(), "^((($$\n");
+
+        let tm = MacroTuringMachine::with_block_size(builder(paren, ""), 2).unwrap();
+        assert_eq!(tm.tape().compressed(), "");
+        assert_eq!(tm.to_string(), "_\n");
+
+        // Too long to write cell by cell
+        let mut tape = MacroTape::new(1, &['1']);
+        tape.push(Side::Left, vec!['1'], 1 << 40);
+        assert_eq!(tape.to_string(), "1^1099511627776 1");
+    }
+
+    #[test]
+    fn finds_loops_inside_a_block() {
+        let bounce =
+            "states 4\na\nb\nhalt !\nreject -\nalphabet 1 1\na _ b 1 R\nb _ a _ L\na 1 b 1 R\n";
+        let mut tm = MacroTuringMachine::with_block_size(builder(bounce, ""), 2).unwrap();
+        tm.step();
+        assert!(tm.is_looping());
+        assert!(tm.is_rejecting());
+        assert!(tm.get_number_of_steps().is_zero());
+
+        // Over blocks of a single cell, it goes back and forth forever
+        let mut tm = MacroTuringMachine::with_block_size(builder(bounce, ""), 1).unwrap();
+        for _ in 0..100 {
+            tm.step();
+        }
+        assert!(!tm.is_finished());
+        assert_eq!(tm.get_number_of_steps().to_u128(), Some(100));
+    }
+}
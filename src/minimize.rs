@@ -0,0 +1,338 @@
+//! State minimization of machines.
+//!
+//! [`minimize`](fn.minimize.html) first drops the states that cannot be reached from the start and the ones from which
+//! no accepting or halting state can be reached, then merges the remaining states that behave the same by partition
+//! refinement on the transition structure, much like DFA minimization.
+//!
+//! Transitions into dropped states become implicit rejects: runs that could only ever reject or loop now reject, which
+//! keeps the accepted language and the output of halting runs the same. Machines meant to run forever, such as
+//! `sqrt2.tm`, should use [`merge_equivalent_states`](fn.merge_equivalent_states.html) instead, which keeps them looping.
+
+use hashbrown::{HashMap, HashSet};
+use std::fmt::Debug;
+
+use crate::{
+    builders::MachineRepresentationBuilder,
+    common::{representation::GeneralMachineRepresentation, Action, Motion, State},
+    machine_parser::MachineParser,
+    machine_representation::MachineRepresentation,
+    transition_table::{ActionSet, TransitionTable},
+};
+
+/// The transitions of a state, for each symbol of the alphabet in order
+type Transitions = Vec<Vec<Action<String>>>;
+
+/// What a state does on a symbol, with the target replaced by its block
+type Signature = Vec<(char, Motion, usize)>;
+
+/// Produce an equivalent machine with as few states as the transition structure allows.
+/// Final states of the same kind are always merged, as they all stop the machine straight away
+pub fn minimize<TableTy>(
+    repr: &GeneralMachineRepresentation<String, TableTy>,
+) -> GeneralMachineRepresentation<String, TableTy>
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    reduce(repr, true)
+}
+
+/// Like [`minimize`](fn.minimize.html), but only drops the unreachable states, so that runs which never stop still don't
+pub fn merge_equivalent_states<TableTy>(
+    repr: &GeneralMachineRepresentation<String, TableTy>,
+) -> GeneralMachineRepresentation<String, TableTy>
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    reduce(repr, false)
+}
+
+fn reduce<TableTy>(
+    repr: &GeneralMachineRepresentation<String, TableTy>,
+    drop_dead: bool,
+) -> GeneralMachineRepresentation<String, TableTy>
+where
+    TableTy: TransitionTable<String, InputTy = char> + Debug,
+    TableTy::OutputTy: ActionSet<String>,
+{
+    let mut alphabet: Vec<char> = repr.alphabet().iter().cloned().collect();
+    alphabet.sort();
+    let is_final = |s: &String| repr.state_kind(s) != State::Neutral;
+
+    // Final states never move, so their transitions are left out
+    let transitions: HashMap<&String, Transitions> = repr
+        .states()
+        .iter()
+        .map(|s| {
+            let actions = alphabet
+                .iter()
+                .map(|c| match is_final(s) {
+                    true => Vec::new(),
+                    false => repr
+                        .transition_table()
+                        .apply_transition_table(s, *c)
+                        .map(|out| out.actions())
+                        .unwrap_or_default(),
+                })
+                .collect();
+            (s, actions)
+        })
+        .collect();
+
+    // Forward reachability from the start
+    let mut reachable = HashSet::new();
+    let mut stack = vec![repr.starting_state()];
+    while let Some(state) = stack.pop() {
+        if !reachable.insert(state) {
+            continue;
+        }
+        for actions in &transitions[state] {
+            stack.extend(actions.iter().map(|act| act.next_state()));
+        }
+    }
+
+    // Backward reachability from the accepting and halting states
+    let mut kept: HashSet<&String> = if drop_dead {
+        let mut predecessors: HashMap<&String, Vec<&String>> = HashMap::new();
+        for state in &reachable {
+            for act in transitions[*state].iter().flatten() {
+                predecessors
+                    .entry(act.next_state())
+                    .or_default()
+                    .push(*state);
+            }
+        }
+        let mut live = HashSet::new();
+        let mut stack: Vec<&String> = reachable
+            .iter()
+            .cloned()
+            .filter(|s| matches!(repr.state_kind(s), State::Accepting | State::Halting))
+            .collect();
+        while let Some(state) = stack.pop() {
+            if live.insert(state) {
+                stack.extend(predecessors.get(state).into_iter().flatten());
+            }
+        }
+        live
+    } else {
+        reachable.clone()
+    };
+
+    // Keep the states the format requires, even if they are useless
+    kept.insert(repr.starting_state());
+    match repr
+        .rejecting_states()
+        .iter()
+        .find(|s| reachable.contains(s))
+    {
+        Some(s) => kept.insert(s),
+        None => kept.insert(repr.implicit_reject()),
+    };
+    if !kept
+        .iter()
+        .any(|s| matches!(repr.state_kind(s), State::Accepting | State::Halting))
+    {
+        let fallback = repr
+            .accepting_states()
+            .iter()
+            .min()
+            .or_else(|| repr.halting_states().iter().min())
+            .expect("A representation always has an accepting or halting state");
+        kept.insert(fallback);
+    }
+
+    let mut states: Vec<&String> = kept.iter().cloned().collect();
+    states.sort();
+
+    // Partition refinement, starting from the kind of each state
+    let mut block: HashMap<&String, usize> = states
+        .iter()
+        .map(|s| {
+            let kind = match repr.state_kind(s) {
+                State::Accepting => 0,
+                State::Rejecting => 1,
+                State::Halting => 2,
+                State::Neutral => 3,
+            };
+            (*s, kind)
+        })
+        .collect();
+    let signature = |s: &String, block: &HashMap<&String, usize>| -> Vec<Signature> {
+        transitions[s]
+            .iter()
+            .map(|actions| {
+                let mut sig: Signature = actions
+                    .iter()
+                    .filter_map(|act| {
+                        block
+                            .get(act.next_state())
+                            .map(|b| (*act.tape_output(), *act.motion(), *b))
+                    })
+                    .collect();
+                sig.sort();
+                sig.dedup();
+                sig
+            })
+            .collect()
+    };
+
+    let mut num_blocks = 0;
+    loop {
+        let mut ids: HashMap<(usize, Vec<Signature>), usize> = HashMap::new();
+        let refined: HashMap<&String, usize> = states
+            .iter()
+            .map(|s| {
+                let key = (block[*s], signature(s, &block));
+                let next_id = ids.len();
+                (*s, *ids.entry(key).or_insert(next_id))
+            })
+            .collect();
+        block = refined;
+        if ids.len() == num_blocks {
+            break;
+        }
+        num_blocks = ids.len();
+    }
+
+    // Each block is named after the starting state, or its first state
+    let mut names: HashMap<usize, &String> = HashMap::new();
+    for state in &states {
+        names.entry(block[*state]).or_insert(state);
+    }
+    names.insert(block[repr.starting_state()], repr.starting_state());
+
+    let mut parser = MachineParser::default();
+    let mut named: Vec<&String> = names.values().cloned().collect();
+    named.sort();
+    for name in named {
+        parser
+            .add_state(name.to_string(), repr.state_kind(name))
+            .expect("Block names are unique");
+        for (c, sig) in alphabet.iter().zip(signature(name, &block)) {
+            for (write, motion, target) in sig {
+                parser.get_transition_builder().add_transition(
+                    name.to_string(),
+                    *c,
+                    Action::new(names[&target].to_string(), write, motion),
+                );
+            }
+        }
+    }
+    parser
+        .add_starting_state(repr.starting_state().clone())
+        .expect("The starting state is only set once");
+    for c in &alphabet {
+        parser
+            .add_alphabet_symbol(*c)
+            .expect("The alphabet has no duplicates");
+    }
+
+    GeneralMachineRepresentation::from_builder(&parser)
+        .expect("A minimized machine is a valid machine")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder,
+        deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+        limited::LimitedTuringMachineExt,
+        machine_parser, TuringMachine,
+    };
+
+    type Repr = DeterministicMachineRepresentation<String>;
+
+    fn load(machine: &str) -> Repr {
+        Repr::from_builder(&machine_parser::parse(machine.as_bytes()).unwrap()).unwrap()
+    }
+
+    fn run(repr: &Repr, tape: Vec<char>) -> (bool, Vec<char>) {
+        let machine = DeterministicTuringMachine::from_builder(
+            TuringMachineBuilder::new().repr(repr.clone()).tape(tape),
+        )
+        .unwrap();
+        let exe = LimitedTuringMachineExt::new_with_limit(machine, 10000).execute_and_get_result();
+        (exe.accepting, exe.tape)
+    }
+
+    /// Compare the verdicts on pseudo random inputs, from a fixed xorshift seed
+    fn assert_equivalent(original: &Repr, minimized: &Repr) {
+        let mut alphabet: Vec<char> = original.alphabet().iter().cloned().collect();
+        alphabet.sort();
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        for _ in 0..200 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let len = (seed % 12) as usize;
+            let tape: Vec<char> = (0..len)
+                .map(|i| alphabet[((seed >> (i * 4)) % alphabet.len() as u64) as usize])
+                .collect();
+
+            let (accepting, _) = run(original, tape.clone());
+            assert_eq!(
+                accepting,
+                run(minimized, tape.clone()).0,
+                "Different verdicts on {:?}",
+                tape
+            );
+        }
+    }
+
+    #[test]
+    fn merges_equivalent_states() {
+        // q1 and q2 behave the same, q3 is unreachable, q4 can never accept and the two accepting states are merged
+        let machine = "states 8\nq0\nq1\nq2\nq3\nq4\nqa +\nqb +\nqr -\nalphabet 2 a b\nq0 a q1 a R\nq0 b q2 b R\nq1 a q1 a R\nq1 _ qa _ S\nq2 a q2 a R\nq2 _ qb _ S\nq3 a qa a S\nq1 b q4 b R\nq2 b q4 b R\nq4 a q4 a R";
+        let original = load(machine);
+        let minimized = minimize(&original);
+
+        let mut states: Vec<&String> = minimized.states().iter().collect();
+        states.sort();
+        assert_eq!(states, vec!["q0", "q1", "qa", "qr"]);
+        assert_eq!(
+            minimized
+                .transition_table()
+                .apply_transition_table(&"q0".to_string(), 'b'),
+            Some(Action::new("q1".to_string(), 'b', Motion::Right))
+        );
+        assert_equivalent(&original, &minimized);
+    }
+
+    #[test]
+    fn minimal_machine_is_unchanged() {
+        let original = load(include_str!("../data/paren.tm"));
+        let minimized = minimize(&original);
+        assert_eq!(minimized.states(), original.states());
+        assert_equivalent(&original, &minimized);
+        assert_eq!(
+            run(&original, "(()())".chars().collect()),
+            run(&minimized, "(()())".chars().collect())
+        );
+    }
+
+    #[test]
+    fn keeps_machines_running_forever() {
+        let original = load(include_str!("../data/sqrt2.tm"));
+        assert_eq!(minimize(&original).states().len(), 3);
+
+        let merged = merge_equivalent_states(&original);
+        assert!(merged.states().len() > 3);
+        let (_, tape) = run(&original, vec![]);
+        assert_eq!(run(&merged, vec![]).1, tape);
+    }
+
+    #[test]
+    fn shrinks_bf() {
+        let original = load(include_str!("../data/bf.tm"));
+        let minimized = minimize(&original);
+        assert!(minimized.states().len() < original.states().len());
+        assert_equivalent(&original, &minimized);
+        // Already minimal
+        assert_eq!(
+            minimize(&minimized).states().len(),
+            minimized.states().len()
+        );
+    }
+}
@@ -0,0 +1,451 @@
+//! Product constructions, building a decider for the intersection or union of the languages of two deciders.
+//!
+//! The product keeps both machines on separate tracks of a single tape. Each cell holds a symbol for each machine, a
+//! marker for each head and a marker for the leftmost cell, all packed into a single character from the Unicode
+//! private use area. The product first converts its input to this encoding, then alternates single steps of the two
+//! machines, rewinding to the leftmost cell to look for the next head each time. Since the steps alternate, the union
+//! accepts even if one of the machines runs forever.
+//!
+//! Inputs are words over the symbols both machines share. Plain halting states count as not accepting.
+
+use hashbrown::{HashMap, HashSet};
+use std::collections::VecDeque;
+
+use crate::{
+    builders::MachineRepresentationBuilder,
+    common::{Action, Motion, State},
+    deterministic_tm::DeterministicMachineRepresentation,
+    machine_parser::MachineParser,
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+};
+
+type Repr = DeterministicMachineRepresentation<String>;
+
+const ACCEPT: &str = "accept";
+const REJECT: &str = "reject";
+
+/// Which language the product decides
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductKind {
+    Intersection,
+    Union,
+}
+
+/// A decider for the words both machines accept
+pub fn intersection(first: &Repr, second: &Repr) -> Repr {
+    product(first, second, ProductKind::Intersection)
+}
+
+/// A decider for the words either machine accepts
+pub fn union(first: &Repr, second: &Repr) -> Repr {
+    product(first, second, ProductKind::Union)
+}
+
+/// The content of a cell of the product tape
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Cell {
+    symbols: [usize; 2],
+    heads: [bool; 2],
+    first: bool,
+}
+
+/// Maps cells to the characters they are stored as, and back
+struct Encoding {
+    alphabets: [Vec<char>; 2],
+    base: u32,
+}
+
+impl Encoding {
+    fn new(first: &Repr, second: &Repr) -> Self {
+        let sorted = |repr: &Repr| {
+            let mut alphabet: Vec<char> = repr.alphabet().iter().cloned().collect();
+            alphabet.sort();
+            alphabet
+        };
+        let alphabets = [sorted(first), sorted(second)];
+        let base = alphabets
+            .iter()
+            .flatten()
+            .map(|c| *c as u32 + 1)
+            .max()
+            .unwrap_or(0)
+            .max(0xE000);
+        Encoding { alphabets, base }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = Cell> + '_ {
+        let (n1, n2) = (self.alphabets[0].len(), self.alphabets[1].len());
+        (0..n1 * n2 * 8).map(move |i| Cell {
+            symbols: [i / (n2 * 8), i / 8 % n2],
+            heads: [i & 4 != 0, i & 2 != 0],
+            first: i & 1 != 0,
+        })
+    }
+
+    fn encode(&self, cell: Cell) -> char {
+        let n2 = self.alphabets[1].len();
+        let i = (cell.symbols[0] * n2 + cell.symbols[1]) * 8
+            + (cell.heads[0] as usize) * 4
+            + (cell.heads[1] as usize) * 2
+            + cell.first as usize;
+        std::char::from_u32(self.base + i as u32).expect("The encoding stays clear of surrogates")
+    }
+
+    fn index(&self, track: usize, c: char) -> Option<usize> {
+        self.alphabets[track].iter().position(|s| *s == c)
+    }
+
+    /// A blank cell, as found past the end of the input
+    fn blank(&self) -> Cell {
+        Cell {
+            symbols: [
+                self.index(0, '_').expect("The blank is in every alphabet"),
+                self.index(1, '_').expect("The blank is in every alphabet"),
+            ],
+            heads: [false, false],
+            first: false,
+        }
+    }
+}
+
+/// What the product is doing, along with the states of the two machines
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Phase {
+    /// Going back to the leftmost cell, before looking for the head of a machine
+    Rewind(usize),
+    /// Looking for the head of a machine, to simulate one of its steps
+    Find(usize),
+    /// Setting the head marker of a machine on the cell it moved to
+    Mark(usize),
+}
+
+type Control = (Phase, [String; 2]);
+
+struct Builder<'a> {
+    machines: [&'a Repr; 2],
+    kind: ProductKind,
+    encoding: Encoding,
+    parser: MachineParser,
+    ids: HashMap<Control, String>,
+    queue: VecDeque<Control>,
+}
+
+impl<'a> Builder<'a> {
+    fn accepted(&self, track: usize, state: &String) -> Option<bool> {
+        match self.machines[track].state_kind(state) {
+            State::Accepting => Some(true),
+            State::Neutral => None,
+            _ => Some(false),
+        }
+    }
+
+    /// The verdict of the product, once it is known
+    fn decide(&self, states: &[String; 2]) -> Option<bool> {
+        let verdicts = [self.accepted(0, &states[0]), self.accepted(1, &states[1])];
+        let decisive = self.kind == ProductKind::Union;
+        if verdicts.contains(&Some(decisive)) {
+            Some(decisive)
+        } else if verdicts.iter().all(Option::is_some) {
+            Some(!decisive)
+        } else {
+            None
+        }
+    }
+
+    /// The machine to run next, skipping the one that has already stopped
+    fn next_turn(&self, states: &[String; 2], last: usize) -> usize {
+        let other = 1 - last;
+        if self.accepted(other, &states[other]).is_none() {
+            other
+        } else {
+            last
+        }
+    }
+
+    /// The name of the state for a control, scheduling it to be built if it is new
+    fn state(&mut self, control: Control) -> String {
+        if let Some(name) = self.ids.get(&control) {
+            return name.clone();
+        }
+        let (phase, _) = &control;
+        let name = match phase {
+            Phase::Rewind(t) => format!("rewind{}_{}", t + 1, self.ids.len()),
+            Phase::Find(t) => format!("find{}_{}", t + 1, self.ids.len()),
+            Phase::Mark(t) => format!("mark{}_{}", t + 1, self.ids.len()),
+        };
+        self.parser
+            .add_state(name.clone(), State::Neutral)
+            .expect("State names are unique");
+        self.ids.insert(control.clone(), name.clone());
+        self.queue.push_back(control);
+        name
+    }
+
+    /// Where to go once the states of the machines have changed, after the machine `last` moved
+    fn after_step(
+        &mut self,
+        states: [String; 2],
+        last: usize,
+        phase: fn(usize) -> Phase,
+    ) -> String {
+        match self.decide(&states) {
+            Some(true) => ACCEPT.to_string(),
+            Some(false) => REJECT.to_string(),
+            None => {
+                let turn = self.next_turn(&states, last);
+                self.state((phase(turn), states))
+            }
+        }
+    }
+
+    fn add_transition(&mut self, from: &str, read: char, to: &str, write: char, motion: Motion) {
+        self.parser.get_transition_builder().add_transition(
+            from.to_string(),
+            read,
+            Action::new(to.to_string(), write, motion),
+        );
+    }
+
+    /// Add the transitions out of a control state
+    fn build(&mut self, control: Control) {
+        let from = self.ids[&control].clone();
+        let (phase, states) = control;
+        let cells: Vec<Cell> = self.encoding.cells().collect();
+
+        for cell in cells {
+            let c = self.encoding.encode(cell);
+            match phase {
+                Phase::Rewind(t) => {
+                    if cell.first {
+                        let to = self.state((Phase::Find(t), states.clone()));
+                        self.add_transition(&from, c, &to, c, Motion::Stay);
+                    } else {
+                        self.add_transition(&from, c, &from, c, Motion::Left);
+                    }
+                }
+                Phase::Find(t) if !cell.heads[t] => {
+                    self.add_transition(&from, c, &from, c, Motion::Right)
+                }
+                Phase::Find(t) => {
+                    let symbol = self.encoding.alphabets[t][cell.symbols[t]];
+                    let machine = self.machines[t];
+                    let mut next = states.clone();
+                    match machine
+                        .transition_table()
+                        .apply_transition_table(&states[t], symbol)
+                    {
+                        Some(act) => {
+                            next[t] = act.next_state().clone();
+                            let mut written = cell;
+                            written.symbols[t] = self
+                                .encoding
+                                .index(t, *act.tape_output())
+                                .expect("Transitions stay within the alphabet");
+                            written.heads[t] = false;
+                            let to = match self.decide(&next) {
+                                Some(_) => self.after_step(next, t, Phase::Mark),
+                                None => self.state((Phase::Mark(t), next)),
+                            };
+                            let w = self.encoding.encode(written);
+                            self.add_transition(&from, c, &to, w, *act.motion());
+                        }
+                        None => {
+                            // The machine implicitly rejects
+                            next[t] = machine.implicit_reject().clone();
+                            let to = self.after_step(next, t, Phase::Rewind);
+                            self.add_transition(&from, c, &to, c, Motion::Stay);
+                        }
+                    }
+                }
+                Phase::Mark(t) => {
+                    let mut marked = cell;
+                    marked.heads[t] = true;
+                    let to = self.after_step(states.clone(), t, Phase::Rewind);
+                    let w = self.encoding.encode(marked);
+                    self.add_transition(&from, c, &to, w, Motion::Stay);
+                }
+            }
+        }
+
+        // A move past the end of the tape lands on a plain blank
+        if let Phase::Mark(t) = phase {
+            let mut marked = self.encoding.blank();
+            marked.heads[t] = true;
+            let to = self.after_step(states, t, Phase::Rewind);
+            let w = self.encoding.encode(marked);
+            self.add_transition(&from, '_', &to, w, Motion::Stay);
+        }
+    }
+}
+
+/// Build the product of two deciders
+pub fn product(first: &Repr, second: &Repr, kind: ProductKind) -> Repr {
+    let encoding = Encoding::new(first, second);
+    let mut builder = Builder {
+        machines: [first, second],
+        kind,
+        encoding,
+        parser: MachineParser::default(),
+        ids: HashMap::new(),
+        queue: VecDeque::new(),
+    };
+
+    let inputs: Vec<char> = {
+        let shared: HashSet<&char> = first.alphabet().intersection(second.alphabet()).collect();
+        let mut inputs: Vec<char> = shared.into_iter().cloned().collect();
+        inputs.sort();
+        inputs
+    };
+    let encode_input = |c: char, first_cell: bool, e: &Encoding| {
+        e.encode(Cell {
+            symbols: [e.index(0, c).unwrap(), e.index(1, c).unwrap()],
+            heads: [first_cell, first_cell],
+            first: first_cell,
+        })
+    };
+
+    // Encode the input, then rewind and start simulating
+    let states = [
+        first.starting_state().clone(),
+        second.starting_state().clone(),
+    ];
+    let start = match builder.decide(&states) {
+        Some(true) => ACCEPT.to_string(),
+        Some(false) => REJECT.to_string(),
+        None => {
+            const INIT: &str = "init";
+            const CONVERT: &str = "convert";
+            builder
+                .parser
+                .add_state(INIT.to_string(), State::Neutral)
+                .unwrap();
+            builder
+                .parser
+                .add_state(CONVERT.to_string(), State::Neutral)
+                .unwrap();
+
+            let turn = builder.next_turn(&states, 1);
+            let rewind = builder.state((Phase::Rewind(turn), states));
+            for c in &inputs {
+                let w = encode_input(*c, true, &builder.encoding);
+                if *c == '_' {
+                    builder.add_transition(INIT, '_', &rewind, w, Motion::Stay);
+                } else {
+                    builder.add_transition(INIT, *c, CONVERT, w, Motion::Right);
+                    let w = encode_input(*c, false, &builder.encoding);
+                    builder.add_transition(CONVERT, *c, CONVERT, w, Motion::Right);
+                }
+            }
+            let blank = builder.encoding.encode(builder.encoding.blank());
+            builder.add_transition(CONVERT, '_', &rewind, blank, Motion::Left);
+            INIT.to_string()
+        }
+    };
+
+    while let Some(control) = builder.queue.pop_front() {
+        builder.build(control);
+    }
+
+    let mut parser = builder.parser;
+    parser
+        .add_state(ACCEPT.to_string(), State::Accepting)
+        .unwrap();
+    parser
+        .add_state(REJECT.to_string(), State::Rejecting)
+        .unwrap();
+    parser.add_starting_state(start).unwrap();
+    for c in inputs {
+        parser.add_alphabet_symbol(c).unwrap();
+    }
+    for cell in builder.encoding.cells() {
+        parser
+            .add_alphabet_symbol(builder.encoding.encode(cell))
+            .unwrap();
+    }
+
+    Repr::from_builder(&parser).expect("A product is a valid machine")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder, deterministic_tm::DeterministicTuringMachine,
+        limited::LimitedTuringMachineExt, machine_parser, TuringMachine,
+    };
+
+    fn load(machine: &str) -> Repr {
+        Repr::from_builder(&machine_parser::parse(machine.as_bytes()).unwrap()).unwrap()
+    }
+
+    fn accepts(repr: &Repr, tape: &[char]) -> bool {
+        let machine = DeterministicTuringMachine::<String>::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr.clone())
+                .tape(tape.to_vec()),
+        )
+        .unwrap();
+        let exe =
+            LimitedTuringMachineExt::new_with_limit(machine, 1_000_000).execute_and_get_result();
+        exe.accepting
+    }
+
+    /// Compare the product with the two machines run separately, on pseudo random inputs from a fixed xorshift seed
+    fn check_product(first: &Repr, second: &Repr) {
+        let both = intersection(first, second);
+        let either = union(first, second);
+        let mut seed: u64 = 0x9e37_79b9_7f4a_7c15;
+        for _ in 0..60 {
+            seed ^= seed << 13;
+            seed ^= seed >> 7;
+            seed ^= seed << 17;
+            let len = (seed % 9) as usize;
+            let tape: Vec<char> = (0..len)
+                .map(|i| if (seed >> i) & 1 == 0 { '(' } else { ')' })
+                .collect();
+
+            let (a1, a2) = (accepts(first, &tape), accepts(second, &tape));
+            assert_eq!(
+                accepts(&both, &tape),
+                a1 && a2,
+                "Intersection on {:?}",
+                tape
+            );
+            assert_eq!(accepts(&either, &tape), a1 || a2, "Union on {:?}", tape);
+        }
+    }
+
+    const EVEN: &str = "states 4\ne\no\nqa +\nqr -\nalphabet 2 ( )\ne ( o ( R\ne ) o ) R\no ( e ( R\no ) e ) R\ne _ qa _ S";
+    const OPENS: &str = "states 3\nq0\nqa +\nqr -\nalphabet 2 ( )\nq0 ( qa ( S";
+
+    #[test]
+    fn product_of_paren_and_even() {
+        check_product(&load(include_str!("../data/paren.tm")), &load(EVEN));
+    }
+
+    #[test]
+    fn product_of_small_machines() {
+        check_product(&load(OPENS), &load(EVEN));
+        check_product(&load(EVEN), &load(OPENS));
+    }
+
+    #[test]
+    fn union_survives_looping_machine() {
+        let looping =
+            load("states 3\nq0\nqa +\nqr -\nalphabet 2 ( )\nq0 ( q0 ( S\nq0 ) q0 ) S\nq0 _ q0 _ S");
+        let machine = union(&looping, &load(EVEN));
+        assert!(accepts(&machine, &['(', ')']));
+    }
+
+    #[test]
+    fn product_can_be_written() {
+        let machine = intersection(&load(OPENS), &load(EVEN));
+        let mut written = Vec::new();
+        machine_parser::write(&machine, &mut written).unwrap();
+        let reparsed = load(&String::from_utf8(written).unwrap());
+        assert_eq!(reparsed.states().len(), machine.states().len());
+        assert!(accepts(&reparsed, &['(', ')']));
+        assert!(!accepts(&reparsed, &[')', ')']));
+    }
+}
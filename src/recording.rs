@@ -0,0 +1,339 @@
+use std::collections::VecDeque;
+
+use crate::{
+    builders::TuringMachineBuilder,
+    common::StateTrait,
+    deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine},
+    TuringMachine,
+};
+
+/// How many steps apart snapshots are taken by [`RecordingTuringMachineExt::new`](struct.RecordingTuringMachineExt.html#method.new)
+pub const DEFAULT_INTERVAL: usize = 1024;
+
+/// How many snapshots are kept by [`RecordingTuringMachineExt::new`](struct.RecordingTuringMachineExt.html#method.new)
+pub const DEFAULT_CAPACITY: usize = 64;
+
+/// What a single step destroyed, enough to take it back
+#[derive(Debug)]
+struct Undo<StateTy> {
+    state: StateTy,
+    head: usize,
+    symbol: char,
+    tape_len: usize,
+}
+
+/// A full copy of a configuration
+#[derive(Debug)]
+struct Snapshot<StateTy> {
+    steps: usize,
+    state: StateTy,
+    head: usize,
+    tape: Vec<char>,
+}
+
+/// A wrapper struct, which takes a [`DeterministicTuringMachine`](../deterministic_tm/struct.DeterministicTuringMachine.html) and records its run so that it can be stepped backwards.
+/// Every step stores the state, head and symbol it replaced, and every `interval` steps the whole configuration is saved.
+/// Only the steps since the last snapshot are kept as undo records, so going further back restores a snapshot and replays forward from it.
+/// Only the last `capacity` snapshots are kept, so the run can be stepped back as far as the oldest of them.
+#[derive(Debug)]
+pub struct RecordingTuringMachineExt<StateTy>
+where
+    StateTy: StateTrait,
+{
+    tm: DeterministicTuringMachine<StateTy>,
+    steps: usize,
+    interval: usize,
+    capacity: usize,
+    undo: Vec<Undo<StateTy>>,
+    snapshots: VecDeque<Snapshot<StateTy>>,
+}
+
+impl<StateTy> RecordingTuringMachineExt<StateTy>
+where
+    StateTy: StateTrait,
+{
+    /// Start recording an existing machine, taking a snapshot every [`DEFAULT_INTERVAL`](constant.DEFAULT_INTERVAL.html) steps
+    /// and keeping the last [`DEFAULT_CAPACITY`](constant.DEFAULT_CAPACITY.html)
+    pub fn new(tm: DeterministicTuringMachine<StateTy>) -> Self {
+        Self::with_interval(tm, DEFAULT_INTERVAL)
+    }
+
+    /// Start recording an existing machine, taking a snapshot every `interval` steps and keeping the last
+    /// [`DEFAULT_CAPACITY`](constant.DEFAULT_CAPACITY.html).
+    /// Panics if `interval` is 0.
+    pub fn with_interval(tm: DeterministicTuringMachine<StateTy>, interval: usize) -> Self {
+        Self::with_history(tm, interval, DEFAULT_CAPACITY)
+    }
+
+    /// Start recording an existing machine, taking a snapshot every `interval` steps and keeping the last `capacity`.
+    /// At most `interval * capacity` steps can be taken back.
+    /// Panics if `interval` or `capacity` is 0.
+    pub fn with_history(
+        tm: DeterministicTuringMachine<StateTy>,
+        interval: usize,
+        capacity: usize,
+    ) -> Self {
+        assert!(interval > 0, "The snapshot interval must be positive");
+        assert!(capacity > 0, "The snapshot capacity must be positive");
+        let mut recording = Self {
+            tm,
+            steps: 0,
+            interval,
+            capacity,
+            undo: Vec::with_capacity(interval),
+            snapshots: VecDeque::with_capacity(capacity),
+        };
+        recording.take_snapshot();
+        recording
+    }
+
+    /// Get the machine in its current configuration
+    pub fn machine(&self) -> &DeterministicTuringMachine<StateTy> {
+        &self.tm
+    }
+
+    /// Stop recording, and get the machine back
+    pub fn into_inner(self) -> DeterministicTuringMachine<StateTy> {
+        self.tm
+    }
+
+    /// Return how many steps the machine has done so far
+    pub fn get_number_of_steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Return the earliest step the machine can go back to, that of the oldest snapshot kept
+    pub fn first_step(&self) -> usize {
+        self.snapshots
+            .front()
+            .expect("There is always a snapshot")
+            .steps
+    }
+
+    /// Take the last step back, returning false if the machine is at the earliest step it can go back to
+    pub fn step_back(&mut self) -> bool {
+        if self.steps == self.first_step() {
+            return false;
+        }
+        self.goto(self.steps - 1);
+        true
+    }
+
+    /// Move to the configuration after `step` steps, returning the step actually reached.
+    /// Going backwards stops at the [`first_step`](#method.first_step), going forwards stops early if the machine finishes.
+    /// Note going forwards might not return at all, as [`run`](../trait.TuringMachine.html#method.run) does.
+    pub fn goto(&mut self, step: usize) -> usize {
+        let step = step.max(self.first_step());
+        if step < self.steps && self.steps - step > self.undo.len() {
+            // Snapshots are `interval` steps apart, starting from the oldest one kept
+            let index = (step - self.first_step()) / self.interval;
+            self.snapshots.truncate(index + 1);
+            self.undo.clear();
+            self.steps = self.snapshots[index].steps;
+
+            let snapshot = &self.snapshots[index];
+            let (tape, head, state) = self.tm.configuration_mut();
+            tape.clone_from(&snapshot.tape);
+            *head = snapshot.head;
+            *state = snapshot.state.clone();
+        }
+
+        while step < self.steps {
+            self.undo_step();
+        }
+        while self.steps < step && !self.is_finished() {
+            self.step();
+        }
+        self.steps
+    }
+
+    fn undo_step(&mut self) {
+        let undo = self
+            .undo
+            .pop()
+            .expect("Every step since the last snapshot has an undo record");
+        let (tape, head, state) = self.tm.configuration_mut();
+        tape.truncate(undo.tape_len);
+        if let Some(cell) = tape.get_mut(undo.head) {
+            *cell = undo.symbol;
+        }
+        *head = undo.head;
+        *state = undo.state;
+        self.steps -= 1;
+    }
+
+    fn take_snapshot(&mut self) {
+        if self.snapshots.len() == self.capacity {
+            self.snapshots.pop_front();
+        }
+        self.snapshots.push_back(Snapshot {
+            steps: self.steps,
+            state: self.tm.current_state().clone(),
+            head: self.tm.head(),
+            tape: self.tm.tape().clone(),
+        });
+        self.undo.clear();
+    }
+}
+
+impl<StateTy> TuringMachine for RecordingTuringMachineExt<StateTy>
+where
+    StateTy: StateTrait,
+{
+    type Tape = Vec<char>;
+    type StateTy = StateTy;
+    type ReprTy = DeterministicMachineRepresentation<StateTy>;
+    type ErrorTy = <DeterministicTuringMachine<StateTy> as TuringMachine>::ErrorTy;
+
+    fn from_builder(
+        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
+    ) -> Result<Self, Self::ErrorTy> {
+        Ok(Self::new(DeterministicTuringMachine::from_builder(
+            builder,
+        )?))
+    }
+
+    fn step(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+
+        let head = self.tm.head();
+        self.undo.push(Undo {
+            state: self.tm.current_state().clone(),
+            head,
+            symbol: *self.tm.tape().get(head).unwrap_or(&'_'),
+            tape_len: self.tm.tape().len(),
+        });
+        self.tm.step();
+        self.steps += 1;
+
+        if self.steps.is_multiple_of(self.interval) {
+            self.take_snapshot();
+        }
+    }
+
+    fn tape(&self) -> &Self::Tape {
+        self.tm.tape()
+    }
+
+    fn is_accepting(&self) -> bool {
+        self.tm.is_accepting()
+    }
+
+    fn is_rejecting(&self) -> bool {
+        self.tm.is_rejecting()
+    }
+
+    fn is_halting(&self) -> bool {
+        self.tm.is_halting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{machine_parser, machine_representation::MachineRepresentation};
+
+    type Configuration = (String, usize, Vec<char>);
+
+    fn machine(source: &str, tape: &str) -> DeterministicTuringMachine<String> {
+        let repr = DeterministicMachineRepresentation::from_builder(
+            &machine_parser::parse(source.as_bytes()).unwrap(),
+        )
+        .unwrap();
+        DeterministicTuringMachine::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape(tape.chars().collect()),
+        )
+        .unwrap()
+    }
+
+    fn configuration(tm: &DeterministicTuringMachine<String>) -> Configuration {
+        (tm.current_state().clone(), tm.head(), tm.tape().clone())
+    }
+
+    /// Every configuration of the first `steps` steps, taken with the plain engine
+    fn history(source: &str, tape: &str, steps: usize) -> Vec<Configuration> {
+        let mut tm = machine(source, tape);
+        let mut history = vec![configuration(&tm)];
+        while history.len() <= steps && !tm.is_finished() {
+            tm.step();
+            history.push(configuration(&tm));
+        }
+        history
+    }
+
+    #[test]
+    fn steps_back_through_the_run() {
+        let source = include_str!("../data/paren.tm");
+        let expected = history(source, "(()())", usize::MAX);
+
+        let mut recording = RecordingTuringMachineExt::with_interval(machine(source, "(()())"), 4);
+        assert!(recording.run());
+        assert_eq!(recording.get_number_of_steps(), expected.len() - 1);
+
+        for step in (0..expected.len()).rev() {
+            assert_eq!(recording.get_number_of_steps(), step);
+            assert_eq!(configuration(recording.machine()), expected[step]);
+            recording.step_back();
+        }
+        assert!(!recording.step_back());
+    }
+
+    #[test]
+    fn jumps_to_past_steps() {
+        let source = include_str!("../data/sqrt2.tm");
+        let expected = history(source, "", 3000);
+
+        let mut recording = RecordingTuringMachineExt::with_interval(machine(source, ""), 64);
+        let mut seed = 0x2545_f491_u32;
+        for _ in 0..200 {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            let step = seed as usize % expected.len();
+
+            assert_eq!(recording.goto(step), step);
+            assert_eq!(configuration(recording.machine()), expected[step]);
+        }
+    }
+
+    #[test]
+    fn stops_at_the_end_of_the_run() {
+        let source = include_str!("../data/paren.tm");
+        let expected = history(source, "(()", usize::MAX);
+        let last = expected.len() - 1;
+
+        let mut recording = RecordingTuringMachineExt::new(machine(source, "(()"));
+        assert_eq!(recording.goto(last + 10), last);
+        assert!(recording.is_rejecting());
+        assert_eq!(recording.goto(1), 1);
+        assert_eq!(configuration(recording.machine()), expected[1]);
+        assert!(!recording.is_finished());
+    }
+
+    #[test]
+    fn keeps_a_bounded_history() {
+        let source = include_str!("../data/sqrt2.tm");
+        let expected = history(source, "", 1000);
+
+        let mut recording = RecordingTuringMachineExt::with_history(machine(source, ""), 16, 4);
+        assert_eq!(recording.goto(1000), 1000);
+        assert_eq!(recording.snapshots.len(), 4);
+        assert!(recording.undo.len() < 16);
+
+        // The snapshots are at steps 944, 960, 976 and 992
+        assert_eq!(recording.first_step(), 944);
+        assert_eq!(recording.goto(0), 944);
+        assert_eq!(configuration(recording.machine()), expected[944]);
+        assert!(!recording.step_back());
+
+        // Going forwards again keeps to the bound
+        assert_eq!(recording.goto(999), 999);
+        assert_eq!(configuration(recording.machine()), expected[999]);
+        assert_eq!(recording.snapshots.len(), 4);
+        assert_eq!(recording.first_step(), 944);
+    }
+}
@@ -0,0 +1,583 @@
+use std::{
+    fs::{self, File},
+    io::{self, BufRead, BufReader, BufWriter, Read, Write},
+    path::PathBuf,
+};
+
+use crate::{
+    builders::TuringMachineBuilder,
+    deterministic_tm::DeterministicTuringMachine,
+    machine_parser,
+    machine_representation::MachineRepresentation,
+    non_deterministic_tm::NonDeterministicTuringMachine,
+    tape::{Run, Tape},
+    transition_table::ActionSet,
+    TuringMachine,
+};
+
+/// The first line of every snapshot file, followed by the version of the format
+const HEADER: &str = "snapshot ";
+
+/// The version of the format written
+const VERSION: u32 = 2;
+
+#[derive(Debug)]
+pub enum SnapshotError {
+    IO(io::Error),
+
+    /// The given line (counting from 1) could not be understood
+    Malformed(usize),
+
+    /// The snapshot was taken from a machine with hash `found`, not `expected`
+    DifferentMachine {
+        expected: u64,
+        found: u64,
+    },
+
+    /// A deterministic machine has exactly one path, but the snapshot has this many
+    PathCount(usize),
+
+    /// The snapshot is in a state the machine doesn't have
+    UnknownState(String),
+
+    /// The snapshot has a symbol on the tape that is not in the alphabet
+    UnknownSymbol(char),
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> Self {
+        SnapshotError::IO(err)
+    }
+}
+
+/// The configuration of a single path of a machine
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path {
+    pub state: String,
+    pub head: usize,
+    /// The runs on the tape, as given by [`Tape::runs`](../tape/trait.Tape.html#method.runs)
+    pub tape: Vec<Run>,
+}
+
+/// A configuration of a running machine, saved to resume the run later.
+///
+/// The text format is a header line, the machine hash and step count, and two lines per path:
+/// ```text
+/// snapshot 2
+/// machine 9ae16a3b2f90404f
+/// steps 12
+/// path 3 close
+/// ^ $^2 )
+/// ```
+/// The tape is written as its runs, each a symbol followed by `^` and its length when it is longer than one.
+/// Snapshots in version 1, with the tape written cell by cell, can still be read.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Snapshot {
+    /// The [`machine_hash`](fn.machine_hash.html) of the machine that was running
+    pub machine: u64,
+    pub steps: usize,
+    pub paths: Vec<Path>,
+}
+
+impl Snapshot {
+    pub fn write(&self, mut w: impl Write) -> io::Result<()> {
+        writeln!(w, "{}{}", HEADER, VERSION)?;
+        writeln!(w, "machine {:016x}", self.machine)?;
+        writeln!(w, "steps {}", self.steps)?;
+        for path in &self.paths {
+            writeln!(w, "path {} {}", path.head, path.state)?;
+            let runs: Vec<String> = path
+                .tape
+                .iter()
+                .map(|run| match run.len {
+                    1 => run.symbol.to_string(),
+                    len => format!("{}^{}", run.symbol, len),
+                })
+                .collect();
+            writeln!(w, "{}", runs.join(" "))?;
+        }
+        Ok(())
+    }
+
+    pub fn read(source: impl Read) -> Result<Self, SnapshotError> {
+        let mut lines = BufReader::new(source).lines().enumerate();
+        let mut next = |prefix: &str| -> Result<Option<(usize, String)>, SnapshotError> {
+            match lines.next() {
+                Some((i, line)) => {
+                    let line = line?;
+                    match line.strip_prefix(prefix) {
+                        Some(rest) => Ok(Some((i + 1, rest.to_string()))),
+                        None => Err(SnapshotError::Malformed(i + 1)),
+                    }
+                }
+                None => Ok(None),
+            }
+        };
+
+        let version = match next(HEADER)? {
+            Some((_, ref version)) if version == "1" => 1,
+            Some((_, ref version)) if version == "2" => 2,
+            _ => return Err(SnapshotError::Malformed(1)),
+        };
+        let (i, machine) = next("machine ")?.ok_or(SnapshotError::Malformed(2))?;
+        let machine = u64::from_str_radix(&machine, 16).map_err(|_| SnapshotError::Malformed(i))?;
+        let (i, steps) = next("steps ")?.ok_or(SnapshotError::Malformed(3))?;
+        let steps = steps.parse().map_err(|_| SnapshotError::Malformed(i))?;
+
+        let mut paths = Vec::new();
+        while let Some((i, path)) = next("path ")? {
+            let mut tokens = path.splitn(2, ' ');
+            let head = tokens
+                .next()
+                .and_then(|h| h.parse().ok())
+                .ok_or(SnapshotError::Malformed(i))?;
+            let state = tokens
+                .next()
+                .filter(|s| !s.is_empty())
+                .ok_or(SnapshotError::Malformed(i))?
+                .to_string();
+            let (i, tape) = next("")?.ok_or(SnapshotError::Malformed(i + 1))?;
+            let tape = match version {
+                1 => tape.chars().collect::<Vec<char>>().runs(),
+                _ => read_runs(&tape).ok_or(SnapshotError::Malformed(i))?,
+            };
+            paths.push(Path { state, head, tape });
+        }
+
+        Ok(Self {
+            machine,
+            steps,
+            paths,
+        })
+    }
+
+    /// Check the snapshot can be resumed on `repr`
+    fn check<Repr>(&self, repr: &Repr) -> Result<(), SnapshotError>
+    where
+        Repr: MachineRepresentation<String, InputTy = char>,
+        Repr::OutputTy: ActionSet<String>,
+    {
+        let expected = machine_hash(repr);
+        if self.machine != expected {
+            return Err(SnapshotError::DifferentMachine {
+                expected,
+                found: self.machine,
+            });
+        }
+        for path in &self.paths {
+            if !repr.states().contains(&path.state) {
+                return Err(SnapshotError::UnknownState(path.state.clone()));
+            }
+            if let Some(run) = path
+                .tape
+                .iter()
+                .find(|run| !repr.alphabet().contains(&run.symbol))
+            {
+                return Err(SnapshotError::UnknownSymbol(run.symbol));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Read a tape written as runs, returning `None` if it is malformed
+fn read_runs(line: &str) -> Option<Vec<Run>> {
+    line.split_whitespace()
+        .map(|token| {
+            let mut chars = token.chars();
+            let symbol = chars.next()?;
+            let len = match chars.as_str() {
+                "" => 1,
+                rest => rest
+                    .strip_prefix('^')?
+                    .parse()
+                    .ok()
+                    .filter(|len| *len > 0)?,
+            };
+            Some(Run { symbol, len })
+        })
+        .collect()
+}
+
+/// A hash of the machine, identifying it across runs, platforms and compiler versions.
+/// This is the 64 bit FNV-1a hash of the machine as written by [`machine_parser::write`](../machine_parser/fn.write.html), so it does not depend on how the source file was laid out.
+pub fn machine_hash<Repr>(repr: &Repr) -> u64
+where
+    Repr: MachineRepresentation<String, InputTy = char>,
+    Repr::OutputTy: ActionSet<String>,
+{
+    let mut source = Vec::new();
+    machine_parser::write_unchecked(repr, &mut source).expect("Writing to memory cannot fail");
+    source.iter().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ u64::from(*byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
+
+/// A [`TuringMachine`](../trait.TuringMachine.html) whose configuration can be saved and restored
+pub trait Resumable: TuringMachine<StateTy = String> {
+    /// Save the current configuration, reached after `steps` steps
+    fn snapshot(&self, steps: usize) -> Snapshot;
+
+    /// Build the machine running `repr` in the configuration of `snapshot`, failing if it was taken from another machine
+    fn resume(repr: Self::ReprTy, snapshot: &Snapshot) -> Result<Self, SnapshotError>;
+}
+
+impl<TapeTy: Tape> Resumable for DeterministicTuringMachine<String, TapeTy> {
+    fn snapshot(&self, steps: usize) -> Snapshot {
+        Snapshot {
+            machine: machine_hash(self.representation()),
+            steps,
+            paths: vec![Path {
+                state: self.current_state().clone(),
+                head: self.head(),
+                tape: self.tape().runs(),
+            }],
+        }
+    }
+
+    fn resume(repr: Self::ReprTy, snapshot: &Snapshot) -> Result<Self, SnapshotError> {
+        snapshot.check(&repr)?;
+        let path = match snapshot.paths.as_slice() {
+            [path] => path,
+            paths => return Err(SnapshotError::PathCount(paths.len())),
+        };
+
+        let mut tm = Self::from_builder(TuringMachineBuilder::new().repr(repr))
+            .expect("A machine without a tape is always valid");
+        let (tape, head, state) = tm.configuration_mut();
+        *tape = TapeTy::from_runs(&path.tape);
+        *head = path.head;
+        *state = path.state.clone();
+        Ok(tm)
+    }
+}
+
+impl<TapeTy: Tape> Resumable for NonDeterministicTuringMachine<String, TapeTy> {
+    fn snapshot(&self, steps: usize) -> Snapshot {
+        Snapshot {
+            machine: machine_hash(self.representation()),
+            steps,
+            paths: self
+                .states()
+                .iter()
+                .zip(self.heads())
+                .zip(self.tape())
+                .map(|((state, head), tape)| Path {
+                    state: state.clone(),
+                    head: *head,
+                    tape: tape.runs(),
+                })
+                .collect(),
+        }
+    }
+
+    fn resume(repr: Self::ReprTy, snapshot: &Snapshot) -> Result<Self, SnapshotError> {
+        snapshot.check(&repr)?;
+        if snapshot.paths.is_empty() {
+            return Err(SnapshotError::PathCount(0));
+        }
+
+        let mut tm = Self::from_builder(TuringMachineBuilder::new().repr(repr))
+            .expect("A machine without a tape is always valid");
+        let (states, heads, tapes) = tm.configuration_mut();
+        *states = snapshot.paths.iter().map(|p| p.state.clone()).collect();
+        *heads = snapshot.paths.iter().map(|p| p.head).collect();
+        *tapes = snapshot
+            .paths
+            .iter()
+            .map(|p| TapeTy::from_runs(&p.tape))
+            .collect();
+        Ok(tm)
+    }
+}
+
+/// A wrapper struct, which takes a [`Resumable`](trait.Resumable.html) machine and saves a snapshot of it every so many steps.
+/// Each snapshot replaces the previous one, going through a temporary file so that an interrupted write never loses it.
+///
+/// When a snapshot cannot be saved the run stops there, counting as rejecting, and the error is kept for [`take_failure`](#method.take_failure).
+pub struct SnapshotTuringMachineExt<T> {
+    tm: T,
+    steps: usize,
+    every: usize,
+    path: Option<PathBuf>,
+    failure: Option<io::Error>,
+}
+
+impl<T: Resumable> SnapshotTuringMachineExt<T> {
+    /// Decorate a machine that already took `steps` steps, without saving anything
+    pub fn new(tm: T, steps: usize) -> Self {
+        Self {
+            tm,
+            steps,
+            every: 0,
+            path: None,
+            failure: None,
+        }
+    }
+
+    /// Decorate a machine that already took `steps` steps, saving it to `path` every `every` steps
+    pub fn with_saving(tm: T, steps: usize, every: usize, path: impl Into<PathBuf>) -> Self {
+        Self {
+            tm,
+            steps,
+            every,
+            path: Some(path.into()),
+            failure: None,
+        }
+    }
+
+    /// Return how many steps the machine has done so far, including the ones before it was resumed
+    pub fn get_number_of_steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Write a snapshot of the current configuration to the file
+    pub fn save(&self) -> io::Result<()> {
+        let path = match &self.path {
+            Some(path) => path,
+            None => return Ok(()),
+        };
+        let mut temporary = path.clone().into_os_string();
+        temporary.push(".tmp");
+
+        let mut w = BufWriter::new(File::create(&temporary)?);
+        self.tm.snapshot(self.steps).write(&mut w)?;
+        w.flush()?;
+        drop(w);
+        fs::rename(temporary, path)
+    }
+
+    /// Take the error a snapshot failed to save with, which stopped the run
+    pub fn take_failure(&mut self) -> Option<io::Error> {
+        self.failure.take()
+    }
+
+    pub fn inner(self) -> T {
+        self.tm
+    }
+}
+
+impl<T: Resumable> TuringMachine for SnapshotTuringMachineExt<T> {
+    type Tape = T::Tape;
+    type StateTy = T::StateTy;
+    type ReprTy = T::ReprTy;
+    type ErrorTy = T::ErrorTy;
+
+    fn from_builder(
+        builder: TuringMachineBuilder<Self::StateTy, Self::ReprTy>,
+    ) -> Result<Self, Self::ErrorTy> {
+        Ok(Self::new(T::from_builder(builder)?, 0))
+    }
+
+    fn step(&mut self) {
+        if self.is_finished() {
+            return;
+        }
+        self.tm.step();
+        self.steps += 1;
+
+        if self.every > 0 && self.steps.is_multiple_of(self.every) {
+            self.failure = self.save().err();
+        }
+    }
+
+    fn tape(&self) -> &Self::Tape {
+        self.tm.tape()
+    }
+
+    fn is_accepting(&self) -> bool {
+        self.tm.is_accepting()
+    }
+
+    fn is_rejecting(&self) -> bool {
+        self.failure.is_some() || self.tm.is_rejecting()
+    }
+
+    fn is_halting(&self) -> bool {
+        self.tm.is_halting()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        deterministic_tm::DeterministicMachineRepresentation,
+        non_deterministic_tm::NonDeterministicMachineRepresentation, tape::RunLengthTape,
+    };
+
+    fn load<Repr>(source: &str) -> Repr
+    where
+        Repr: MachineRepresentation<String, InputTy = char>,
+        Repr::ErrorTy: std::fmt::Debug,
+    {
+        Repr::from_builder(&machine_parser::parse(source.as_bytes()).unwrap()).unwrap()
+    }
+
+    fn machine<T: Resumable>(source: &str, tape: &str) -> T
+    where
+        T::ReprTy: MachineRepresentation<String, InputTy = char>,
+        <T::ReprTy as MachineRepresentation<String>>::ErrorTy: std::fmt::Debug,
+    {
+        T::from_builder(
+            TuringMachineBuilder::new()
+                .repr(load(source))
+                .tape(tape.chars().collect()),
+        )
+        .unwrap()
+    }
+
+    /// Snapshot `tm` after `steps` steps, resume it from the written file, and check both agree from then on
+    fn round_trip<T: Resumable>(mut tm: T, source: &str, steps: usize) -> Snapshot
+    where
+        T::ReprTy: MachineRepresentation<String, InputTy = char>,
+        <T::ReprTy as MachineRepresentation<String>>::ErrorTy: std::fmt::Debug,
+    {
+        for _ in 0..steps {
+            tm.step();
+        }
+        let snapshot = tm.snapshot(steps);
+        let mut file = Vec::new();
+        snapshot.write(&mut file).unwrap();
+        let read = Snapshot::read(file.as_slice()).unwrap();
+        assert_eq!(read, snapshot);
+
+        let mut resumed = T::resume(load(source), &read).unwrap();
+        for i in 0..500 {
+            assert_eq!(resumed.snapshot(steps + i), tm.snapshot(steps + i));
+            assert_eq!(resumed.is_finished(), tm.is_finished());
+            tm.step();
+            resumed.step();
+        }
+        snapshot
+    }
+
+    #[test]
+    fn resumes_deterministic_machines() {
+        let source = include_str!("../data/sqrt2.tm");
+        let tm: DeterministicTuringMachine<String> = machine(source, "");
+        let snapshot = round_trip(tm, source, 2000);
+        assert_eq!(snapshot.steps, 2000);
+        assert_eq!(snapshot.paths.len(), 1);
+    }
+
+    #[test]
+    fn resumes_non_deterministic_machines() {
+        let source = include_str!("../data/repeat.tm");
+        let tm: NonDeterministicTuringMachine<String> = machine(source, "01100110");
+        let snapshot = round_trip(tm, source, 30);
+        assert!(snapshot.paths.len() > 1);
+    }
+
+    #[test]
+    fn saves_every_so_many_steps() {
+        let source = include_str!("../data/sqrt2.tm");
+        let path = std::env::temp_dir().join(format!("snapshot-{}.txt", std::process::id()));
+        let mut tm = SnapshotTuringMachineExt::with_saving(
+            machine::<DeterministicTuringMachine<String>>(source, ""),
+            5,
+            10,
+            &path,
+        );
+        for _ in 0..27 {
+            tm.step();
+        }
+        let saved = Snapshot::read(File::open(&path).unwrap()).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(saved.steps, 30);
+        assert!(tm.take_failure().is_none());
+    }
+
+    #[test]
+    fn stops_when_saving_fails() {
+        let source = include_str!("../data/sqrt2.tm");
+        let path = std::env::temp_dir()
+            .join("missing-directory")
+            .join("snapshot.txt");
+        let mut tm = SnapshotTuringMachineExt::with_saving(
+            machine::<DeterministicTuringMachine<String>>(source, ""),
+            0,
+            10,
+            path,
+        );
+        assert!(!tm.run());
+        assert_eq!(tm.get_number_of_steps(), 10);
+        assert!(tm.take_failure().is_some());
+    }
+
+    #[test]
+    fn rejects_other_machines() {
+        let paren = include_str!("../data/paren.tm");
+        let tm: DeterministicTuringMachine<String> = machine(paren, "(())");
+        let snapshot = tm.snapshot(0);
+
+        let other: DeterministicMachineRepresentation<String> =
+            load(include_str!("../data/binadd.tm"));
+        match DeterministicTuringMachine::<String>::resume(other, &snapshot) {
+            Err(SnapshotError::DifferentMachine { found, .. }) => {
+                assert_eq!(found, snapshot.machine)
+            }
+            other => panic!("Expected a different machine, got {:?}", other),
+        }
+
+        // The hash only depends on the machine, not on how it is run
+        let same: NonDeterministicMachineRepresentation<String> = load(paren);
+        assert_eq!(machine_hash(&same), snapshot.machine);
+    }
+
+    #[test]
+    fn writes_tapes_as_runs() {
+        let source = "snapshot 2\nmachine 0000000000000012\nsteps 3\npath 1 close\n^ $^2 )\n";
+        let snapshot = Snapshot::read(source.as_bytes()).unwrap();
+        let run = |symbol, len| Run { symbol, len };
+        assert_eq!(
+            snapshot.paths[0].tape,
+            vec![run('^', 1), run('$', 2), run(')', 1)]
+        );
+        let mut file = Vec::new();
+        snapshot.write(&mut file).unwrap();
+        assert_eq!(String::from_utf8(file).unwrap(), source);
+
+        // The first version wrote every cell
+        let old = "snapshot 1\nmachine 12\nsteps 3\npath 1 close\n^$$)__\n";
+        assert_eq!(Snapshot::read(old.as_bytes()).unwrap(), snapshot);
+
+        // Long runs stay short, and are never expanded on resuming
+        let tape = RunLengthTape::from_runs(&[run('1', 1 << 40)]);
+        let long = Snapshot {
+            machine: 0x12,
+            steps: 3,
+            paths: vec![Path {
+                state: "start".to_string(),
+                head: 0,
+                tape: tape.runs(),
+            }],
+        };
+        let mut file = Vec::new();
+        long.write(&mut file).unwrap();
+        assert!(String::from_utf8(file)
+            .unwrap()
+            .ends_with("\n1^1099511627776\n"));
+        assert_eq!(RunLengthTape::from_runs(&long.paths[0].tape), tape);
+    }
+
+    #[test]
+    fn rejects_malformed_snapshots() {
+        let cases = [
+            ("", 1),
+            ("snapshot 3\n", 1),
+            ("snapshot 1\nmachine xyz\n", 2),
+            ("snapshot 1\nmachine 12\nsteps -1\n", 3),
+            ("snapshot 1\nmachine 12\nsteps 1\npath 0\n", 4),
+            ("snapshot 1\nmachine 12\nsteps 1\npath 0 start\n", 5),
+            ("snapshot 2\nmachine 12\nsteps 1\npath 0 start\na^0\n", 5),
+            ("snapshot 2\nmachine 12\nsteps 1\npath 0 start\nab\n", 5),
+        ];
+        for (source, line) in cases.iter() {
+            match Snapshot::read(source.as_bytes()) {
+                Err(SnapshotError::Malformed(l)) => assert_eq!(l, *line, "{:?}", source),
+                other => panic!("Expected a malformed snapshot, got {:?}", other),
+            }
+        }
+    }
+}
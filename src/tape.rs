@@ -0,0 +1,606 @@
+//! Tapes of machines, and the backends storing them.
+//!
+//! The [`Tape`](trait.Tape.html) trait is what the engines read and write through. `Vec<char>` stores every cell,
+//! while [`RunLengthTape`](struct.RunLengthTape.html) stores runs of the same symbol, so that tapes made of long runs
+//! stay small. Code that goes over a whole tape should use [`runs`](trait.Tape.html#tymethod.runs) rather than
+//! [`symbols`](trait.Tape.html#tymethod.symbols), which expands every run.
+
+use std::{
+    fmt::Debug,
+    hash::{Hash, Hasher},
+    iter,
+    ops::Range,
+    rc::Rc,
+};
+
+/// A maximal block of cells holding the same symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Run {
+    pub symbol: char,
+    pub len: usize,
+}
+
+/// The storage behind the singly infinite tape of a [`TuringMachine`](../trait.TuringMachine.html).
+/// Every cell past the ones stored holds the blank symbol `_`.
+pub trait Tape: Clone + Debug + Eq + Hash + From<Vec<char>> {
+    /// Read the symbol in a cell
+    fn read(&self, cell: usize) -> char;
+
+    /// Write a symbol in a cell, growing the tape if needed
+    fn write(&mut self, cell: usize, symbol: char);
+
+    /// How many cells are stored, past which the tape is blank
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the stored cells one by one.
+    /// This expands every run, so use [`runs`](#tymethod.runs) for tapes which may be long
+    fn symbols(&self) -> Vec<char>;
+
+    /// Get the runs on the tape in order, merging neighbours with the same symbol and leaving out the trailing blanks
+    fn runs(&self) -> Vec<Run> {
+        let mut runs = merge((0..self.len()).map(|cell| Run {
+            symbol: self.read(cell),
+            len: 1,
+        }));
+        if runs.last().is_some_and(|r| r.symbol == '_') {
+            runs.pop();
+        }
+        runs
+    }
+
+    /// Build a tape holding the given runs
+    fn from_runs(runs: &[Run]) -> Self {
+        runs.iter()
+            .flat_map(|r| iter::repeat_n(r.symbol, r.len))
+            .collect::<Vec<char>>()
+            .into()
+    }
+
+    /// How many cells, starting from `cell` and going right, hold the same symbol as it.
+    /// This is `usize::MAX` when they all do, i.e. the blanks past the stored cells.
+    fn run_right(&self, cell: usize) -> usize {
+        let symbol = self.read(cell);
+        let mut end = cell;
+        while end < self.len() && self.read(end) == symbol {
+            end += 1;
+        }
+        if end >= self.len() && symbol == '_' {
+            usize::MAX
+        } else {
+            end - cell
+        }
+    }
+
+    /// How many cells, starting from `cell` and going left, hold the same symbol as it
+    fn run_left(&self, cell: usize) -> usize {
+        let symbol = self.read(cell);
+        let mut start = cell;
+        while start > 0 && self.read(start - 1) == symbol {
+            start -= 1;
+        }
+        cell - start + 1
+    }
+
+    /// Write the same symbol in a range of cells
+    fn fill(&mut self, cells: Range<usize>, symbol: char) {
+        for cell in cells {
+            self.write(cell, symbol);
+        }
+    }
+}
+
+impl Tape for Vec<char> {
+    fn read(&self, cell: usize) -> char {
+        *self.get(cell).unwrap_or(&'_')
+    }
+
+    /// Grows ahead of the head, doubling the tape, so that it can always read the next cell
+    fn write(&mut self, cell: usize, symbol: char) {
+        while cell + 1 >= self.len() {
+            let new_section = iter::repeat_n('_', self.len() + 2);
+            self.reserve(self.len() + 2);
+            self.extend(new_section);
+        }
+        self[cell] = symbol;
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+
+    fn symbols(&self) -> Vec<char> {
+        self.clone()
+    }
+}
+
+/// How many runs a segment holds before it is split in two
+const SEGMENT_RUNS: usize = 64;
+
+/// A [`Tape`](trait.Tape.html) storing runs of repeated symbols rather than single cells.
+/// The runs are grouped into segments, which are shared between clones and only copied once written to,
+/// so the branches of a [`NonDeterministicTuringMachine`](../non_deterministic_tm/struct.NonDeterministicTuringMachine.html) only pay for the parts of the tape they change.
+///
+/// Two tapes are equal when they hold the same symbols, no matter how they are split into segments or how many trailing blanks they store.
+#[derive(Debug, Clone, Default)]
+pub struct RunLengthTape {
+    segments: Vec<Rc<Vec<Run>>>,
+    /// The first cell of each segment
+    starts: Vec<usize>,
+    len: usize,
+}
+
+impl RunLengthTape {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// How many segments the tape is split into
+    pub fn num_segments(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Find the segment and run holding a cell, with the first cell of that run
+    fn locate(&self, cell: usize) -> Option<(usize, usize, usize)> {
+        if cell >= self.len {
+            return None;
+        }
+        let segment = self.starts.partition_point(|start| *start <= cell) - 1;
+        let mut start = self.starts[segment];
+        for (i, run) in self.segments[segment].iter().enumerate() {
+            if cell < start + run.len {
+                return Some((segment, i, start));
+            }
+            start += run.len;
+        }
+        unreachable!("The segments cover the whole stored tape")
+    }
+
+    /// Add a run at the end of the tape
+    fn push(&mut self, run: Run) {
+        if run.len == 0 {
+            return;
+        }
+        self.len += run.len;
+        if let Some(last) = self.segments.last_mut() {
+            let runs = Rc::make_mut(last);
+            let full = runs.len() >= SEGMENT_RUNS;
+            match runs.last_mut() {
+                Some(previous) if previous.symbol == run.symbol => {
+                    previous.len += run.len;
+                    return;
+                }
+                _ if !full => {
+                    runs.push(run);
+                    return;
+                }
+                _ => {}
+            }
+        }
+        self.starts.push(self.len - run.len);
+        self.segments.push(Rc::new(vec![run]));
+    }
+
+    /// How many cells a segment covers
+    fn segment_len(&self, segment: usize) -> usize {
+        self.starts.get(segment + 1).unwrap_or(&self.len) - self.starts[segment]
+    }
+
+    /// Split a segment that grew too long in two
+    fn split(&mut self, segment: usize) {
+        let runs = Rc::make_mut(&mut self.segments[segment]);
+        let second = runs.split_off(runs.len() / 2);
+        let start = self.starts[segment] + runs.iter().map(|r| r.len).sum::<usize>();
+        self.segments.insert(segment + 1, Rc::new(second));
+        self.starts.insert(segment + 1, start);
+    }
+}
+
+/// The runs covering the cells `from..to` of a segment, counting from its start
+fn cut(runs: &[Run], from: usize, to: usize) -> Vec<Run> {
+    let mut cut = Vec::new();
+    let mut start = 0;
+    for run in runs {
+        let end = start + run.len;
+        let len = end.min(to).saturating_sub(start.max(from));
+        if len > 0 {
+            cut.push(Run {
+                symbol: run.symbol,
+                len,
+            });
+        }
+        start = end;
+    }
+    cut
+}
+
+/// Merge neighbouring runs with the same symbol
+fn merge(runs: impl IntoIterator<Item = Run>) -> Vec<Run> {
+    let mut merged: Vec<Run> = Vec::new();
+    for run in runs.into_iter().filter(|r| r.len > 0) {
+        match merged.last_mut() {
+            Some(last) if last.symbol == run.symbol => last.len += run.len,
+            _ => merged.push(run),
+        }
+    }
+    merged
+}
+
+impl From<Vec<char>> for RunLengthTape {
+    fn from(symbols: Vec<char>) -> Self {
+        let mut tape = Self::new();
+        for symbol in symbols {
+            tape.push(Run { symbol, len: 1 });
+        }
+        tape
+    }
+}
+
+impl Tape for RunLengthTape {
+    fn read(&self, cell: usize) -> char {
+        match self.locate(cell) {
+            Some((segment, run, _)) => self.segments[segment][run].symbol,
+            None => '_',
+        }
+    }
+
+    fn write(&mut self, cell: usize, symbol: char) {
+        let (segment, index, start) = match self.locate(cell) {
+            Some(found) => found,
+            None => {
+                // Blanks past the end are implicit
+                if symbol != '_' {
+                    self.push(Run {
+                        symbol: '_',
+                        len: cell - self.len,
+                    });
+                    self.push(Run { symbol, len: 1 });
+                }
+                return;
+            }
+        };
+        let run = self.segments[segment][index];
+        if run.symbol == symbol {
+            return;
+        }
+
+        // Cut the run around the cell, and merge what is left with the neighbouring runs
+        let before = cell - start;
+        let after = run.len - before - 1;
+        let runs = Rc::make_mut(&mut self.segments[segment]);
+        let pieces = [
+            Run {
+                symbol: run.symbol,
+                len: before,
+            },
+            Run { symbol, len: 1 },
+            Run {
+                symbol: run.symbol,
+                len: after,
+            },
+        ];
+        let from = index.saturating_sub(1);
+        let to = (index + 2).min(runs.len());
+        let merged = merge(
+            runs[from..index]
+                .iter()
+                .chain(pieces.iter())
+                .chain(runs[index + 1..to].iter())
+                .cloned(),
+        );
+        runs.splice(from..to, merged);
+
+        if runs.len() > 2 * SEGMENT_RUNS {
+            self.split(segment);
+        }
+    }
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn symbols(&self) -> Vec<char> {
+        self.segments
+            .iter()
+            .flat_map(|s| s.iter())
+            .flat_map(|r| iter::repeat_n(r.symbol, r.len))
+            .collect()
+    }
+
+    fn runs(&self) -> Vec<Run> {
+        let mut runs = merge(self.segments.iter().flat_map(|s| s.iter()).cloned());
+        if runs.last().is_some_and(|r| r.symbol == '_') {
+            runs.pop();
+        }
+        runs
+    }
+
+    fn from_runs(runs: &[Run]) -> Self {
+        let mut tape = Self::new();
+        for run in runs {
+            tape.push(*run);
+        }
+        tape
+    }
+
+    fn run_right(&self, cell: usize) -> usize {
+        let (segment, index, start) = match self.locate(cell) {
+            Some(found) => found,
+            None => return usize::MAX,
+        };
+        let symbol = self.segments[segment][index].symbol;
+        let mut end = start;
+        let following = self.segments[segment][index..]
+            .iter()
+            .chain(self.segments[segment + 1..].iter().flat_map(|s| s.iter()));
+        for run in following.take_while(|r| r.symbol == symbol) {
+            end += run.len;
+        }
+        if end == self.len && symbol == '_' {
+            usize::MAX
+        } else {
+            end - cell
+        }
+    }
+
+    fn run_left(&self, cell: usize) -> usize {
+        let (segment, index, start) = match self.locate(cell) {
+            Some(found) => found,
+            None => {
+                // Past the stored cells everything is blank, up to the blanks stored last
+                let stored = match self.len.checked_sub(1) {
+                    Some(end) if self.read(end) == '_' => self.run_left(end),
+                    _ => 0,
+                };
+                return cell - self.len + 1 + stored;
+            }
+        };
+        let symbol = self.segments[segment][index].symbol;
+        let preceding = self.segments[segment][..index].iter().rev().chain(
+            self.segments[..segment]
+                .iter()
+                .rev()
+                .flat_map(|s| s.iter().rev()),
+        );
+        let before: usize = preceding
+            .take_while(|r| r.symbol == symbol)
+            .map(|r| r.len)
+            .sum();
+        cell - start + 1 + before
+    }
+
+    fn fill(&mut self, cells: Range<usize>, symbol: char) {
+        if cells.start >= cells.end {
+            return;
+        }
+        if cells.end > self.len {
+            // Write what is stored, then add what is past it as a single run
+            let stored = cells.start.min(self.len)..self.len;
+            self.fill(stored, symbol);
+            if symbol != '_' {
+                self.push(Run {
+                    symbol: '_',
+                    len: cells.start.saturating_sub(self.len),
+                });
+                self.push(Run {
+                    symbol,
+                    len: cells.end - cells.start.max(self.len),
+                });
+            }
+            return;
+        }
+
+        let first = self.locate(cells.start).unwrap().0;
+        let last = self.locate(cells.end - 1).unwrap().0;
+        let from = cells.start - self.starts[first];
+        let run = Run {
+            symbol,
+            len: cells.end - cells.start,
+        };
+        if first == last {
+            let len = self.segment_len(first);
+            let runs = &self.segments[first];
+            let to = cells.end - self.starts[first];
+            let filled = merge(
+                cut(runs, 0, from)
+                    .into_iter()
+                    .chain(Some(run))
+                    .chain(cut(runs, to, len)),
+            );
+            self.segments[first] = Rc::new(filled);
+        } else {
+            // The first segment takes the whole run, the last one keeps what follows it
+            let len = self.segment_len(last);
+            let to = cells.end - self.starts[last];
+            let rest = cut(&self.segments[last], to, len);
+            let filled = merge(
+                cut(&self.segments[first], 0, from)
+                    .into_iter()
+                    .chain(Some(run)),
+            );
+            self.segments[first] = Rc::new(filled);
+            if rest.is_empty() {
+                self.segments.drain(first + 1..=last);
+                self.starts.drain(first + 1..=last);
+            } else {
+                self.segments[last] = Rc::new(rest);
+                self.starts[last] = cells.end;
+                self.segments.drain(first + 1..last);
+                self.starts.drain(first + 1..last);
+            }
+        }
+
+        if self.segments[first].len() > 2 * SEGMENT_RUNS {
+            self.split(first);
+        }
+    }
+}
+
+impl PartialEq for RunLengthTape {
+    fn eq(&self, other: &Self) -> bool {
+        self.runs() == other.runs()
+    }
+}
+
+impl Eq for RunLengthTape {}
+
+impl Hash for RunLengthTape {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.runs().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        builders::TuringMachineBuilder, deterministic_tm::DeterministicTuringMachine,
+        limited::LimitedTuringMachineExt, machine_parser,
+        machine_representation::MachineRepresentation,
+        non_deterministic_tm::NonDeterministicTuringMachine, TuringMachine,
+    };
+
+    /// Apply the same writes to both backends, checking they always agree.
+    /// Single cells are written, longer ranges filled.
+    fn compare(initial: &str, writes: impl Iterator<Item = (Range<usize>, char)>) -> RunLengthTape {
+        let mut plain: Vec<char> = initial.chars().collect();
+        let mut runs = RunLengthTape::from(plain.clone());
+        for (cells, symbol) in writes {
+            if cells.len() == 1 {
+                plain.write(cells.start, symbol);
+                runs.write(cells.start, symbol);
+            } else {
+                plain.fill(cells.clone(), symbol);
+                runs.fill(cells, symbol);
+            }
+            for i in 0..plain.len() + 3 {
+                assert_eq!(runs.read(i), plain.read(i), "cell {}", i);
+                assert_eq!(runs.run_right(i), plain.run_right(i), "run from {}", i);
+                assert_eq!(runs.run_left(i), plain.run_left(i), "run to {}", i);
+            }
+        }
+        let stored = runs.symbols();
+        assert_eq!(&plain[..stored.len()], stored.as_slice());
+        assert!(plain[stored.len()..].iter().all(|c| *c == '_'));
+        runs
+    }
+
+    #[test]
+    fn agrees_with_plain_tapes() {
+        let mut seed = 0x9e37_79b9_u32;
+        let writes = iter::repeat_with(|| {
+            seed ^= seed << 13;
+            seed ^= seed >> 17;
+            seed ^= seed << 5;
+            let symbol = ['_', '0', '1'][(seed >> 8) as usize % 3];
+            let start = seed as usize % 400;
+            let len = match (seed >> 12) % 8 {
+                0 => (seed >> 16) as usize % 40,
+                _ => 1,
+            };
+            (start..start + len, symbol)
+        });
+        let tape = compare(&"0011_10".repeat(50), writes.take(400));
+        assert!(tape.num_segments() > 1);
+    }
+
+    #[test]
+    fn stores_uniform_regions_as_runs() {
+        let tape = compare("", (0..1000).map(|i| (i..i + 1, '1')));
+        assert_eq!(
+            tape.runs(),
+            vec![Run {
+                symbol: '1',
+                len: 1000
+            }]
+        );
+        assert_eq!(tape.num_segments(), 1);
+
+        let mut sparse = RunLengthTape::new();
+        sparse.write(1_000_000_000, 'x');
+        assert_eq!(sparse.len(), 1_000_000_001);
+        assert_eq!(sparse.read(999_999_999), '_');
+        assert_eq!(sparse.read(1_000_000_000), 'x');
+        sparse.fill(10..2_000_000_000, 'y');
+        assert_eq!(sparse.run_right(10), 2_000_000_000 - 10);
+        assert_eq!(sparse.run_left(1_999_999_999), 2_000_000_000 - 10);
+        assert_eq!(sparse.runs().len(), 2);
+    }
+
+    #[test]
+    fn shares_segments_until_written() {
+        let mut first = RunLengthTape::from(
+            (0..1000)
+                .map(|i| if i % 2 == 0 { 'a' } else { 'b' })
+                .collect::<Vec<_>>(),
+        );
+        let mut second = first.clone();
+        assert!(first
+            .segments
+            .iter()
+            .zip(&second.segments)
+            .all(|(a, b)| Rc::ptr_eq(a, b)));
+
+        second.write(10, 'c');
+        let shared = first
+            .segments
+            .iter()
+            .zip(&second.segments)
+            .filter(|(a, b)| Rc::ptr_eq(a, b))
+            .count();
+        assert_eq!(shared, first.num_segments() - 1);
+        assert_ne!(first, second);
+
+        first.write(10, 'c');
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn compares_by_content() {
+        let short = RunLengthTape::from("ab".chars().collect::<Vec<_>>());
+        let mut long = RunLengthTape::from("a___".chars().collect::<Vec<_>>());
+        assert_ne!(short, long);
+        long.write(1, 'b');
+        assert_eq!(short, long);
+    }
+
+    fn run<T>(source: &str, tape: &str, limit: usize) -> (bool, usize, T::Tape)
+    where
+        T: TuringMachine<StateTy = String>,
+        T::ReprTy: MachineRepresentation<String, InputTy = char>,
+        <T::ReprTy as MachineRepresentation<String>>::ErrorTy: Debug,
+    {
+        let repr =
+            T::ReprTy::from_builder(&machine_parser::parse(source.as_bytes()).unwrap()).unwrap();
+        let tm = T::from_builder(
+            TuringMachineBuilder::new()
+                .repr(repr)
+                .tape(tape.chars().collect()),
+        )
+        .unwrap();
+        let exe = LimitedTuringMachineExt::new_with_limit(tm, limit).execute_and_get_result();
+        (exe.accepting, exe.num_steps, exe.tape)
+    }
+
+    #[test]
+    fn runs_machines_the_same() {
+        let sqrt2 = include_str!("../data/sqrt2.tm");
+        let (_, steps, plain) = run::<DeterministicTuringMachine<String>>(sqrt2, "", 20_000);
+        let (_, run_steps, runs) =
+            run::<DeterministicTuringMachine<String, RunLengthTape>>(sqrt2, "", 20_000);
+        assert_eq!(steps, run_steps);
+        assert_eq!(RunLengthTape::from(plain), runs);
+
+        let repeat = include_str!("../data/repeat.tm");
+        for input in &["0101", "0110", "1111", "100100"] {
+            let (accepting, steps, _) =
+                run::<NonDeterministicTuringMachine<String>>(repeat, input, 10_000);
+            let (run_accepting, run_steps, _) =
+                run::<NonDeterministicTuringMachine<String, RunLengthTape>>(repeat, input, 10_000);
+            assert_eq!((accepting, steps), (run_accepting, run_steps), "{}", input);
+        }
+    }
+}
@@ -0,0 +1,332 @@
+//! Declarative test suites for machines.
+//!
+//! A test file contains one case per line, in the form `<verdict> <tape> [tape=<output>] [limit=<steps>]`, where:
+//! * `verdict` is one of `accept`, `reject` or `halt`
+//! * `tape` is the starting tape, use `_` for the empty tape
+//! * `tape=<output>` optionally checks the final tape, without trailing blanks
+//! * `limit=<steps>` optionally bounds the number of steps the machine can take, [`DEFAULT_LIMIT`](constant.DEFAULT_LIMIT.html) otherwise
+//!
+//! A case where the machine is still running past its limit fails, whatever verdict it expects.
+//!
+//! Blank lines and lines starting with `#` are ignored.
+
+use std::{
+    fmt::{self, Display},
+    io::{self, BufRead, BufReader, Read},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        Mutex,
+    },
+    thread,
+};
+
+use crate::{
+    builders::TuringMachineBuilder, limited::LimitedTuringMachineExt,
+    machine_representation::MachineRepresentation, TuringMachine,
+};
+
+/// How many steps a machine can take in a case without a `limit`
+pub const DEFAULT_LIMIT: usize = 1_000_000;
+
+/// The way a machine is expected to stop
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Accept,
+    Reject,
+    Halt,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Accept => write!(f, "accept"),
+            Verdict::Reject => write!(f, "reject"),
+            Verdict::Halt => write!(f, "halt"),
+        }
+    }
+}
+
+/// A single test case, as read from a test file
+#[derive(Debug, Clone, PartialEq)]
+pub struct TestCase {
+    /// The line of the test file the case was read from
+    pub line: usize,
+    pub tape: Vec<char>,
+    pub verdict: Verdict,
+
+    /// The expected final tape, without trailing blanks
+    pub output: Option<String>,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug)]
+pub enum TestSuiteError {
+    /// A line has a verdict but no tape
+    MissingTape(usize),
+    InvalidVerdict(usize, String),
+    InvalidOption(usize, String),
+    InvalidLimit(usize, String),
+    IO(io::Error),
+}
+
+impl From<io::Error> for TestSuiteError {
+    fn from(err: io::Error) -> Self {
+        TestSuiteError::IO(err)
+    }
+}
+
+/// Parse a test file into its cases
+pub fn parse(source: impl Read) -> Result<Vec<TestCase>, TestSuiteError> {
+    let mut cases = Vec::new();
+    for (i, line) in BufReader::new(source).lines().enumerate() {
+        let line = line?;
+        let line_number = i + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let mut tokens = line.split_whitespace();
+        let verdict = match tokens.next() {
+            Some("accept") => Verdict::Accept,
+            Some("reject") => Verdict::Reject,
+            Some("halt") => Verdict::Halt,
+            Some(v) => return Err(TestSuiteError::InvalidVerdict(line_number, v.to_string())),
+            None => unreachable!(),
+        };
+
+        let tape = tokens
+            .next()
+            .ok_or(TestSuiteError::MissingTape(line_number))?
+            .chars()
+            .collect();
+
+        let mut case = TestCase {
+            line: line_number,
+            tape,
+            verdict,
+            output: None,
+            limit: None,
+        };
+
+        for option in tokens {
+            if let Some(output) = option.strip_prefix("tape=") {
+                case.output = Some(output.trim_end_matches('_').to_string());
+            } else if let Some(limit) = option.strip_prefix("limit=") {
+                case.limit =
+                    Some(limit.parse().map_err(|_| {
+                        TestSuiteError::InvalidLimit(line_number, limit.to_string())
+                    })?);
+            } else {
+                return Err(TestSuiteError::InvalidOption(
+                    line_number,
+                    option.to_string(),
+                ));
+            }
+        }
+
+        cases.push(case);
+    }
+    Ok(cases)
+}
+
+/// The outcome of running a single [`TestCase`](struct.TestCase.html)
+#[derive(Debug, Clone)]
+pub struct CaseResult {
+    pub case: TestCase,
+
+    /// How the machine actually stopped, `None` if the machine could not be built or did not stop within the limit
+    pub verdict: Option<Verdict>,
+
+    /// Was the machine still running past the limit?
+    pub timed_out: bool,
+
+    /// The final tape, as displayed by the machine
+    pub output: String,
+    pub num_steps: usize,
+
+    /// The reason the machine could not be built, if any
+    pub error: Option<String>,
+}
+
+impl CaseResult {
+    /// Did the machine behave as expected?
+    pub fn passed(&self) -> bool {
+        self.verdict == Some(self.case.verdict)
+            && self
+                .case
+                .output
+                .as_ref()
+                .is_none_or(|output| *output == self.output)
+    }
+}
+
+/// Runs a single case on a fresh machine built from `repr`
+pub fn run_case<T>(repr: &T::ReprTy, case: &TestCase) -> CaseResult
+where
+    T: TuringMachine + Display,
+    T::ReprTy: MachineRepresentation<T::StateTy, InputTy = char> + Clone,
+{
+    let builder = TuringMachineBuilder::new()
+        .repr(repr.clone())
+        .tape(case.tape.clone());
+
+    let machine = match T::from_builder(builder) {
+        Ok(machine) => machine,
+        Err(e) => {
+            return CaseResult {
+                case: case.clone(),
+                verdict: None,
+                timed_out: false,
+                output: String::new(),
+                num_steps: 0,
+                error: Some(format!("{:?}", e)),
+            }
+        }
+    };
+
+    let limit = case.limit.unwrap_or(DEFAULT_LIMIT);
+    let exe = LimitedTuringMachineExt::new_with_limit(machine, limit).execute_and_get_result();
+
+    // The limited extension counts a machine over the limit as rejecting
+    let timed_out = exe.num_steps > limit;
+    let verdict = if timed_out {
+        None
+    } else if exe.accepting {
+        Some(Verdict::Accept)
+    } else if exe.halting {
+        Some(Verdict::Halt)
+    } else {
+        Some(Verdict::Reject)
+    };
+
+    CaseResult {
+        case: case.clone(),
+        verdict,
+        timed_out,
+        output: exe
+            .tm
+            .to_string()
+            .trim_end()
+            .trim_end_matches('_')
+            .to_string(),
+        num_steps: exe.num_steps,
+        error: None,
+    }
+}
+
+/// Runs all the cases, spread over `num_threads` threads. The results are in the same order as the cases
+pub fn run_suite<T>(repr: &T::ReprTy, cases: &[TestCase], num_threads: usize) -> Vec<CaseResult>
+where
+    T: TuringMachine + Display,
+    T::ReprTy: MachineRepresentation<T::StateTy, InputTy = char> + Clone + Sync,
+{
+    let next_case = AtomicUsize::new(0);
+    let results = Mutex::new(vec![None; cases.len()]);
+
+    thread::scope(|s| {
+        for _ in 0..num_threads.max(1) {
+            s.spawn(|| loop {
+                let i = next_case.fetch_add(1, Ordering::SeqCst);
+                if i >= cases.len() {
+                    break;
+                }
+                let result = run_case::<T>(repr, &cases[i]);
+                results.lock().unwrap()[i] = Some(result);
+            });
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("Every case has been run"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::deterministic_tm::{DeterministicMachineRepresentation, DeterministicTuringMachine};
+    use crate::machine_parser;
+
+    #[test]
+    fn parse_cases() {
+        let suite = "# comment\naccept (())\n\nreject (() limit=10\nhalt _ tape=[$$_\n";
+        let cases = parse(suite.as_bytes()).unwrap();
+        assert_eq!(cases.len(), 3);
+
+        assert_eq!(cases[0].line, 2);
+        assert_eq!(cases[0].verdict, Verdict::Accept);
+        assert_eq!(cases[0].tape, vec!['(', '(', ')', ')']);
+
+        assert_eq!(cases[1].verdict, Verdict::Reject);
+        assert_eq!(cases[1].limit, Some(10));
+
+        assert_eq!(cases[2].verdict, Verdict::Halt);
+        assert_eq!(cases[2].output, Some("[$$".to_string()));
+    }
+
+    #[test]
+    fn parse_errors() {
+        match parse("maybe (())".as_bytes()) {
+            Err(TestSuiteError::InvalidVerdict(1, _)) => {}
+            _ => panic!("Invalid Enum Variant"),
+        }
+        match parse("accept".as_bytes()) {
+            Err(TestSuiteError::MissingTape(1)) => {}
+            _ => panic!("Invalid Enum Variant"),
+        }
+        match parse("accept () steps=3".as_bytes()) {
+            Err(TestSuiteError::InvalidOption(1, _)) => {}
+            _ => panic!("Invalid Enum Variant"),
+        }
+        match parse("accept () limit=x".as_bytes()) {
+            Err(TestSuiteError::InvalidLimit(1, _)) => {}
+            _ => panic!("Invalid Enum Variant"),
+        }
+    }
+
+    #[test]
+    fn run_paren_suite() {
+        let parser = machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let cases = parse(include_str!("../data/paren.tests").as_bytes()).unwrap();
+
+        let results = run_suite::<DeterministicTuringMachine<String>>(&repr, &cases, 4);
+        assert_eq!(results.len(), cases.len());
+        for result in results {
+            assert!(result.passed(), "Line {} failed", result.case.line);
+        }
+
+        let wrong = parse("reject (()) tape=x".as_bytes()).unwrap();
+        let result = run_case::<DeterministicTuringMachine<String>>(&repr, &wrong[0]);
+        assert_eq!(result.verdict, Some(Verdict::Accept));
+        assert!(!result.passed());
+    }
+
+    #[test]
+    fn fail_on_timeouts() {
+        let parser = machine_parser::parse(include_str!("../data/paren.tm").as_bytes()).unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let cases = parse("reject (((((((((( limit=5\naccept () limit=5".as_bytes()).unwrap();
+        for case in &cases {
+            let result = run_case::<DeterministicTuringMachine<String>>(&repr, case);
+            assert!(result.timed_out);
+            assert_eq!(result.verdict, None);
+            assert!(!result.passed());
+        }
+
+        // Without a limit, a machine running forever stops at the default one
+        let parser = machine_parser::parse(
+            "states 3\nq0\nhalt !\nreject -\nalphabet 1 1\nq0 _ q0 _ S\n".as_bytes(),
+        )
+        .unwrap();
+        let repr = DeterministicMachineRepresentation::from_builder(&parser).unwrap();
+        let cases = parse("reject _".as_bytes()).unwrap();
+        let result = run_case::<DeterministicTuringMachine<String>>(&repr, &cases[0]);
+        assert!(result.timed_out);
+        assert_eq!(result.num_steps, DEFAULT_LIMIT + 1);
+    }
+}
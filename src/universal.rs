@@ -0,0 +1,603 @@
+//! A universal Turing machine, and the standard encoding of deterministic machines it runs.
+//!
+//! A machine `M` with input `w` is encoded as a single string `<M,w>`:
+//!
+//! ```text
+//! ^ A . Q k # :T ;T ... ;T $ *c |c |c ...
+//! ```
+//!
+//! States and symbols are numbered in binary with a fixed width, the blank being symbol zero. `A` is a register for
+//! the symbol under the head, `Q` is the current state and `k` its kind, one of `~` (running), `+`, `-` or `!`. Each
+//! transition `T` is written `a . q > q' k' a' m`, with `m` one of `L`, `R` or `S`; the `:` marks the transition being
+//! looked at. The cells `c` of the tape follow the `$`, the one under the head being introduced by `*` rather than `|`.
+//!
+//! The [`universal`](fn.universal.html) machine repeatedly copies the symbol under the head into the register, compares
+//! the register with each transition in turn, copies the next state and the written symbol of the matching one, and
+//! moves the head marker, growing the tape by a blank cell when needed. It accepts, rejects or halts when the simulated
+//! machine does, and rejects when no transition matches, like
+//! [`DeterministicTuringMachine`](../deterministic_tm/struct.DeterministicTuringMachine.html) does. The generated
+//! machine is shipped as `data/universal.tm`.
+
+use hashbrown::HashSet;
+
+use crate::{
+    binary::{BlockEncoding, EncodingError},
+    builders::MachineRepresentationBuilder,
+    common::{Action, Motion, State},
+    deterministic_tm::DeterministicMachineRepresentation,
+    machine_parser::MachineParser,
+    machine_representation::MachineRepresentation,
+    transition_table::TransitionTable,
+};
+
+type Repr = DeterministicMachineRepresentation<String>;
+
+const BITS: [char; 2] = ['0', '1'];
+const MARKED: [char; 2] = ['o', 'i'];
+const KINDS: [char; 4] = ['~', '+', '-', '!'];
+const MOVES: [char; 3] = ['L', 'R', 'S'];
+const PUNCTUATION: [char; 10] = ['^', '.', '#', ';', 'x', '>', '$', '|', '*', '_'];
+
+fn kind_char(kind: State) -> char {
+    match kind {
+        State::Neutral => '~',
+        State::Accepting => '+',
+        State::Rejecting => '-',
+        State::Halting => '!',
+    }
+}
+
+/// Numbers the states and symbols of a machine, to encode it along with its input and decode the tapes of the
+/// universal machine
+#[derive(Debug, Clone)]
+pub struct Description {
+    symbols: BlockEncoding,
+    states: Vec<String>,
+    state_width: usize,
+    transitions: Vec<char>,
+    start: Vec<char>,
+}
+
+impl Description {
+    pub fn new(repr: &Repr) -> Self {
+        let symbols = BlockEncoding::new(repr.alphabet());
+        let mut states: Vec<String> = repr.states().iter().cloned().collect();
+        states.sort();
+        let mut state_width = 1;
+        while 1 << state_width < states.len() {
+            state_width += 1;
+        }
+
+        let mut description = Description {
+            symbols,
+            states,
+            state_width,
+            transitions: Vec::new(),
+            start: Vec::new(),
+        };
+        description.start = description.state_code(repr.starting_state());
+        description
+            .start
+            .push(kind_char(repr.state_kind(repr.starting_state())));
+
+        for state in &description.states {
+            if repr.state_kind(state) != State::Neutral {
+                continue;
+            }
+            for symbol in description.symbols.symbols() {
+                if let Some(act) = repr
+                    .transition_table()
+                    .apply_transition_table(state, *symbol)
+                {
+                    let mut transition = vec![';'];
+                    transition.extend(description.symbol_code(*symbol));
+                    transition.push('.');
+                    transition.extend(description.state_code(state));
+                    transition.push('>');
+                    transition.extend(description.state_code(act.next_state()));
+                    transition.push(kind_char(repr.state_kind(act.next_state())));
+                    transition.extend(description.symbol_code(*act.tape_output()));
+                    transition.push(match act.motion() {
+                        Motion::Left => 'L',
+                        Motion::Right => 'R',
+                        Motion::Stay => 'S',
+                    });
+                    description.transitions.extend(transition);
+                }
+            }
+        }
+        description
+    }
+
+    fn state_code(&self, state: &String) -> Vec<char> {
+        let index = self.states.iter().position(|s| s == state).unwrap();
+        (0..self.state_width)
+            .rev()
+            .map(|i| BITS[(index >> i) & 1])
+            .collect()
+    }
+
+    fn symbol_code(&self, symbol: char) -> Vec<char> {
+        self.symbols
+            .code(symbol)
+            .expect("Transitions stay within the alphabet")
+            .into_iter()
+            .map(|c| if c == '_' { '0' } else { '1' })
+            .collect()
+    }
+
+    /// The tape `<M,w>` the universal machine starts on
+    pub fn encode(&self, tape: &[char]) -> Result<Vec<char>, EncodingError> {
+        let mut encoded = vec!['^'];
+        encoded.extend((0..self.symbols.width()).map(|_| '0'));
+        encoded.push('.');
+        encoded.extend(&self.start);
+        encoded.push('#');
+        encoded.extend(&self.transitions);
+        encoded.push('$');
+
+        let blank = ['_'];
+        let cells = if tape.is_empty() { &blank[..] } else { tape };
+        for (i, c) in cells.iter().enumerate() {
+            encoded.push(if i == 0 { '*' } else { '|' });
+            let code = self.symbols.code(*c)?;
+            encoded.extend(code.into_iter().map(|c| if c == '_' { '0' } else { '1' }));
+        }
+        Ok(encoded)
+    }
+
+    /// The tape and head position of the simulated machine, from a tape of the universal machine
+    pub fn decode(&self, tape: &[char]) -> Result<(Vec<char>, usize), EncodingError> {
+        let start = tape
+            .iter()
+            .position(|c| *c == '$')
+            .ok_or_else(|| EncodingError::UnknownBlock(tape.to_vec()))?;
+
+        let mut cells = Vec::new();
+        let mut head = 0;
+        for c in tape[start + 1..].iter().take_while(|c| **c != '_') {
+            match c {
+                '|' | '*' => {
+                    if *c == '*' {
+                        head = cells.len();
+                    }
+                    cells.push(Vec::new());
+                }
+                // Bits may still be marked when the machine stops in the middle of a step
+                '0' | 'o' => cells.last_mut().unwrap().push('_'),
+                '1' | 'i' => cells.last_mut().unwrap().push('1'),
+                _ => return Err(EncodingError::UnknownBlock(vec![*c])),
+            }
+        }
+        let decoded = self.symbols.decode_tape(&cells.concat())?;
+        Ok((decoded, head))
+    }
+}
+
+/// Accumulates the states and transitions of the universal machine
+#[derive(Default)]
+struct Generator {
+    parser: MachineParser,
+    states: HashSet<String>,
+}
+
+impl Generator {
+    fn state(&mut self, name: &str) -> String {
+        if self.states.insert(name.to_string()) {
+            let kind = match name {
+                "accept" => State::Accepting,
+                "reject" => State::Rejecting,
+                "halt" => State::Halting,
+                _ => State::Neutral,
+            };
+            self.parser
+                .add_state(name.to_string(), kind)
+                .expect("State names are unique");
+        }
+        name.to_string()
+    }
+
+    fn add(&mut self, from: &str, read: char, to: &str, write: char, motion: Motion) {
+        self.state(from);
+        self.state(to);
+        self.parser.get_transition_builder().add_transition(
+            from.to_string(),
+            read,
+            Action::new(to.to_string(), write, motion),
+        );
+    }
+
+    /// Leave the symbols unchanged and keep moving
+    fn skip(&mut self, from: &str, symbols: &[char], motion: Motion) {
+        for c in symbols {
+            self.add(from, *c, from, *c, motion);
+        }
+    }
+
+    /// Move right until `target`, then move past it into `next`
+    fn seek(&mut self, from: &str, target: char, next: &str) {
+        let others: Vec<char> = alphabet().into_iter().filter(|c| *c != target).collect();
+        self.skip(from, &others, Motion::Right);
+        self.add(from, target, next, target, Motion::Right);
+    }
+
+    /// The state going back to the leftmost cell, then entering `next` on the cell after it
+    fn rewind(&mut self, next: &str) -> String {
+        let name = format!("back-{}", next);
+        if !self.states.contains(&name) {
+            let others: Vec<char> = alphabet().into_iter().filter(|c| *c != '^').collect();
+            self.skip(&name, &others, Motion::Left);
+            self.add(&name, '^', next, '^', Motion::Right);
+        }
+        name
+    }
+
+    /// The state unmarking the register, then going back into `next`
+    fn clear(&mut self, next: &str) -> String {
+        let name = format!("clear-{}", next);
+        if !self.states.contains(&name) {
+            let back = self.rewind(next);
+            self.add(&name, 'o', &name, '0', Motion::Right);
+            self.add(&name, 'i', &name, '1', Motion::Right);
+            self.skip(&name, &BITS, Motion::Right);
+            self.skip(&name, &KINDS, Motion::Right);
+            self.add(&name, '.', &name, '.', Motion::Right);
+            self.add(&name, '#', &back, '#', Motion::Left);
+        }
+        name
+    }
+}
+
+fn alphabet() -> Vec<char> {
+    [&BITS[..], &MARKED, &KINDS, &MOVES, &PUNCTUATION].concat()
+}
+
+/// Build the universal machine
+pub fn universal() -> Repr {
+    let mut g = Generator::default();
+
+    // Check the kind of the current state, starting on the leftmost cell
+    let fetch_back = g.rewind("fetch");
+    g.add("check", '^', "check", '^', Motion::Right);
+    g.skip("check", &BITS, Motion::Right);
+    g.add("check", '.', "check", '.', Motion::Right);
+    g.add("check", '~', &fetch_back, '~', Motion::Left);
+    g.add("check", '+', "accept", '+', Motion::Stay);
+    g.add("check", '-', "reject", '-', Motion::Stay);
+    g.add("check", '!', "halt", '!', Motion::Stay);
+
+    // Copy the symbol under the head into the register, marking the bits copied on both sides
+    g.seek("fetch", '*', "fetch-cell");
+    g.skip("fetch-cell", &MARKED, Motion::Right);
+    for (bit, marked) in BITS.iter().zip(&MARKED) {
+        let put = format!("fetch-put-{}", bit);
+        let back = g.rewind(&put);
+        g.add("fetch-cell", *bit, &back, *marked, Motion::Left);
+        g.skip(&put, &MARKED, Motion::Right);
+        g.add(&put, '0', "fetch", *marked, Motion::Right);
+        g.add(&put, '1', "fetch", *marked, Motion::Right);
+    }
+    let clear = g.clear("compare");
+    let back = g.rewind(&clear);
+    g.add("fetch-cell", '|', &back, '|', Motion::Left);
+    g.add("fetch-cell", '_', &back, '_', Motion::Left);
+
+    // Compare each bit of the register with the transitions still matching, ruling out the others by turning their
+    // `;` into `x`. Once the whole register is compared, at most one transition is left
+    g.skip("compare", &MARKED, Motion::Right);
+    g.add("compare", '.', "compare", '.', Motion::Right);
+    for kind in &KINDS {
+        g.add("compare", *kind, "apply", *kind, Motion::Right);
+    }
+    let back = g.rewind("compare");
+    for (bit, marked) in BITS.iter().zip(&MARKED) {
+        let sweep = format!("compare-sweep-{}", bit);
+        let against = format!("compare-bit-{}", bit);
+        let rule_out = format!("compare-rule-out-{}", bit);
+        g.add("compare", *bit, &sweep, *marked, Motion::Right);
+
+        let others: Vec<char> = alphabet()
+            .into_iter()
+            .filter(|c| *c != ';' && *c != '$')
+            .collect();
+        g.skip(&sweep, &others, Motion::Right);
+        g.add(&sweep, ';', &against, ';', Motion::Right);
+        g.add(&sweep, '$', &back, '$', Motion::Left);
+
+        g.skip(&against, &MARKED, Motion::Right);
+        g.add(&against, '.', &against, '.', Motion::Right);
+        g.add(&against, *bit, &sweep, *marked, Motion::Right);
+        let other = if *bit == '0' { '1' } else { '0' };
+        g.add(&against, other, &rule_out, other, Motion::Left);
+
+        g.skip(&rule_out, &BITS, Motion::Left);
+        g.skip(&rule_out, &MARKED, Motion::Left);
+        g.add(&rule_out, '.', &rule_out, '.', Motion::Left);
+        g.add(&rule_out, ';', &sweep, 'x', Motion::Right);
+    }
+
+    // Copy the next state of the matching transition into the register
+    let others: Vec<char> = alphabet()
+        .into_iter()
+        .filter(|c| *c != ';' && *c != '$')
+        .collect();
+    g.skip("apply", &others, Motion::Right);
+    g.add("apply", ';', "apply-target", ';', Motion::Right);
+    g.add("apply", '$', "reject", '$', Motion::Stay);
+    g.seek("apply-target", '>', "copy-state");
+    g.skip("copy-state", &MARKED, Motion::Right);
+    for (bit, marked) in BITS.iter().zip(&MARKED) {
+        let put = format!("copy-state-put-{}", bit);
+        let into = format!("copy-state-into-{}", bit);
+        let back = g.rewind(&put);
+        g.add("copy-state", *bit, &back, *marked, Motion::Left);
+        g.seek(&put, '.', &into);
+        g.skip(&into, &BITS, Motion::Right);
+        for m in &MARKED {
+            g.add(&into, *m, "apply", *bit, Motion::Right);
+        }
+    }
+    for kind in &KINDS {
+        let put = format!("copy-kind-{}", kind);
+        let back = g.rewind(&put);
+        g.add("copy-state", *kind, &back, *kind, Motion::Left);
+        g.skip(&put, &BITS, Motion::Right);
+        g.skip(&put, &MARKED, Motion::Right);
+        g.add(&put, '.', &put, '.', Motion::Right);
+        for k in &KINDS {
+            g.add(&put, *k, "write", *kind, Motion::Right);
+        }
+    }
+
+    // Copy the symbol written by the matching transition into the cell under the head
+    g.seek("write", ';', "write-target");
+    g.seek("write-target", '>', "write-symbol");
+    g.skip("write-symbol", &MARKED, Motion::Right);
+    g.skip("write-symbol", &KINDS, Motion::Right);
+    for (bit, marked) in BITS.iter().zip(&MARKED) {
+        let put = format!("write-put-{}", bit);
+        let into = format!("write-into-{}", bit);
+        g.add("write-symbol", *bit, &put, *marked, Motion::Right);
+        g.seek(&put, '*', &into);
+        g.skip(&into, &BITS, Motion::Right);
+        for m in &MARKED {
+            g.add(&into, *m, "write-back", *bit, Motion::Left);
+        }
+    }
+    let others: Vec<char> = alphabet().into_iter().filter(|c| *c != ';').collect();
+    g.skip("write-back", &others, Motion::Left);
+    g.add("write-back", ';', "write-target", ';', Motion::Right);
+
+    // Move the head marker, adding a blank cell at the right end of the tape when needed
+    let cleanup = g.rewind("cleanup");
+    let extend = g.rewind("extend");
+    for m in &MOVES {
+        let name = format!("move-{}", m);
+        g.add("write-symbol", *m, &name, *m, Motion::Right);
+        let others: Vec<char> = alphabet().into_iter().filter(|c| *c != '*').collect();
+        g.skip(&name, &others, Motion::Right);
+        match m {
+            'S' => g.add(&name, '*', &cleanup, '*', Motion::Left),
+            'R' => g.add(&name, '*', "move-right", '|', Motion::Right),
+            _ => g.add(&name, '*', "move-left", '|', Motion::Left),
+        }
+    }
+    g.skip("move-right", &BITS, Motion::Right);
+    g.add("move-right", '|', &cleanup, '*', Motion::Left);
+    g.add("move-right", '_', &extend, '*', Motion::Left);
+    g.skip("move-left", &BITS, Motion::Left);
+    g.add("move-left", '|', &cleanup, '*', Motion::Left);
+    // The head stays on the first cell, like the simulated machine does
+    g.add("move-left", '$', "move-left-edge", '$', Motion::Right);
+    g.add("move-left-edge", '|', &cleanup, '*', Motion::Left);
+
+    // The new cell gets as many blank bits as the register has, unmarking them as they are counted
+    g.skip("extend", &BITS, Motion::Right);
+    g.add("extend", '.', &cleanup, '.', Motion::Left);
+    for m in &MARKED {
+        g.add(
+            "extend",
+            *m,
+            "extend-put",
+            if *m == 'o' { '0' } else { '1' },
+            Motion::Right,
+        );
+    }
+    let others: Vec<char> = alphabet().into_iter().filter(|c| *c != '_').collect();
+    g.skip("extend-put", &others, Motion::Right);
+    g.add("extend-put", '_', &extend, '0', Motion::Left);
+
+    // Unmark everything, bring back the transitions ruled out and check the new state
+    g.add("cleanup", 'o', "cleanup", '0', Motion::Right);
+    g.add("cleanup", 'i', "cleanup", '1', Motion::Right);
+    g.add("cleanup", 'x', "cleanup", ';', Motion::Right);
+    let others: Vec<char> = alphabet()
+        .into_iter()
+        .filter(|c| !['o', 'i', 'x', '$'].contains(c))
+        .collect();
+    g.skip("cleanup", &others, Motion::Right);
+    let check = g.rewind("check");
+    g.add("cleanup", '$', &check, '$', Motion::Left);
+
+    let mut parser = g.parser;
+    parser
+        .add_starting_state("check".to_string())
+        .expect("The starting state is only set once");
+    for c in alphabet() {
+        parser
+            .add_alphabet_symbol(c)
+            .expect("The alphabet has no duplicates");
+    }
+    Repr::from_builder(&parser).expect("The universal machine is a valid machine")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accelerate::AcceleratedTuringMachineExt, builders::TuringMachineBuilder,
+        deterministic_tm::DeterministicTuringMachine, limited::LimitedTuringMachineExt,
+        machine_parser, TuringMachine,
+    };
+
+    fn load(machine: &str) -> Repr {
+        Repr::from_builder(&machine_parser::parse(machine.as_bytes()).unwrap()).unwrap()
+    }
+
+    fn machine(repr: Repr, tape: Vec<char>) -> DeterministicTuringMachine<String> {
+        DeterministicTuringMachine::from_builder(TuringMachineBuilder::new().repr(repr).tape(tape))
+            .unwrap()
+    }
+
+    fn trimmed(mut tape: Vec<char>) -> Vec<char> {
+        while tape.last() == Some(&'_') {
+            tape.pop();
+        }
+        tape
+    }
+
+    /// Run the machine to completion, returning whether it accepted or halted and the tape.
+    /// Sweeps are accelerated, as the universal machine spends most of its millions of steps in them.
+    fn run(repr: &Repr, tape: Vec<char>) -> (bool, bool, Vec<char>) {
+        let tm = AcceleratedTuringMachineExt::new(machine(repr.clone(), tape));
+        let exe = LimitedTuringMachineExt::new_with_limit(tm, 100_000_000).execute_and_get_result();
+        (exe.accepting, exe.halting, trimmed(exe.tape))
+    }
+
+    /// Compare complete runs of the machine and of the universal machine
+    fn assert_simulates(source: &str, inputs: &[&str]) {
+        let (utm, repr) = (universal(), load(source));
+        let description = Description::new(&repr);
+        for input in inputs {
+            let tape: Vec<char> = input.chars().collect();
+            let (accepting, halting, output) = run(&repr, tape.clone());
+            let (u_accepting, u_halting, u_output) = run(&utm, description.encode(&tape).unwrap());
+            let (decoded, _) = description.decode(&u_output).unwrap();
+            assert_eq!(
+                (u_accepting, u_halting),
+                (accepting, halting),
+                "On {}",
+                input
+            );
+            assert_eq!(trimmed(decoded), output, "On {}", input);
+        }
+    }
+
+    /// Compare the first steps of the machine with the universal machine, which enters `check` once per simulated step
+    fn assert_follows(source: &str, input: &str, steps: usize) {
+        let repr = load(source);
+        let description = Description::new(&repr);
+        let tape: Vec<char> = input.chars().collect();
+        let mut original = machine(repr, tape.clone());
+        let mut utm = machine(universal(), description.encode(&tape).unwrap());
+
+        for step in 0..steps {
+            while utm.current_state() != "check" {
+                utm.step();
+            }
+            let (decoded, head) = description.decode(utm.tape()).unwrap();
+            assert_eq!(
+                trimmed(decoded),
+                trimmed(original.tape().clone()),
+                "At step {}",
+                step
+            );
+            assert_eq!(head, original.head(), "At step {}", step);
+            original.step();
+            while utm.current_state() == "check" {
+                utm.step();
+            }
+        }
+    }
+
+    #[test]
+    fn encodes_machines() {
+        let repr = load("states 3\nq0\nqa +\nqr -\nalphabet 1 a\nq0 a q0 _ R\nq0 _ qa _ S");
+        let description = Description::new(&repr);
+        let encoded: String = description
+            .encode(&['a', 'a'])
+            .unwrap()
+            .into_iter()
+            .collect();
+        assert_eq!(encoded, "^0.00~#;0.00>01+0S;1.00>00~0R$*1|1");
+        let empty: String = description.encode(&[]).unwrap().into_iter().collect();
+        assert!(empty.ends_with("$*0"));
+        assert_eq!(
+            description.decode(&"^0.00~#$|1*0|1_".chars().collect::<Vec<_>>()),
+            Ok((vec!['a', '_', 'a'], 1))
+        );
+    }
+
+    #[test]
+    fn simulates_small_machines() {
+        // Rewriting and growing the tape, then halting
+        assert_simulates(
+            "states 3\nq0\nqh !\nqr -\nalphabet 2 a b\nq0 a q0 b R\nq0 _ qh a L",
+            &["", "a", "aaa"],
+        );
+        // Moving left on the first cell, and rejecting on a missing transition
+        assert_simulates(
+            "states 4\nq0\nq1\nqa +\nqr -\nalphabet 2 a b\nq0 a q1 b L\nq1 b qa a S",
+            &["", "a", "b"],
+        );
+    }
+
+    #[test]
+    fn simulates_paren() {
+        assert_simulates(
+            include_str!("../data/paren.tm"),
+            &["", "()", "(()", "(())()", ")("],
+        );
+    }
+
+    #[test]
+    fn simulates_binadd() {
+        // Rejecting on malformed input at several points, and accepting a sum
+        assert_simulates(
+            include_str!("../data/binadd.tm"),
+            &["", "1", "1#1", "1#1#1", "##"],
+        );
+    }
+
+    #[test]
+    fn simulates_bf() {
+        // The shortest program already takes the universal machine about fifteen million steps
+        assert_simulates(include_str!("../data/bf.tm"), &["", "+"]);
+    }
+
+    #[test]
+    fn follows_bf() {
+        for program in &["[]", "+>-"] {
+            assert_follows(include_str!("../data/bf.tm"), program, 3);
+        }
+    }
+
+    #[test]
+    fn follows_sqrt2() {
+        // This one never halts
+        assert_follows(include_str!("../data/sqrt2.tm"), "", 8);
+    }
+
+    /// Too slow for a debug build, run with `cargo test --release -- --ignored`
+    #[test]
+    #[ignore]
+    fn simulates_long_runs() {
+        assert_simulates(
+            include_str!("../data/binadd.tm"),
+            &["1#1#0", "1#0#1", "0#0#0", "#1#1", "1#1#01"],
+        );
+        assert_simulates(include_str!("../data/bf.tm"), &["-", "[]", "+-", "++"]);
+        assert_follows(include_str!("../data/sqrt2.tm"), "", 40);
+    }
+
+    #[test]
+    fn shipped_machine_is_up_to_date() {
+        let mut written = Vec::new();
+        machine_parser::write(&universal(), &mut written).unwrap();
+        let shipped = include_str!("../data/universal.tm").replace('\r', "");
+        assert!(
+            String::from_utf8(written).unwrap() == shipped,
+            "data/universal.tm is out of date, regenerate it with the utm subcommand"
+        );
+    }
+}